@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main};
-use geo::algorithm::{ConvexHull, EuclideanDistance};
+use geo::algorithm::{ConvexHull, DistanceIndex, EuclideanDistance, Translate};
 use geo::{polygon, Polygon};
 
 fn criterion_benchmark(c: &mut criterion::Criterion) {
@@ -87,6 +87,27 @@ fn criterion_benchmark(c: &mut criterion::Criterion) {
             });
         },
     );
+
+    c.bench_function("Large polygons Euclidean distance f64", |bencher| {
+        let poly1 = Polygon::new(geo_test_fixtures::norway_main::<f64>(), vec![]);
+        let poly2 = poly1.translate(0., 15.);
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&poly1).euclidean_distance(criterion::black_box(&poly2)),
+            );
+        });
+    });
+
+    c.bench_function("Large polygons DistanceIndex distance f64", |bencher| {
+        let poly1 = Polygon::new(geo_test_fixtures::norway_main::<f64>(), vec![]);
+        let index1 = DistanceIndex::from(&poly1);
+        let index2 = DistanceIndex::from(&poly1.translate(0., 15.));
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&index1).distance(criterion::black_box(&index2)),
+            );
+        });
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
-use geo::algorithm::{Relate, Rotate, Translate};
+use geo::algorithm::{Contains, PreparedGeometry, Relate, Rotate, Translate};
 use geo::geometry::{LineString, Polygon};
 
 fn criterion_benchmark(c: &mut Criterion) {
@@ -85,6 +85,39 @@ fn criterion_benchmark(c: &mut Criterion) {
             criterion::black_box(norway.relate(&translated_norway));
         });
     });
+
+    c.bench_function("large rotated polygons intersects spec", |bencher| {
+        let norway = Polygon::new(geo_test_fixtures::norway_main::<f64>(), vec![]);
+        let rotated_norway = norway.rotate_around_center(20.0);
+
+        bencher.iter(|| {
+            assert!(criterion::black_box(&norway)
+                .relate_matches(criterion::black_box(&rotated_norway), "T********")
+                .unwrap());
+        });
+    });
+
+    c.bench_function("prepared large rotated polygons", |bencher| {
+        let norway = Polygon::new(geo_test_fixtures::norway_main::<f64>(), vec![]);
+        let rotated_norway = norway.rotate_around_center(20.0);
+        let prepared_norway = PreparedGeometry::from(&norway);
+
+        bencher.iter(|| {
+            criterion::black_box(prepared_norway.relate(&rotated_norway));
+        });
+    });
+
+    c.bench_function("prepared point in complex polygon", |bencher| {
+        let louisiana = Polygon::new(geo_test_fixtures::louisiana::<f64>(), vec![]);
+        let prepared_louisiana = PreparedGeometry::from(&louisiana);
+        let point = geo_test_fixtures::baton_rouge();
+
+        bencher.iter(|| {
+            assert!(
+                criterion::black_box(&prepared_louisiana).contains(criterion::black_box(&point))
+            );
+        });
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
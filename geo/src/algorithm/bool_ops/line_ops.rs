@@ -0,0 +1,237 @@
+use geo_types::{Coord, Line, LineString, MultiLineString, MultiPoint, Point};
+use std::cmp::Ordering;
+
+use super::OpType;
+use crate::line_merge::{merge, Nodes};
+use crate::node::split_line;
+use crate::sweep::{Cross, Intersections, LineOrPoint};
+use crate::utils::lex_cmp;
+use crate::{GeoFloat, LineIntersection};
+
+/// Boolean Operations on linework.
+///
+/// The lines are split at every point where they meet, and at the ends of any parts they
+/// share, so that the result contains each piece of linework once. Where pieces meet at a
+/// point with exactly two ends, they are merged back into a single `LineString`.
+///
+/// The intersection of two lines may also contain points where they cross or touch without
+/// sharing any linework. These are not part of the `MultiLineString` returned by
+/// [`intersection`](Self::intersection), but are found by
+/// [`intersection_points`](Self::intersection_points).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, LineBooleanOps};
+///
+/// let a = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+/// let b = line_string![(x: 5., y: 0.), (x: 15., y: 0.), (x: 15., y: 5.)];
+///
+/// assert_eq!(
+///     a.intersection(&b).0,
+///     vec![line_string![(x: 5., y: 0.), (x: 10., y: 0.)]]
+/// );
+/// assert_eq!(
+///     a.difference(&b).0,
+///     vec![line_string![(x: 0., y: 0.), (x: 5., y: 0.)]]
+/// );
+/// ```
+pub trait LineBooleanOps {
+    type Scalar: GeoFloat;
+
+    fn boolean_op(&self, other: &Self, op: OpType) -> MultiLineString<Self::Scalar>;
+    fn intersection(&self, other: &Self) -> MultiLineString<Self::Scalar> {
+        self.boolean_op(other, OpType::Intersection)
+    }
+    fn union(&self, other: &Self) -> MultiLineString<Self::Scalar> {
+        self.boolean_op(other, OpType::Union)
+    }
+    fn xor(&self, other: &Self) -> MultiLineString<Self::Scalar> {
+        self.boolean_op(other, OpType::Xor)
+    }
+    fn difference(&self, other: &Self) -> MultiLineString<Self::Scalar> {
+        self.boolean_op(other, OpType::Difference)
+    }
+
+    /// Returns the points where the lines cross or touch, apart from the linework they share.
+    fn intersection_points(&self, other: &Self) -> MultiPoint<Self::Scalar>;
+}
+
+impl<T: GeoFloat> LineBooleanOps for LineString<T> {
+    type Scalar = T;
+
+    fn boolean_op(&self, other: &Self, op: OpType) -> MultiLineString<T> {
+        LineGraph::new([self], [other]).select(op)
+    }
+
+    fn intersection_points(&self, other: &Self) -> MultiPoint<T> {
+        LineGraph::new([self], [other]).intersection_points()
+    }
+}
+
+impl<T: GeoFloat> LineBooleanOps for MultiLineString<T> {
+    type Scalar = T;
+
+    fn boolean_op(&self, other: &Self, op: OpType) -> MultiLineString<T> {
+        LineGraph::new(self, other).select(op)
+    }
+
+    fn intersection_points(&self, other: &Self) -> MultiPoint<T> {
+        LineGraph::new(self, other).intersection_points()
+    }
+}
+
+/// A segment of one of the inputs.
+#[derive(Debug, Clone)]
+struct Segment<T: GeoFloat> {
+    line: Line<T>,
+    idx: usize,
+}
+
+impl<T: GeoFloat> Cross for Segment<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<Self::Scalar> {
+        self.line.into()
+    }
+}
+
+/// A piece of linework which doesn't meet any other, except at its ends.
+#[derive(Debug, Clone, Copy)]
+struct Edge<T: GeoFloat> {
+    line: Line<T>,
+    is_first: bool,
+    is_second: bool,
+}
+
+impl<T: GeoFloat> Edge<T> {
+    fn is_ty(&self, ty: OpType) -> bool {
+        match ty {
+            OpType::Intersection => self.is_first && self.is_second,
+            OpType::Union => self.is_first || self.is_second,
+            OpType::Difference => self.is_first && !self.is_second,
+            OpType::Xor => self.is_first ^ self.is_second,
+        }
+    }
+
+    /// The end points in lexicographic order, which are the same for overlapping edges.
+    fn key(&self) -> (Coord<T>, Coord<T>) {
+        let Line { start, end } = self.line;
+        match lex_cmp(&start, &end) {
+            Ordering::Greater => (end, start),
+            _ => (start, end),
+        }
+    }
+}
+
+/// The linework of both inputs, noded at every point where they meet.
+struct LineGraph<T: GeoFloat> {
+    edges: Vec<Edge<T>>,
+}
+
+impl<T: GeoFloat> LineGraph<T> {
+    fn new<'a>(
+        first: impl IntoIterator<Item = &'a LineString<T>>,
+        second: impl IntoIterator<Item = &'a LineString<T>>,
+    ) -> Self
+    where
+        T: 'a,
+    {
+        let operands: Vec<_> = first
+            .into_iter()
+            .flat_map(|ls| ls.lines().map(|line| (line, true)))
+            .chain(
+                second
+                    .into_iter()
+                    .flat_map(|ls| ls.lines().map(|line| (line, false))),
+            )
+            .filter(|(line, _)| line.start != line.end)
+            .collect();
+
+        let mut splits = vec![vec![]; operands.len()];
+        let segments = operands
+            .iter()
+            .enumerate()
+            .map(|(idx, &(line, _))| Segment { line, idx });
+        for (a, b, intersection) in Intersections::from_iter(segments) {
+            let points = match intersection {
+                LineIntersection::SinglePoint { intersection, .. } => vec![intersection],
+                LineIntersection::Collinear { intersection } => {
+                    vec![intersection.start, intersection.end]
+                }
+            };
+            for point in points {
+                splits[a.idx].push(point);
+                splits[b.idx].push(point);
+            }
+        }
+
+        let mut edges: Vec<_> = operands
+            .iter()
+            .zip(splits)
+            .flat_map(|(&(line, is_first), splits)| {
+                let coords = split_line(line, splits);
+                coords
+                    .windows(2)
+                    .map(|w| Edge {
+                        line: Line::new(w[0], w[1]),
+                        is_first,
+                        is_second: !is_first,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .enumerate()
+            .collect();
+
+        // Merge overlapping edges, keeping the first in its original place.
+        edges.sort_by(|(a_idx, a), (b_idx, b)| {
+            let (a_key, b_key) = (a.key(), b.key());
+            lex_cmp(&a_key.0, &b_key.0)
+                .then_with(|| lex_cmp(&a_key.1, &b_key.1))
+                .then(a_idx.cmp(b_idx))
+        });
+        let mut merged: Vec<(usize, Edge<T>)> = vec![];
+        for (idx, edge) in edges {
+            match merged.last_mut() {
+                Some((_, last)) if last.key() == edge.key() => {
+                    last.is_first |= edge.is_first;
+                    last.is_second |= edge.is_second;
+                }
+                _ => merged.push((idx, edge)),
+            }
+        }
+        merged.sort_by_key(|(idx, _)| *idx);
+
+        LineGraph {
+            edges: merged.into_iter().map(|(_, edge)| edge).collect(),
+        }
+    }
+
+    /// The edges of the given type, merged into maximal `LineString`s.
+    fn select(&self, ty: OpType) -> MultiLineString<T> {
+        let edges: Vec<_> = self
+            .edges
+            .iter()
+            .filter(|e| e.is_ty(ty))
+            .map(|e| LineString::from(e.line))
+            .collect();
+        MultiLineString::new(merge(&edges))
+    }
+
+    /// The nodes where edges of both inputs meet, except on the linework they share.
+    fn intersection_points(&self) -> MultiPoint<T> {
+        let nodes = Nodes::new(self.edges.iter().map(|e| (e.line.start, e.line.end)));
+        nodes
+            .coords
+            .iter()
+            .zip(&nodes.edges)
+            .filter(|(_, edges)| {
+                let edges = || edges.iter().map(|(idx, _)| &self.edges[*idx]);
+                edges().any(|e| e.is_first)
+                    && edges().any(|e| e.is_second)
+                    && !edges().any(|e| e.is_first && e.is_second)
+            })
+            .map(|(coord, _)| Point::from(*coord))
+            .collect()
+    }
+}
@@ -22,6 +22,8 @@ use crate::{CoordsIter, GeoFloat, GeoNum, Polygon};
 /// In particular, taking `union` with an empty geom should remove degeneracies
 /// and fix invalid polygons as long the interior-exterior requirement above is
 /// satisfied.
+/// For a complete repair of invalid polygons, see
+/// [`MakeValid`](crate::MakeValid).
 pub trait BooleanOps: Sized {
     type Scalar: GeoNum;
 
@@ -109,12 +111,31 @@ impl<T: GeoFloat> BooleanOps for MultiPolygon<T> {
     }
 }
 
+/// Returns the parts of `ls` lying strictly outside `mp`; parts on its boundary are dropped.
+pub(crate) fn exterior_lines<T: GeoFloat>(
+    mp: &MultiPolygon<T>,
+    ls: &MultiLineString<T>,
+) -> MultiLineString<T> {
+    let mut bop = Proc::new(ClipOp::exterior(), mp.coords_count() + ls.coords_count());
+    bop.add_multi_polygon(mp, 0);
+    ls.0.iter().enumerate().for_each(|(idx, l)| {
+        bop.add_line_string(l, idx + 1);
+    });
+    bop.sweep()
+}
+
 mod op;
 use op::*;
 mod assembly;
 use assembly::*;
 mod spec;
 use spec::*;
+mod unary_union;
+pub use unary_union::{unary_union, UnaryUnion};
+mod line_ops;
+pub use line_ops::LineBooleanOps;
+mod overlay;
+pub use overlay::Overlay;
 
 #[cfg(test)]
 mod tests;
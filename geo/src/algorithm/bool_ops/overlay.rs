@@ -0,0 +1,273 @@
+use geo_types::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPolygon, Point, Polygon,
+};
+use rstar::RTreeNum;
+
+use super::{exterior_lines, unary_union, BooleanOps, LineBooleanOps, OpType};
+use crate::utils::lex_cmp;
+use crate::{GeoFloat, Intersects};
+
+/// Boolean Operations on geometries of any dimension.
+///
+/// Unlike [`BooleanOps`] and [`LineBooleanOps`], the operands may be any mix of points, lines
+/// and polygons, including a `GeometryCollection`. The result follows the semantics of JTS
+/// `OverlayNG`, and contains the parts of each dimension which are in the result set:
+///
+/// - the polygons, which are the result of the operation on the polygonal parts,
+/// - the lines which are not covered by the polygons of the result,
+/// - the points which are not covered by any of the lines or polygons of the result.
+///
+/// In particular, the intersection of two polygons also contains the edges and points where
+/// their boundaries touch, but not their interiors.
+///
+/// Each operand is treated as the union of its components, so overlapping components of a
+/// `GeometryCollection` are merged first.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, Geometry, OpType, Overlay};
+///
+/// let left: Geometry = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)].into();
+/// let right: Geometry = polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)].into();
+///
+/// // The squares only share an edge.
+/// let shared = left.overlay(&right, OpType::Intersection);
+/// assert_eq!(shared.0.len(), 1);
+/// assert!(matches!(shared.0[0], Geometry::LineString(_)));
+///
+/// let line: Geometry = line_string![(x: -1., y: 0.5), (x: 0.5, y: 0.5)].into();
+/// let outside = line.overlay(&left, OpType::Difference);
+/// assert_eq!(
+///     outside.0,
+///     vec![Geometry::LineString(line_string![(x: -1., y: 0.5), (x: 0., y: 0.5)])]
+/// );
+/// ```
+pub trait Overlay {
+    type Scalar: GeoFloat;
+
+    fn overlay(&self, other: &Self, op: OpType) -> GeometryCollection<Self::Scalar>;
+}
+
+impl<T: GeoFloat + RTreeNum> Overlay for Geometry<T> {
+    type Scalar = T;
+
+    fn overlay(&self, other: &Self, op: OpType) -> GeometryCollection<T> {
+        let a = Parts::new(self);
+        let b = Parts::new(other);
+        match op {
+            OpType::Intersection => a.intersection(&b),
+            OpType::Union => a.union(&b),
+            OpType::Difference => a.difference(&b),
+            OpType::Xor => a.xor(&b),
+        }
+        .into_collection()
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Overlay for GeometryCollection<T> {
+    type Scalar = T;
+
+    fn overlay(&self, other: &Self, op: OpType) -> GeometryCollection<T> {
+        Geometry::GeometryCollection(self.clone())
+            .overlay(&Geometry::GeometryCollection(other.clone()), op)
+    }
+}
+
+/// The components of a geometry by dimension.
+///
+/// The components are disjoint: no line lies on one of the polygons, and no point lies on one
+/// of the lines or polygons.
+struct Parts<T: GeoFloat> {
+    polygons: MultiPolygon<T>,
+    lines: MultiLineString<T>,
+    points: Vec<Coord<T>>,
+}
+
+impl<T: GeoFloat + RTreeNum> Parts<T> {
+    fn new(geometry: &Geometry<T>) -> Self {
+        let mut polygons = vec![];
+        let mut lines = vec![];
+        let mut points = vec![];
+        flatten(geometry, &mut polygons, &mut lines, &mut points);
+
+        let polygons = unary_union(&polygons);
+        // Node the lines, and merge any overlaps.
+        let lines = MultiLineString::new(lines).union(&MultiLineString::new(vec![]));
+        Parts::from_disjoint(polygons, lines, points)
+    }
+}
+
+impl<T: GeoFloat> Parts<T> {
+    /// Removes the lines and points which are covered by a component of higher dimension.
+    fn from_disjoint(
+        polygons: MultiPolygon<T>,
+        lines: MultiLineString<T>,
+        mut points: Vec<Coord<T>>,
+    ) -> Self {
+        let lines = if polygons.0.is_empty() || lines.0.is_empty() {
+            lines
+        } else {
+            exterior_lines(&polygons, &lines)
+        };
+        points.sort_by(lex_cmp);
+        points.dedup();
+        points.retain(|p| !polygons.intersects(p) && !lines.intersects(p));
+        Parts {
+            polygons,
+            lines,
+            points,
+        }
+    }
+
+    fn intersects(&self, coord: &Coord<T>) -> bool {
+        self.polygons.intersects(coord)
+            || self.lines.intersects(coord)
+            || self.points.contains(coord)
+    }
+
+    /// The rings of the polygons.
+    fn boundary(&self) -> impl Iterator<Item = &LineString<T>> {
+        self.polygons
+            .iter()
+            .flat_map(|p| std::iter::once(p.exterior()).chain(p.interiors()))
+    }
+
+    /// The lines and the boundary of the polygons.
+    fn linework(&self) -> MultiLineString<T> {
+        self.lines.iter().chain(self.boundary()).cloned().collect()
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        let polygons = self.polygons.intersection(&other.polygons);
+
+        let boundary: MultiLineString<T> = self.boundary().cloned().collect();
+        let other_boundary: MultiLineString<T> = other.boundary().cloned().collect();
+        let lines: MultiLineString<T> = [
+            self.lines.intersection(&other.lines),
+            other.lines_within(&self.lines),
+            self.lines_within(&other.lines),
+            // Polygons which only touch along their boundaries.
+            boundary.intersection(&other_boundary),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let lines = lines.union(&MultiLineString::new(vec![]));
+
+        let points = self
+            .points
+            .iter()
+            .filter(|p| other.intersects(p))
+            .chain(other.points.iter().filter(|p| self.intersects(p)))
+            .copied()
+            .chain(
+                self.linework()
+                    .intersection_points(&other.linework())
+                    .iter()
+                    .map(|p| p.0),
+            )
+            .collect();
+
+        Parts::from_disjoint(polygons, lines, points)
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        let polygons = self.polygons.union(&other.polygons);
+        let lines = self.lines.union(&other.lines);
+        let points = self.points.iter().chain(&other.points).copied().collect();
+        Parts::from_disjoint(polygons, lines, points)
+    }
+
+    fn difference(&self, other: &Self) -> Self {
+        let polygons = self.polygons.difference(&other.polygons);
+        Parts {
+            polygons,
+            lines: self.lines_outside(other),
+            points: self.points_outside(other),
+        }
+    }
+
+    fn xor(&self, other: &Self) -> Self {
+        let polygons = self.polygons.xor(&other.polygons);
+        let lines = self
+            .lines_outside(other)
+            .into_iter()
+            .chain(other.lines_outside(self))
+            .collect();
+        let points = self
+            .points_outside(other)
+            .into_iter()
+            .chain(other.points_outside(self))
+            .collect();
+        Parts {
+            polygons,
+            lines,
+            points,
+        }
+    }
+
+    /// The parts of `lines` which are covered by the polygons.
+    fn lines_within(&self, lines: &MultiLineString<T>) -> MultiLineString<T> {
+        if self.polygons.0.is_empty() || lines.0.is_empty() {
+            return MultiLineString::new(vec![]);
+        }
+        // Clipping drops the parts which lie on the boundary, so these are found separately.
+        let boundary: MultiLineString<T> = self.boundary().cloned().collect();
+        self.polygons
+            .clip(lines, false)
+            .into_iter()
+            .chain(lines.intersection(&boundary))
+            .collect()
+    }
+
+    /// The parts of the lines which are not covered by `other`.
+    fn lines_outside(&self, other: &Self) -> MultiLineString<T> {
+        let lines = self.lines.difference(&other.lines);
+        if other.polygons.0.is_empty() || lines.0.is_empty() {
+            lines
+        } else {
+            exterior_lines(&other.polygons, &lines)
+        }
+    }
+
+    /// The points which are not covered by `other`.
+    fn points_outside(&self, other: &Self) -> Vec<Coord<T>> {
+        self.points
+            .iter()
+            .filter(|p| !other.intersects(p))
+            .copied()
+            .collect()
+    }
+
+    fn into_collection(self) -> GeometryCollection<T> {
+        self.polygons
+            .into_iter()
+            .map(Geometry::Polygon)
+            .chain(self.lines.into_iter().map(Geometry::LineString))
+            .chain(self.points.into_iter().map(|c| Geometry::Point(c.into())))
+            .collect()
+    }
+}
+
+fn flatten<T: GeoFloat>(
+    geometry: &Geometry<T>,
+    polygons: &mut Vec<Polygon<T>>,
+    lines: &mut Vec<LineString<T>>,
+    points: &mut Vec<Coord<T>>,
+) {
+    match geometry {
+        Geometry::Point(p) => points.push(p.0),
+        Geometry::MultiPoint(mp) => points.extend(mp.iter().map(|p: &Point<T>| p.0)),
+        Geometry::Line(l) => lines.push(LineString::from(*l)),
+        Geometry::LineString(ls) => lines.push(ls.clone()),
+        Geometry::MultiLineString(mls) => lines.extend(mls.iter().cloned()),
+        Geometry::Polygon(p) => polygons.push(p.clone()),
+        Geometry::MultiPolygon(mp) => polygons.extend(mp.iter().cloned()),
+        Geometry::Rect(r) => polygons.push(r.to_polygon()),
+        Geometry::Triangle(t) => polygons.push(t.to_polygon()),
+        Geometry::GeometryCollection(gc) => {
+            gc.iter().for_each(|g| flatten(g, polygons, lines, points))
+        }
+    }
+}
@@ -55,6 +55,7 @@ impl<T: GeoFloat> Spec<T> for BoolOp<T> {
 
 pub struct ClipOp<T: GeoFloat> {
     invert: bool,
+    keep_boundary: bool,
     assembly: LineAssembly<T>,
 }
 
@@ -62,6 +63,16 @@ impl<T: GeoFloat> ClipOp<T> {
     pub fn new(invert: bool) -> Self {
         Self {
             invert,
+            keep_boundary: true,
+            assembly: Default::default(),
+        }
+    }
+
+    /// Clip to the exterior of the region, excluding its boundary.
+    pub fn exterior() -> Self {
+        Self {
+            invert: true,
+            keep_boundary: false,
             assembly: Default::default(),
         }
     }
@@ -86,7 +97,15 @@ impl<T: GeoFloat> Spec<T> for ClipOp<T> {
     }
 
     fn output(&mut self, regions: [Self::Region; 2], geom: LineOrPoint<T>, idx: usize) {
-        if idx > 0 && (regions[0].is_first && regions[1].is_first) != self.invert {
+        if idx == 0 {
+            return;
+        }
+        let keep = if self.invert && !self.keep_boundary {
+            !regions[0].is_first && !regions[1].is_first
+        } else {
+            (regions[0].is_first && regions[1].is_first) != self.invert
+        };
+        if keep {
             self.assembly.add_edge(geom, idx);
         }
     }
@@ -1,4 +1,8 @@
-use crate::{LineString, MultiPolygon, Polygon};
+use crate::{
+    polygon, Area, Geometry, GeometryCollection, LineBooleanOps, LineString, MultiLineString,
+    MultiPolygon, OpType, Overlay, Point, Polygon,
+};
+use approx::assert_relative_eq;
 use log::{error, info};
 
 use std::{
@@ -230,3 +234,170 @@ fn test_issue_buffer_box() {
     let wkt2 = "MULTIPOLYGON(((-164.93595896333647 149.53568721641966,-51.873865625542294 153.2197777241044,-153.80312445248086 153.2197777241044,-266.86521779027504 149.53568721641966,-164.93595896333647 149.53568721641966)))";
     check_sweep::<f64>(wkt1, wkt2, OpType::Union).unwrap();
 }
+
+#[test]
+fn test_unary_union_grid() {
+    // Overlapping unit squares on a 20 by 20 grid, offset by half a square.
+    let polygons: Vec<Polygon<f64>> = (0..400)
+        .map(|i| {
+            let (x, y) = ((i % 20) as f64 * 0.5, (i / 20) as f64 * 0.5);
+            polygon![(x: x, y: y), (x: x + 1., y: y), (x: x + 1., y: y + 1.), (x: x, y: y + 1.)]
+        })
+        .collect();
+    let union = unary_union(&polygons);
+    assert_eq!(union.0.len(), 1);
+    assert_eq!(union.0[0].interiors().len(), 0);
+    assert_relative_eq!(union.unsigned_area(), 10.5 * 10.5);
+
+    let sequential = polygons.iter().fold(MultiPolygon::new(vec![]), |acc, p| {
+        acc.union(&MultiPolygon::new(vec![p.clone()]))
+    });
+    assert_relative_eq!(union.unsigned_area(), sequential.unsigned_area());
+}
+
+#[test]
+fn test_unary_union_multi_polygon() -> Result<()> {
+    // Overlapping components, and one disjoint from the rest.
+    let mp = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((0 0,2 0,2 2,0 2,0 0)),((1 1,3 1,3 3,1 3,1 1)),((10 10,11 10,11 11,10 11,10 10)))",
+    )?;
+    let union = mp.unary_union();
+    assert_eq!(union.0.len(), 2);
+    assert_relative_eq!(union.unsigned_area(), 8.);
+
+    let single = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,0 2,2 2,2 0,0 0)))")?;
+    assert_eq!(
+        single.unary_union(),
+        single.union(&MultiPolygon::new(vec![]))
+    );
+
+    assert!(MultiPolygon::<f64>::new(vec![]).unary_union().0.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_line_ops_crossing() -> Result<()> {
+    let a = LineString::<f64>::try_from_wkt_str("LINESTRING(0 0,10 10)")?;
+    let b = LineString::<f64>::try_from_wkt_str("LINESTRING(0 10,10 0)")?;
+    assert!(a.intersection(&b).0.is_empty());
+    assert_eq!(a.intersection_points(&b).0, vec![Point::new(5., 5.)]);
+    assert_eq!(
+        a.union(&b),
+        MultiLineString::try_from_wkt_str(
+            "MULTILINESTRING((0 0,5 5),(5 5,10 10),(0 10,5 5),(5 5,10 0))"
+        )?
+    );
+    assert_eq!(
+        a.difference(&b),
+        MultiLineString::try_from_wkt_str("MULTILINESTRING((0 0,5 5,10 10))")?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_line_ops_overlap() -> Result<()> {
+    let a = MultiLineString::<f64>::try_from_wkt_str("MULTILINESTRING((0 0,4 0,10 0,10 5))")?;
+    let b = MultiLineString::<f64>::try_from_wkt_str("MULTILINESTRING((12 0,2 0),(0 5,0 0))")?;
+    assert_eq!(
+        a.intersection(&b),
+        MultiLineString::try_from_wkt_str("MULTILINESTRING((2 0,4 0,10 0))")?
+    );
+    assert_eq!(a.intersection_points(&b).0, vec![Point::new(0., 0.)]);
+    assert_eq!(
+        a.xor(&b),
+        MultiLineString::try_from_wkt_str("MULTILINESTRING((0 5,0 0,2 0),(12 0,10 0,10 5))")?
+    );
+    assert_eq!(
+        a.union(&b).0.iter().map(|ls| ls.0.len()).sum::<usize>(),
+        a.intersection(&b).0[0].0.len() + a.xor(&b).0.iter().map(|ls| ls.0.len()).sum::<usize>()
+    );
+    assert!(a.difference(&a).0.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_line_ops_rings() -> Result<()> {
+    // Rings are merged all the way round.
+    let a = LineString::<f64>::try_from_wkt_str("LINESTRING(0 0,10 0,10 10,0 10,0 0)")?;
+    let b = LineString::<f64>::try_from_wkt_str("LINESTRING(20 0,30 0,30 10,20 0)")?;
+    let union = a.union(&b);
+    assert_eq!(union.0.len(), 2);
+    assert!(union.0.iter().all(|ls| ls.is_closed()));
+    Ok(())
+}
+
+fn overlay(a: &str, b: &str, op: OpType) -> Result<GeometryCollection<f64>> {
+    let a = Geometry::<f64>::try_from_wkt_str(a)?;
+    let b = Geometry::<f64>::try_from_wkt_str(b)?;
+    Ok(a.overlay(&b, op))
+}
+
+#[test]
+fn test_overlay_touching_polygons() -> Result<()> {
+    let a = "POLYGON((0 0,1 0,1 1,0 1,0 0))";
+    // Shares the edge from (1 0) to (1 1).
+    let b = "POLYGON((1 0,2 0,2 1,1 1,1 0))";
+    assert_eq!(
+        overlay(a, b, OpType::Intersection)?,
+        GeometryCollection::try_from_wkt_str("GEOMETRYCOLLECTION(LINESTRING(1 0,1 1))")?
+    );
+    // Only touches at (1 1).
+    let c = "POLYGON((1 1,2 1,2 2,1 2,1 1))";
+    assert_eq!(
+        overlay(a, c, OpType::Intersection)?,
+        GeometryCollection::try_from_wkt_str("GEOMETRYCOLLECTION(POINT(1 1))")?
+    );
+    let union = overlay(a, b, OpType::Union)?;
+    assert_eq!(union.0.len(), 1);
+    assert_relative_eq!(union.unsigned_area(), 2.);
+    Ok(())
+}
+
+#[test]
+fn test_overlay_mixed_dimensions() -> Result<()> {
+    let square = "POLYGON((0 0,10 0,10 10,0 10,0 0))";
+    let line = "LINESTRING(-5 5,15 5)";
+    assert_eq!(
+        overlay(line, square, OpType::Intersection)?,
+        GeometryCollection::try_from_wkt_str("GEOMETRYCOLLECTION(LINESTRING(0 5,10 5))")?
+    );
+    assert_eq!(
+        overlay(line, square, OpType::Difference)?.0.len(),
+        2,
+        "the line is split in two"
+    );
+    // A polygon isn't changed by removing lines.
+    assert_relative_eq!(
+        overlay(square, line, OpType::Difference)?.unsigned_area(),
+        100.
+    );
+
+    let union = overlay(square, "MULTIPOINT((5 5),(20 20))", OpType::Union)?;
+    assert_eq!(union.0.len(), 2);
+    assert_eq!(union.0[1], Geometry::Point(Point::new(20., 20.)));
+
+    // A line on the boundary is in the intersection.
+    assert_eq!(
+        overlay("LINESTRING(0 10,5 10)", square, OpType::Intersection)?,
+        GeometryCollection::try_from_wkt_str("GEOMETRYCOLLECTION(LINESTRING(0 10,5 10))")?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_overlay_collection() -> Result<()> {
+    let collection = "GEOMETRYCOLLECTION(POLYGON((0 0,4 0,4 4,0 4,0 0)),POLYGON((2 0,6 0,6 4,2 4,2 0)),LINESTRING(6 2,8 2),POINT(9 9))";
+    let square = "POLYGON((3 -1,7 -1,7 5,3 5,3 -1))";
+    let intersection = overlay(collection, square, OpType::Intersection)?;
+    assert_eq!(intersection.0.len(), 2);
+    assert_relative_eq!(intersection.unsigned_area(), 12.);
+    assert_eq!(
+        intersection.0[1],
+        Geometry::LineString(LineString::from(vec![(6., 2.), (7., 2.)]))
+    );
+
+    let xor = overlay(collection, square, OpType::Xor)?;
+    assert_relative_eq!(xor.unsigned_area(), 24.);
+    assert!(xor.0.contains(&Geometry::Point(Point::new(9., 9.))));
+    Ok(())
+}
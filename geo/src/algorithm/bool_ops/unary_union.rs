@@ -0,0 +1,126 @@
+use geo_types::MultiPolygon;
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{ParentNode, RTree, RTreeNode, RTreeNum};
+
+use super::BooleanOps;
+use crate::{BoundingRect, GeoFloat, Polygon};
+
+/// Union any number of polygons together.
+///
+/// Calling [`BooleanOps::union`] on each polygon in turn takes time quadratic in the number
+/// of polygons, since the result grows with every step. Instead, the polygons are ordered
+/// spatially in an [R-tree](rstar::RTree), and the polygons in each node of the tree are
+/// unioned together first, so each union only involves nearby polygons of similar size.
+///
+/// The polygons may overlap, so this also dissolves the overlapping components of an invalid
+/// `MultiPolygon`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, UnaryUnion};
+///
+/// let parcels: Vec<_> = (0..10)
+///     .map(|i| {
+///         let x = i as f64;
+///         polygon![(x: x, y: 0.), (x: x + 1., y: 0.), (x: x + 1., y: 1.), (x: x, y: 1.)]
+///     })
+///     .collect();
+///
+/// let dissolved = parcels.unary_union();
+/// assert_eq!(dissolved.0.len(), 1);
+/// assert_eq!(dissolved.unsigned_area(), 10.);
+/// ```
+pub trait UnaryUnion {
+    type Scalar: GeoFloat;
+
+    /// Returns the union of all the polygons.
+    fn unary_union(&self) -> MultiPolygon<Self::Scalar>;
+}
+
+impl<T: GeoFloat + RTreeNum> UnaryUnion for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn unary_union(&self) -> MultiPolygon<T> {
+        unary_union(&self.0)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> UnaryUnion for [Polygon<T>] {
+    type Scalar = T;
+
+    fn unary_union(&self) -> MultiPolygon<T> {
+        unary_union(self)
+    }
+}
+
+/// Returns the union of all the `polygons`.
+///
+/// This is the same as [`UnaryUnion`], for any iterator of polygons.
+///
+/// ```
+/// use geo::{polygon, unary_union};
+///
+/// let polygons = [
+///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+///     polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)],
+/// ];
+/// assert_eq!(unary_union(&polygons).0.len(), 1);
+/// ```
+pub fn unary_union<'a, T: GeoFloat + RTreeNum + 'a>(
+    polygons: impl IntoIterator<Item = &'a Polygon<T>>,
+) -> MultiPolygon<T> {
+    let polygons: Vec<_> = polygons.into_iter().collect();
+    let envelopes = polygons
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, polygon)| {
+            let rect = polygon.bounding_rect()?;
+            let envelope =
+                Rectangle::from_corners(rect.min().x_y().into(), rect.max().x_y().into());
+            Some(GeomWithData::new(envelope, idx))
+        })
+        .collect();
+    let tree = RTree::bulk_load(envelopes);
+    let union = union_node(tree.root(), &polygons);
+    if tree.size() == 1 {
+        // Nothing has been unioned, so clean up the single polygon.
+        union.union(&MultiPolygon::new(vec![]))
+    } else {
+        union
+    }
+}
+
+type Envelope<T> = GeomWithData<Rectangle<[T; 2]>, usize>;
+
+fn union_node<T: GeoFloat + RTreeNum>(
+    node: &ParentNode<Envelope<T>>,
+    polygons: &[&Polygon<T>],
+) -> MultiPolygon<T> {
+    let children = node
+        .children()
+        .iter()
+        .map(|child| match child {
+            RTreeNode::Leaf(envelope) => MultiPolygon::new(vec![polygons[envelope.data].clone()]),
+            RTreeNode::Parent(parent) => union_node(parent, polygons),
+        })
+        .collect();
+    union_pairwise(children)
+}
+
+/// Union neighbouring multi-polygons in the list pairwise, until only one is left.
+fn union_pairwise<T: GeoFloat>(mut multi_polygons: Vec<MultiPolygon<T>>) -> MultiPolygon<T> {
+    while multi_polygons.len() > 1 {
+        multi_polygons = multi_polygons
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => a.union(b),
+                [a] => a.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    multi_polygons
+        .pop()
+        .unwrap_or_else(|| MultiPolygon::new(vec![]))
+}
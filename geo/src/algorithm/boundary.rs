@@ -0,0 +1,325 @@
+use crate::geometry::*;
+use crate::utils::lex_cmp;
+use crate::CoordNum;
+
+/// Calculate the combinatorial boundary of a geometry, as defined by the
+/// [OGC Simple Features](https://www.ogc.org/standards/sfa) specification.
+///
+/// - Points have an empty boundary.
+/// - The boundary of a `LineString` is its two endpoints, unless it's closed, when it's empty.
+/// - The boundary of a `MultiLineString` is the endpoints of its members which are the endpoint
+///   of an odd number of them, following the "Mod-2" rule. Lines which meet end to end don't
+///   have a boundary where they meet.
+/// - The boundary of a polygon is its rings.
+///
+/// The points of a boundary are sorted by their coordinates, and it's the same as the boundary
+/// used by [`Relate`](crate::Relate). The boundary of a `GeometryCollection`, which the OGC
+/// leaves undefined, is taken to be the Mod-2 boundary of its lines together with the rings of
+/// its polygons.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, Boundary, MultiLineString, MultiPoint};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+/// assert_eq!(line_string.boundary(), MultiPoint::from(vec![(0., 0.), (10., 10.)]));
+///
+/// // The lines meet at (10, 0), which isn't on the boundary.
+/// let lines = MultiLineString::new(vec![
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+///     line_string![(x: 10., y: 0.), (x: 10., y: 10.)],
+/// ]);
+/// assert_eq!(lines.boundary(), MultiPoint::from(vec![(0., 0.), (10., 10.)]));
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+/// assert_eq!(polygon.boundary(), MultiLineString::new(vec![polygon.exterior().clone()]));
+/// ```
+pub trait Boundary {
+    type Output;
+
+    /// Returns the boundary of the geometry.
+    fn boundary(&self) -> Self::Output;
+}
+
+impl<T: CoordNum> Boundary for Point<T> {
+    type Output = MultiPoint<T>;
+
+    fn boundary(&self) -> MultiPoint<T> {
+        MultiPoint::new(vec![])
+    }
+}
+
+impl<T: CoordNum> Boundary for MultiPoint<T> {
+    type Output = MultiPoint<T>;
+
+    fn boundary(&self) -> MultiPoint<T> {
+        MultiPoint::new(vec![])
+    }
+}
+
+impl<T: CoordNum> Boundary for Line<T> {
+    type Output = MultiPoint<T>;
+
+    fn boundary(&self) -> MultiPoint<T> {
+        line_boundary([self.start, self.end])
+    }
+}
+
+impl<T: CoordNum> Boundary for LineString<T> {
+    type Output = MultiPoint<T>;
+
+    fn boundary(&self) -> MultiPoint<T> {
+        line_boundary(endpoints(self))
+    }
+}
+
+impl<T: CoordNum> Boundary for MultiLineString<T> {
+    type Output = MultiPoint<T>;
+
+    fn boundary(&self) -> MultiPoint<T> {
+        line_boundary(self.iter().flat_map(endpoints))
+    }
+}
+
+impl<T: CoordNum> Boundary for Polygon<T> {
+    type Output = MultiLineString<T>;
+
+    fn boundary(&self) -> MultiLineString<T> {
+        MultiLineString::new(rings(self).collect())
+    }
+}
+
+impl<T: CoordNum> Boundary for MultiPolygon<T> {
+    type Output = MultiLineString<T>;
+
+    fn boundary(&self) -> MultiLineString<T> {
+        MultiLineString::new(self.iter().flat_map(rings).collect())
+    }
+}
+
+impl<T: CoordNum> Boundary for Rect<T> {
+    type Output = MultiLineString<T>;
+
+    fn boundary(&self) -> MultiLineString<T> {
+        self.to_polygon().boundary()
+    }
+}
+
+impl<T: CoordNum> Boundary for Triangle<T> {
+    type Output = MultiLineString<T>;
+
+    fn boundary(&self) -> MultiLineString<T> {
+        self.to_polygon().boundary()
+    }
+}
+
+impl<T: CoordNum> Boundary for GeometryCollection<T> {
+    type Output = GeometryCollection<T>;
+
+    fn boundary(&self) -> GeometryCollection<T> {
+        let mut line_endpoints = vec![];
+        let mut polygon_rings = vec![];
+        collect_boundaries(self, &mut line_endpoints, &mut polygon_rings);
+
+        let mut boundary = vec![];
+        let points = line_boundary(line_endpoints);
+        if !points.0.is_empty() {
+            boundary.push(Geometry::MultiPoint(points));
+        }
+        if !polygon_rings.is_empty() {
+            boundary.push(Geometry::MultiLineString(MultiLineString::new(
+                polygon_rings,
+            )));
+        }
+        GeometryCollection::new_from(boundary)
+    }
+}
+
+impl<T: CoordNum> Boundary for Geometry<T> {
+    type Output = Geometry<T>;
+
+    fn boundary(&self) -> Geometry<T> {
+        match self {
+            Geometry::Point(g) => Geometry::MultiPoint(g.boundary()),
+            Geometry::Line(g) => Geometry::MultiPoint(g.boundary()),
+            Geometry::LineString(g) => Geometry::MultiPoint(g.boundary()),
+            Geometry::Polygon(g) => Geometry::MultiLineString(g.boundary()),
+            Geometry::MultiPoint(g) => Geometry::MultiPoint(g.boundary()),
+            Geometry::MultiLineString(g) => Geometry::MultiPoint(g.boundary()),
+            Geometry::MultiPolygon(g) => Geometry::MultiLineString(g.boundary()),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.boundary()),
+            Geometry::Rect(g) => Geometry::MultiLineString(g.boundary()),
+            Geometry::Triangle(g) => Geometry::MultiLineString(g.boundary()),
+        }
+    }
+}
+
+/// Adds the endpoints of the lines, and the rings of the polygons, in a collection.
+fn collect_boundaries<T: CoordNum>(
+    collection: &GeometryCollection<T>,
+    line_endpoints: &mut Vec<Coord<T>>,
+    polygon_rings: &mut Vec<LineString<T>>,
+) {
+    for geometry in collection {
+        match geometry {
+            Geometry::Point(_) | Geometry::MultiPoint(_) => {}
+            Geometry::Line(line) => line_endpoints.extend([line.start, line.end]),
+            Geometry::LineString(line_string) => line_endpoints.extend(endpoints(line_string)),
+            Geometry::MultiLineString(multi) => {
+                line_endpoints.extend(multi.iter().flat_map(endpoints))
+            }
+            Geometry::Polygon(polygon) => polygon_rings.extend(rings(polygon)),
+            Geometry::MultiPolygon(multi) => polygon_rings.extend(multi.iter().flat_map(rings)),
+            Geometry::Rect(rect) => polygon_rings.extend(rings(&rect.to_polygon())),
+            Geometry::Triangle(triangle) => polygon_rings.extend(rings(&triangle.to_polygon())),
+            Geometry::GeometryCollection(inner) => {
+                collect_boundaries(inner, line_endpoints, polygon_rings)
+            }
+        }
+    }
+}
+
+/// The first and last coordinates of a line string, if it isn't empty.
+fn endpoints<T: CoordNum>(line_string: &LineString<T>) -> impl Iterator<Item = Coord<T>> + '_ {
+    line_string
+        .0
+        .first()
+        .into_iter()
+        .chain(line_string.0.last())
+        .copied()
+}
+
+/// The non-empty rings of a polygon.
+fn rings<T: CoordNum>(polygon: &Polygon<T>) -> impl Iterator<Item = LineString<T>> + '_ {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .filter(|ring| !ring.0.is_empty())
+        .cloned()
+}
+
+/// The boundary of lines with the given endpoints: the points which are the endpoint of an odd
+/// number of lines, in order.
+pub(crate) fn line_boundary<T: CoordNum>(
+    endpoints: impl IntoIterator<Item = Coord<T>>,
+) -> MultiPoint<T> {
+    let mut endpoints: Vec<_> = endpoints.into_iter().collect();
+    endpoints.sort_by(lex_cmp);
+
+    let mut boundary = vec![];
+    let mut i = 0;
+    while i < endpoints.len() {
+        let count = endpoints[i..]
+            .iter()
+            .take_while(|c| **c == endpoints[i])
+            .count();
+        if count % 2 == 1 {
+            boundary.push(Point::from(endpoints[i]));
+        }
+        i += count;
+    }
+    MultiPoint::new(boundary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, point, polygon};
+    use wkt::TryFromWkt;
+
+    #[test]
+    fn points() {
+        assert!(point!(x: 1., y: 1.).boundary().0.is_empty());
+        let geometry = Geometry::from(MultiPoint::from(vec![(1., 1.), (2., 2.)]));
+        assert_eq!(
+            geometry.boundary(),
+            Geometry::MultiPoint(MultiPoint::new(vec![]))
+        );
+    }
+
+    #[test]
+    fn line_strings() {
+        let closed = line_string![(x: 10, y: 10), (x: 20, y: 20), (x: 20, y: 10), (x: 10, y: 10)];
+        assert!(closed.boundary().0.is_empty());
+        assert!(LineString::<f64>::new(vec![]).boundary().0.is_empty());
+        // The endpoint also touches the middle of the line.
+        let line_string = LineString::<f64>::try_from_wkt_str(
+            "LINESTRING(40 40, 100 100, 180 100, 180 180, 100 180, 100 100)",
+        )
+        .unwrap();
+        assert_eq!(
+            line_string.boundary(),
+            MultiPoint::from(vec![(40., 40.), (100., 100.)])
+        );
+    }
+
+    #[test]
+    fn mod_2() {
+        let three = MultiLineString::<f64>::try_from_wkt_str(
+            "MULTILINESTRING((10 10, 20 20), (20 20, 30 20), (20 20, 30 30))",
+        )
+        .unwrap();
+        assert_eq!(
+            three.boundary(),
+            MultiPoint::from(vec![(10., 10.), (20., 20.), (30., 20.), (30., 30.)])
+        );
+        let four = MultiLineString::<f64>::try_from_wkt_str(
+            "MULTILINESTRING((10 10, 20 20), (20 20, 30 20), (20 20, 30 30), (20 20, 30 40))",
+        )
+        .unwrap();
+        assert_eq!(
+            four.boundary(),
+            MultiPoint::from(vec![(10., 10.), (30., 20.), (30., 30.), (30., 40.)])
+        );
+        let closed = MultiLineString::<f64>::try_from_wkt_str(
+            "MULTILINESTRING((10 10, 20 20), (20 20, 20 30, 30 30, 30 20, 20 20))",
+        )
+        .unwrap();
+        assert_eq!(
+            closed.boundary(),
+            MultiPoint::from(vec![(10., 10.), (20., 20.)])
+        );
+    }
+
+    #[test]
+    fn polygons() {
+        let polygon = polygon!(
+            exterior: [(x: 0, y: 0), (x: 10, y: 0), (x: 10, y: 10), (x: 0, y: 10)],
+            interiors: [[(x: 2, y: 2), (x: 4, y: 2), (x: 2, y: 4)]],
+        );
+        let boundary = polygon.boundary();
+        assert_eq!(boundary.0.len(), 2);
+        assert_eq!(&boundary.0[1], &polygon.interiors()[0]);
+        assert_eq!(Rect::new((0, 0), (1, 1)).boundary().0.len(), 1);
+        let empty: Polygon<f64> = polygon![];
+        assert!(empty.boundary().0.is_empty());
+    }
+
+    #[test]
+    fn collection() {
+        let collection = GeometryCollection::<f64>::try_from_wkt_str(
+            "GEOMETRYCOLLECTION(
+                POINT(0 0),
+                LINESTRING(0 0, 10 0),
+                LINESTRING(10 0, 10 10),
+                POLYGON((20 20, 30 20, 30 30, 20 20))
+            )",
+        )
+        .unwrap();
+        let boundary = collection.boundary();
+        assert_eq!(boundary.0.len(), 2);
+        assert_eq!(
+            boundary.0[0],
+            Geometry::MultiPoint(MultiPoint::from(vec![(0., 0.), (10., 10.)]))
+        );
+        assert_eq!(
+            boundary.0[1],
+            Geometry::MultiLineString(MultiLineString::new(vec![line_string![
+                (x: 20., y: 20.),
+                (x: 30., y: 20.),
+                (x: 30., y: 30.),
+                (x: 20., y: 20.),
+            ]]))
+        );
+    }
+}
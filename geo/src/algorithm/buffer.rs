@@ -0,0 +1,753 @@
+use rstar::RTreeNum;
+
+use crate::geometry::*;
+use crate::{coord, unary_union, BooleanOps, GeoFloat, Kernel, Orientation};
+
+/// Compute the buffer of a geometry: the area within a given distance of it.
+///
+/// A positive distance grows the geometry, and a negative distance shrinks it. Since points and
+/// lines have no area to shrink, their buffer is empty for distances which are not positive.
+/// Similarly, the buffer of a polygon at distance `0` is the polygon itself.
+///
+/// The buffer at a `NaN` or infinite distance is empty: an infinite buffer would cover the
+/// whole plane, which can't be represented by a polygon.
+///
+/// The curved parts of the buffer are approximated by line segments, and the shape of the
+/// corners and line ends is controlled by a [`BufferStyle`]. [`buffer`](Self::buffer) uses the
+/// default style: round joins and caps, with `8` segments per quarter circle.
+///
+/// The pieces making up the buffer are combined with [`BooleanOps`], so the result is subject
+/// to the same floating-point behaviour.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Area, Buffer};
+/// use geo::buffer::{BufferStyle, CapStyle};
+///
+/// let line = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+///
+/// let rounded = line.buffer(1.);
+/// assert!(rounded.unsigned_area() > 20.);
+///
+/// let flat = line.buffer_with_style(1., &BufferStyle::new().cap_style(CapStyle::Flat));
+/// assert_eq!(flat.unsigned_area(), 20.);
+/// ```
+pub trait Buffer {
+    type Scalar: GeoFloat;
+
+    /// Returns the buffer of the geometry at `distance`, using the default [`BufferStyle`].
+    fn buffer(&self, distance: Self::Scalar) -> MultiPolygon<Self::Scalar> {
+        self.buffer_with_style(distance, &BufferStyle::default())
+    }
+
+    /// Returns the buffer of the geometry at `distance`, using the given `style`.
+    fn buffer_with_style(
+        &self,
+        distance: Self::Scalar,
+        style: &BufferStyle<Self::Scalar>,
+    ) -> MultiPolygon<Self::Scalar>;
+}
+
+/// The shape of the buffer around the corners and ends of a geometry.
+///
+/// ```
+/// use geo::buffer::{BufferStyle, CapStyle, JoinStyle};
+///
+/// let style = BufferStyle::new()
+///     .join_style(JoinStyle::Mitre)
+///     .mitre_limit(2.)
+///     .cap_style(CapStyle::Square);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BufferStyle<T: GeoFloat> {
+    join_style: JoinStyle,
+    cap_style: CapStyle,
+    quadrant_segments: usize,
+    mitre_limit: T,
+}
+
+impl<T: GeoFloat> BufferStyle<T> {
+    /// Round joins and caps, with `8` segments per quarter circle and a mitre limit of `5`.
+    pub fn new() -> Self {
+        Self {
+            join_style: JoinStyle::Round,
+            cap_style: CapStyle::Round,
+            quadrant_segments: 8,
+            mitre_limit: T::from(5).unwrap(),
+        }
+    }
+
+    /// Set how the buffer turns around the outside of corners.
+    pub fn join_style(mut self, join_style: JoinStyle) -> Self {
+        self.join_style = join_style;
+        self
+    }
+
+    /// Set the shape of the buffer at the ends of lines.
+    pub fn cap_style(mut self, cap_style: CapStyle) -> Self {
+        self.cap_style = cap_style;
+        self
+    }
+
+    /// Set the number of line segments used to approximate a quarter circle. Values less than
+    /// `1` are treated as `1`.
+    pub fn quadrant_segments(mut self, quadrant_segments: usize) -> Self {
+        self.quadrant_segments = quadrant_segments.max(1);
+        self
+    }
+
+    /// Set how far a [`JoinStyle::Mitre`] corner may extend, as a multiple of the buffer
+    /// distance. Corners extending further are cut off at that distance.
+    pub fn mitre_limit(mut self, mitre_limit: T) -> Self {
+        self.mitre_limit = mitre_limit;
+        self
+    }
+
+    pub fn get_join_style(&self) -> JoinStyle {
+        self.join_style
+    }
+
+    pub fn get_cap_style(&self) -> CapStyle {
+        self.cap_style
+    }
+
+    pub fn get_quadrant_segments(&self) -> usize {
+        self.quadrant_segments
+    }
+
+    pub fn get_mitre_limit(&self) -> T {
+        self.mitre_limit
+    }
+}
+
+impl<T: GeoFloat> Default for BufferStyle<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How the buffer turns around the outside of a corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoinStyle {
+    /// Follow a circular arc around the corner.
+    Round,
+    /// Extend the sides until they meet in a point, up to the mitre limit.
+    Mitre,
+    /// Cut the corner with a straight line.
+    Bevel,
+}
+
+/// The shape of the buffer at the ends of a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CapStyle {
+    /// End in a semicircle around the end point.
+    Round,
+    /// End at the end point.
+    Flat,
+    /// End in a half square around the end point.
+    Square,
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for Coord<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        point_buffer(*self, distance, style).into_iter().collect()
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for Point<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        self.0.buffer_with_style(distance, style)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for MultiPoint<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        let circles: Vec<_> = self
+            .0
+            .iter()
+            .filter_map(|p| point_buffer(p.0, distance, style))
+            .collect();
+        unary_union(&circles)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for Line<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        line_buffer(&[self.start, self.end], distance, style)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for LineString<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        line_buffer(&self.0, distance, style)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for MultiLineString<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        let buffers: Vec<_> = self
+            .0
+            .iter()
+            .flat_map(|ls| line_buffer(&ls.0, distance, style))
+            .collect();
+        unary_union(&buffers)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for Polygon<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        polygon_buffer(self, distance, style)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        let buffers: Vec<_> = self
+            .0
+            .iter()
+            .flat_map(|p| polygon_buffer(p, distance, style))
+            .collect();
+        unary_union(&buffers)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for Rect<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        polygon_buffer(&self.to_polygon(), distance, style)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for Triangle<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        polygon_buffer(&self.to_polygon(), distance, style)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for GeometryCollection<T> {
+    type Scalar = T;
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        let buffers: Vec<_> = self
+            .0
+            .iter()
+            .flat_map(|g| g.buffer_with_style(distance, style))
+            .collect();
+        unary_union(&buffers)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> Buffer for Geometry<T> {
+    type Scalar = T;
+
+    crate::geometry_delegate_impl! {
+        fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T>;
+    }
+}
+
+fn point_buffer<T: GeoFloat>(
+    center: Coord<T>,
+    distance: T,
+    style: &BufferStyle<T>,
+) -> Option<Polygon<T>> {
+    if !distance.is_finite() || distance <= T::zero() {
+        return None;
+    }
+    match style.cap_style {
+        CapStyle::Round => {
+            let mut ring = vec![];
+            arc(
+                center,
+                distance,
+                T::zero(),
+                T::from(2.0 * std::f64::consts::PI).unwrap(),
+                style.quadrant_segments,
+                &mut ring,
+            );
+            ring.pop();
+            Some(Polygon::new(LineString::new(ring), vec![]))
+        }
+        CapStyle::Square => {
+            let offset = coord! { x: distance, y: distance };
+            Some(Rect::new(center - offset, center + offset).to_polygon())
+        }
+        CapStyle::Flat => None,
+    }
+}
+
+/// The buffer of a line, given by its vertices. Closed lines are treated as rings, with a
+/// join in place of the caps.
+fn line_buffer<T: GeoFloat + RTreeNum>(
+    coords: &[Coord<T>],
+    distance: T,
+    style: &BufferStyle<T>,
+) -> MultiPolygon<T> {
+    let mut coords = coords.to_vec();
+    coords.dedup();
+    match coords.len() {
+        0 => MultiPolygon::new(vec![]),
+        1 => point_buffer(coords[0], distance, style)
+            .into_iter()
+            .collect(),
+        _ if !distance.is_finite() || distance <= T::zero() => MultiPolygon::new(vec![]),
+        _ => unary_union(&segment_pieces(&coords, distance, style)),
+    }
+}
+
+fn polygon_buffer<T: GeoFloat + RTreeNum>(
+    polygon: &Polygon<T>,
+    distance: T,
+    style: &BufferStyle<T>,
+) -> MultiPolygon<T> {
+    if !distance.is_finite() {
+        return MultiPolygon::new(vec![]);
+    }
+    let area = MultiPolygon::new(vec![polygon.clone()]);
+    if distance == T::zero() {
+        return area.union(&MultiPolygon::new(vec![]));
+    }
+
+    // The buffer of the boundary, in which joins on the outside of the rings become the
+    // corners of the result.
+    let pieces: Vec<_> = std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .flat_map(|ring| {
+            let mut coords = ring.0.clone();
+            coords.dedup();
+            if coords.len() > 1 {
+                segment_pieces(&coords, distance.abs(), style)
+            } else {
+                vec![]
+            }
+        })
+        .collect();
+    let boundary = unary_union(&pieces);
+
+    if distance > T::zero() {
+        area.union(&boundary)
+    } else {
+        area.difference(&boundary)
+    }
+}
+
+/// The buffer of each segment of a line with at least two distinct, consecutive points.
+///
+/// Each piece is the rectangle around the segment, along with the join to the next segment on
+/// the outside of the corner, and caps at the ends of open lines. Together they cover the
+/// buffer of the line.
+///
+/// The join is closed off by a chord across the circle around the corner, rather than by
+/// returning to the corner itself. The rectangle of the next segment covers the extra area on
+/// the inside of the corner, and this avoids a vertex lying almost exactly on the edge of the
+/// next piece, which the overlay is sensitive to.
+fn segment_pieces<T: GeoFloat>(
+    coords: &[Coord<T>],
+    distance: T,
+    style: &BufferStyle<T>,
+) -> Vec<Polygon<T>> {
+    let coords = remove_straight_vertices(coords);
+    let is_closed = coords.first() == coords.last();
+    let num_segments = coords.len() - 1;
+    (0..num_segments)
+        .map(|idx| {
+            let start = coords[idx];
+            let end = coords[idx + 1];
+            let next = if idx + 1 < num_segments {
+                Some(coords[idx + 2])
+            } else if is_closed {
+                Some(coords[1])
+            } else {
+                None
+            };
+
+            let dir = unit(end - start);
+            let left = perp(dir);
+            let right = -left;
+
+            let mut ring = vec![start + right * distance, end + right * distance];
+            match next {
+                Some(next) => {
+                    let next_dir = unit(next - end);
+                    let next_left = perp(next_dir);
+                    let next_right = -next_left;
+                    match T::Ker::orient2d(start, end, next) {
+                        // Turning right, so the join is on the left
+                        Orientation::Clockwise => {
+                            ring.push(end + next_left * distance);
+                            join(end, distance, next_left, left, style, &mut ring);
+                        }
+                        Orientation::Collinear
+                            if dir.x * next_dir.x + dir.y * next_dir.y > T::zero() => {}
+                        // Turning left, or back on itself
+                        _ => {
+                            join(end, distance, right, next_right, style, &mut ring);
+                            ring.push(end + next_right * distance);
+                        }
+                    }
+                }
+                None => cap(end, distance, right, style, &mut ring),
+            }
+            ring.push(end + left * distance);
+            ring.push(start + left * distance);
+            if idx == 0 && !is_closed {
+                cap(start, distance, left, style, &mut ring);
+            }
+            Polygon::new(LineString::new(ring), vec![])
+        })
+        .collect()
+}
+
+/// Remove the interior vertices where the line continues straight on, which don't affect the
+/// buffer.
+pub(crate) fn remove_straight_vertices<T: GeoFloat>(coords: &[Coord<T>]) -> Vec<Coord<T>> {
+    let mut result: Vec<Coord<T>> = Vec::with_capacity(coords.len());
+    for (idx, &coord) in coords.iter().enumerate() {
+        if idx + 1 < coords.len() && !result.is_empty() {
+            let prev = result[result.len() - 1];
+            let next = coords[idx + 1];
+            let is_straight = T::Ker::orient2d(prev, coord, next) == Orientation::Collinear
+                && (coord.x - prev.x) * (next.x - coord.x)
+                    + (coord.y - prev.y) * (next.y - coord.y)
+                    > T::zero();
+            if is_straight {
+                continue;
+            }
+        }
+        result.push(coord);
+    }
+    result
+}
+
+/// Add the points of a join around `center`, turning counter-clockwise from the offset in
+/// direction `from` to the offset in direction `to`. The end points are not added.
+pub(crate) fn join<T: GeoFloat>(
+    center: Coord<T>,
+    distance: T,
+    from: Coord<T>,
+    to: Coord<T>,
+    style: &BufferStyle<T>,
+    out: &mut Vec<Coord<T>>,
+) {
+    let mut angle = ccw_angle(from, to);
+    if angle > T::from(1.5 * std::f64::consts::PI).unwrap() {
+        // Joins turn by at most half a circle, so this is a rounding error in a very slight
+        // turn.
+        angle = T::zero();
+    }
+    let (sin_half, cos_half) = (angle / (T::one() + T::one())).sin_cos();
+    match style.join_style {
+        JoinStyle::Round => {
+            let mut points = vec![];
+            arc(
+                center,
+                distance,
+                from.y.atan2(from.x),
+                angle,
+                style.quadrant_segments,
+                &mut points,
+            );
+            out.extend(&points[1..points.len() - 1]);
+        }
+        JoinStyle::Bevel => {}
+        JoinStyle::Mitre => {
+            // The direction from `center` to the mitre point.
+            let bisector = rotate(from, angle / (T::one() + T::one()));
+            let limit = style.mitre_limit * distance;
+            if distance <= limit * cos_half {
+                out.push(center + bisector * (distance / cos_half));
+            } else if distance * cos_half < limit {
+                // Cut the mitre off at the limit, perpendicular to the bisector.
+                let along = (limit - distance * cos_half) / sin_half;
+                out.push(center + from * distance + perp(from) * along);
+                out.push(center + to * distance - perp(to) * along);
+            }
+        }
+    }
+}
+
+/// Add the points of a cap at the end `center` of a line, turning counter-clockwise from the
+/// offset in direction `from` to the opposite offset. The end points are not added.
+pub(crate) fn cap<T: GeoFloat>(
+    center: Coord<T>,
+    distance: T,
+    from: Coord<T>,
+    style: &BufferStyle<T>,
+    out: &mut Vec<Coord<T>>,
+) {
+    match style.cap_style {
+        CapStyle::Round => {
+            let mut points = vec![];
+            arc(
+                center,
+                distance,
+                from.y.atan2(from.x),
+                T::from(std::f64::consts::PI).unwrap(),
+                style.quadrant_segments,
+                &mut points,
+            );
+            out.extend(&points[1..points.len() - 1]);
+        }
+        CapStyle::Flat => {}
+        CapStyle::Square => {
+            let outward = perp(from);
+            out.push(center + (from + outward) * distance);
+            out.push(center + (outward - from) * distance);
+        }
+    }
+}
+
+/// Add the points of a counter-clockwise circular arc, including both end points.
+///
+/// As in JTS, the arc is split evenly into as many segments as make the angle of each closest
+/// to that of the segments of a quarter circle.
+fn arc<T: GeoFloat>(
+    center: Coord<T>,
+    radius: T,
+    start_angle: T,
+    angle: T,
+    quadrant_segments: usize,
+    out: &mut Vec<Coord<T>>,
+) {
+    let quantum =
+        T::from(std::f64::consts::FRAC_PI_2).unwrap() / T::from(quadrant_segments).unwrap();
+    let num_segments = (angle / quantum + T::from(0.5).unwrap())
+        .floor()
+        .to_usize()
+        .unwrap_or(0)
+        .max(1);
+    let step = angle / T::from(num_segments).unwrap();
+    out.extend((0..num_segments).map(|i| {
+        let (sin, cos) = (start_angle + step * T::from(i).unwrap()).sin_cos();
+        center + coord! { x: cos, y: sin } * radius
+    }));
+    let (sin, cos) = (start_angle + angle).sin_cos();
+    out.push(center + coord! { x: cos, y: sin } * radius);
+}
+
+/// The counter-clockwise angle from `from` to `to`, in `(0, 2π]`.
+fn ccw_angle<T: GeoFloat>(from: Coord<T>, to: Coord<T>) -> T {
+    let two_pi = T::from(2.0 * std::f64::consts::PI).unwrap();
+    let angle = to.y.atan2(to.x) - from.y.atan2(from.x);
+    if angle <= T::zero() {
+        angle + two_pi
+    } else {
+        angle
+    }
+}
+
+pub(crate) fn unit<T: GeoFloat>(c: Coord<T>) -> Coord<T> {
+    c / c.x.hypot(c.y)
+}
+
+/// Rotate by 90° counter-clockwise.
+pub(crate) fn perp<T: GeoFloat>(c: Coord<T>) -> Coord<T> {
+    coord! { x: -c.y, y: c.x }
+}
+
+fn rotate<T: GeoFloat>(c: Coord<T>, angle: T) -> Coord<T> {
+    let (sin, cos) = angle.sin_cos();
+    coord! { x: c.x * cos - c.y * sin, y: c.x * sin + c.y * cos }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, point, polygon, Area, IsValid};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn point_buffer() {
+        let p = point!(x: 1., y: 2.);
+        let buffer = p.buffer(1.);
+        assert_eq!(buffer.0.len(), 1);
+        assert_eq!(buffer.0[0].exterior().0.len(), 33);
+        // A regular 32-gon inscribed in the unit circle
+        assert_relative_eq!(buffer.unsigned_area(), 3.1214451522580524, epsilon = 1e-10);
+
+        let square = p.buffer_with_style(1., &BufferStyle::new().cap_style(CapStyle::Square));
+        assert_relative_eq!(square.unsigned_area(), 4.);
+
+        let flat = p.buffer_with_style(1., &BufferStyle::new().cap_style(CapStyle::Flat));
+        assert!(flat.0.is_empty());
+
+        assert!(p.buffer(0.).0.is_empty());
+        assert!(p.buffer(-1.).0.is_empty());
+    }
+
+    #[test]
+    fn quadrant_segments() {
+        let p = point!(x: 0., y: 0.);
+        let buffer = p.buffer_with_style(1., &BufferStyle::new().quadrant_segments(1));
+        assert_relative_eq!(buffer.unsigned_area(), 2.);
+    }
+
+    #[test]
+    fn line_caps() {
+        let line = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let style = BufferStyle::new();
+
+        let flat = line.buffer_with_style(1., &style.cap_style(CapStyle::Flat));
+        assert_relative_eq!(flat.unsigned_area(), 20.);
+
+        let square = line.buffer_with_style(1., &style.cap_style(CapStyle::Square));
+        assert_relative_eq!(square.unsigned_area(), 24.);
+
+        let round = line.buffer(1.);
+        assert_relative_eq!(
+            round.unsigned_area(),
+            20. + 3.1214451522580524,
+            epsilon = 1e-10
+        );
+
+        assert!(line.buffer(-1.).0.is_empty());
+    }
+
+    #[test]
+    fn line_joins() {
+        // A right angle, turning left
+        let line = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let style = BufferStyle::new().cap_style(CapStyle::Flat);
+
+        // Two 10x2 rectangles overlapping in a unit square at the inner corner, and a corner
+        // on the outside.
+        let mitre = line.buffer_with_style(1., &style.join_style(JoinStyle::Mitre));
+        assert_relative_eq!(mitre.unsigned_area(), 40. - 1. + 1.);
+        assert!(mitre.is_valid());
+
+        let bevel = line.buffer_with_style(1., &style.join_style(JoinStyle::Bevel));
+        assert_relative_eq!(bevel.unsigned_area(), 40. - 1. + 0.5);
+
+        let round = line.buffer_with_style(1., &style);
+        let quarter_circle = 3.1214451522580524 / 4.;
+        assert_relative_eq!(
+            round.unsigned_area(),
+            40. - 1. + quarter_circle,
+            epsilon = 1e-10
+        );
+
+        // The same line, turning right
+        let line = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: -10.)];
+        let mitre = line.buffer_with_style(1., &style.join_style(JoinStyle::Mitre));
+        assert_relative_eq!(mitre.unsigned_area(), 40.);
+    }
+
+    #[test]
+    fn mitre_limit() {
+        // A sharp turn, where the mitre would extend far beyond the corner
+        let line = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 0., y: 1.)];
+        let style = BufferStyle::new()
+            .join_style(JoinStyle::Mitre)
+            .cap_style(CapStyle::Flat);
+
+        let unlimited = line.buffer_with_style(1., &style.mitre_limit(100.));
+        let limited = line.buffer_with_style(1., &style.mitre_limit(2.));
+        let bevel = line.buffer_with_style(1., &style.join_style(JoinStyle::Bevel));
+        assert!(unlimited.unsigned_area() > limited.unsigned_area());
+        assert!(limited.unsigned_area() > bevel.unsigned_area());
+
+        let extent = |mp: &MultiPolygon<f64>| {
+            use crate::BoundingRect;
+            mp.bounding_rect().unwrap().max().x
+        };
+        assert!(extent(&limited) < 12.1);
+        assert!(extent(&unlimited) > 12.1);
+    }
+
+    #[test]
+    fn closed_line_has_no_caps() {
+        let ring = line_string![
+            (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)
+        ];
+        let style = BufferStyle::new()
+            .join_style(JoinStyle::Mitre)
+            .cap_style(CapStyle::Flat);
+        let buffer = ring.buffer_with_style(1., &style);
+        assert_eq!(buffer.0.len(), 1);
+        assert_eq!(buffer.0[0].interiors().len(), 1);
+        assert_relative_eq!(buffer.unsigned_area(), 144. - 64.);
+    }
+
+    #[test]
+    fn polygon_buffer() {
+        let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let mitre = BufferStyle::new().join_style(JoinStyle::Mitre);
+
+        assert_relative_eq!(square.buffer_with_style(1., &mitre).unsigned_area(), 144.);
+        assert_relative_eq!(square.buffer_with_style(-1., &mitre).unsigned_area(), 64.);
+        assert_relative_eq!(square.buffer(-1.).unsigned_area(), 64.);
+        assert_relative_eq!(square.buffer(0.).unsigned_area(), 100.);
+        assert!(square.buffer(-5.).0.is_empty());
+        assert_relative_eq!(
+            square.buffer(1.).unsigned_area(),
+            140. + 3.1214451522580524,
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn non_finite_distance() {
+        let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let line = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let p = point!(x: 1., y: 2.);
+        for distance in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(square.buffer(distance).0.is_empty());
+            assert!(line.buffer(distance).0.is_empty());
+            assert!(p.buffer(distance).0.is_empty());
+        }
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        );
+        let mitre = BufferStyle::new().join_style(JoinStyle::Mitre);
+
+        // The hole closes up
+        let grown = poly.buffer_with_style(1., &mitre);
+        assert_eq!(grown.0.len(), 1);
+        assert!(grown.0[0].interiors().is_empty());
+        assert_relative_eq!(grown.unsigned_area(), 144.);
+
+        let shrunk = poly.buffer_with_style(-1., &mitre);
+        assert_eq!(shrunk.0[0].interiors().len(), 1);
+        assert_relative_eq!(shrunk.unsigned_area(), 64. - 16.);
+    }
+
+    #[test]
+    fn multi_geometries() {
+        let points = MultiPoint::from(vec![(0., 0.), (1., 0.), (10., 0.)]);
+        let style = BufferStyle::new().cap_style(CapStyle::Square);
+        let buffer = points.buffer_with_style(1., &style);
+        assert_eq!(buffer.0.len(), 2);
+        assert_relative_eq!(buffer.unsigned_area(), 6. + 4.);
+
+        let geometry = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            point!(x: 0., y: 0.).into(),
+            line_string![(x: 0., y: 0.), (x: 5., y: 0.)].into(),
+        ]));
+        let buffer = geometry.buffer_with_style(1., &style);
+        assert_relative_eq!(buffer.unsigned_area(), 14.);
+    }
+}
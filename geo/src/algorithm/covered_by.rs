@@ -0,0 +1,34 @@
+use crate::algorithm::Covers;
+
+/// Tests if a geometry is covered by another: it has no points outside the other.
+///
+/// In other words, the [DE-9IM] intersection matrix for (Self, Rhs) is `[T*F**F***]`,
+/// `[*TF**F***]`, `[**FT*F***]` or `[**F*TF***]`.
+///
+/// `CoveredBy` is equivalent to [`Covers`] with the arguments swapped.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon};
+/// use geo::algorithm::{CoveredBy, Within};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let edge = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+/// assert!(edge.is_covered_by(&polygon));
+/// assert!(!edge.is_within(&polygon));
+/// ```
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+pub trait CoveredBy<Other> {
+    fn is_covered_by(&self, b: &Other) -> bool;
+}
+
+impl<G1, G2> CoveredBy<G2> for G1
+where
+    G2: Covers<G1>,
+{
+    fn is_covered_by(&self, b: &G2) -> bool {
+        b.covers(self)
+    }
+}
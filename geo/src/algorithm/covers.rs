@@ -0,0 +1,24 @@
+/// Tests if a geometry covers another: the other geometry has no points outside it.
+///
+/// In other words, the [DE-9IM] intersection matrix for (Self, Rhs) is `[T*****FF*]`,
+/// `[*T****FF*]`, `[***T**FF*]` or `[****T*FF*]`.
+///
+/// Unlike [`Contains`](crate::Contains), a geometry covers the geometries lying on its
+/// boundary.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon};
+/// use geo::algorithm::{Contains, Covers};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let edge = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+/// assert!(polygon.covers(&edge));
+/// assert!(!polygon.contains(&edge));
+/// ```
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+pub trait Covers<Rhs = Self> {
+    fn covers(&self, rhs: &Rhs) -> bool;
+}
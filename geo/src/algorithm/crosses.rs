@@ -0,0 +1,26 @@
+/// Tests if a geometry crosses another: their interiors intersect in a geometry of lower
+/// dimension than the higher-dimensional of the two, and each has points outside the other.
+///
+/// In other words, the [DE-9IM] intersection matrix for (Self, Rhs) is `[T*T******]` if `Self`
+/// has a lower dimension than `Rhs`, `[T*****T**]` if it has a higher dimension, or
+/// `[0********]` if both are lines. Two points, or two areas, never cross.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon};
+/// use geo::algorithm::Crosses;
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let line_string = line_string![(x: 1., y: 1.), (x: 3., y: 3.)];
+/// assert!(line_string.crosses(&polygon));
+/// assert!(polygon.crosses(&line_string));
+///
+/// let other = line_string![(x: 1., y: 3.), (x: 3., y: 1.)];
+/// assert!(line_string.crosses(&other));
+/// ```
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+pub trait Crosses<Rhs = Self> {
+    fn crosses(&self, rhs: &Rhs) -> bool;
+}
@@ -0,0 +1,373 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use rstar::primitives::GeomWithData;
+use rstar::{Envelope, ParentNode, RTree, RTreeNode, RTreeNum, RTreeObject, AABB};
+
+use crate::nearest_points::{
+    components, containment_locations, coord_distance, nearest_on_lines, rect_distance, Component,
+};
+use crate::{
+    Coord, GeoFloat, Geometry, GeometryCollection, GeometryCow, GeometryLocation, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, NearestLocations, NearestPoints, Point, Polygon,
+    Rect, Triangle,
+};
+
+/// A segment of a geometry, with the index of its component and its index in the component.
+/// Points are zero length segments, without a segment index.
+type IndexedSegment<F> = GeomWithData<Line<F>, (usize, Option<usize>)>;
+
+/// A geometry prepared for repeated distance queries against other large geometries.
+///
+/// The segments of the geometry are stored in an R*-tree, so that queries can search both
+/// geometries' trees together, skipping the pairs of branches which are further apart than the
+/// nearest segments found so far. That's much faster than comparing every pair of segments,
+/// as [`EuclideanDistance`](crate::EuclideanDistance) does for most geometries, when both
+/// geometries have many segments.
+///
+/// Build a `DistanceIndex` from a reference to any geometry, and query it against another
+/// `DistanceIndex`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{DistanceIndex, EuclideanDistance, LineString, Polygon};
+///
+/// // Two wiggly coastlines with thousands of vertices
+/// let coastline = |x_offset: f64| -> Polygon {
+///     let exterior: LineString = (0..4000)
+///         .map(|i| {
+///             let angle = f64::from(i) * std::f64::consts::TAU / 4000.;
+///             let radius = 10. + (angle * 40.).sin();
+///             (x_offset + radius * angle.cos(), radius * angle.sin())
+///         })
+///         .collect();
+///     Polygon::new(exterior, vec![])
+/// };
+/// let (a, b) = (coastline(0.), coastline(30.));
+/// let index_a = DistanceIndex::from(&a);
+/// let index_b = DistanceIndex::from(&b);
+///
+/// let distance = index_a.distance(&index_b).unwrap();
+/// assert_eq!(distance, a.euclidean_distance(&b));
+/// assert!(index_a.is_within_distance(&index_b, distance));
+/// assert!(!index_a.is_within_distance(&index_b, distance - 0.1));
+///
+/// // the line to draw between the coastlines
+/// let line = index_a.nearest_points(&index_b).unwrap().line();
+/// assert_eq!(line.start.x.round(), 11.);
+/// ```
+pub struct DistanceIndex<F: GeoFloat + RTreeNum> {
+    components: Vec<Component<F>>,
+    segments: RTree<IndexedSegment<F>>,
+}
+
+impl<F: GeoFloat + RTreeNum> DistanceIndex<F> {
+    fn new(components: Vec<Component<F>>) -> Self {
+        let segments = components
+            .iter()
+            .flat_map(|component| {
+                component.elements().map(|(segment_index, segment)| {
+                    GeomWithData::new(segment, (component.index, segment_index))
+                })
+            })
+            .collect();
+        DistanceIndex {
+            components,
+            segments: RTree::bulk_load(segments),
+        }
+    }
+
+    /// The minimum euclidean distance to another geometry, or `None` if either is empty.
+    pub fn distance(&self, other: &DistanceIndex<F>) -> Option<F> {
+        self.nearest_points(other).map(|nearest| nearest.distance())
+    }
+
+    /// The nearest points of the geometry and another geometry, as found by
+    /// [`NearestPoints`], or `None` if either is empty.
+    pub fn nearest_points(&self, other: &DistanceIndex<F>) -> Option<NearestLocations<F>> {
+        self.search(other, None)
+    }
+
+    /// Whether the geometry is within `distance` of another geometry.
+    ///
+    /// This stops at the first pair of segments within `distance` of each other, rather than
+    /// searching for the nearest pair.
+    pub fn is_within_distance(&self, other: &DistanceIndex<F>, distance: F) -> bool {
+        self.search(other, Some(distance)).is_some()
+    }
+
+    /// Search for the nearest points of the geometries by expanding the nearest pair of
+    /// branches of their trees first.
+    ///
+    /// With a `threshold`, this only searches for points within that distance, and returns the
+    /// first pair it finds.
+    fn search(
+        &self,
+        other: &DistanceIndex<F>,
+        threshold: Option<F>,
+    ) -> Option<NearestLocations<F>> {
+        if self.components.is_empty() || other.components.is_empty() {
+            return None;
+        }
+        let root_a = Node::Parent(self.segments.root());
+        let root_b = Node::Parent(other.segments.root());
+        if threshold.map_or(false, |threshold| {
+            envelope_distance(&root_a.envelope(), &root_b.envelope()) > threshold
+        }) {
+            return None;
+        }
+
+        if let Some(nearest) = containment_locations(&self.components, &other.components) {
+            return Some(nearest);
+        }
+        if let Some(nearest) = containment_locations(&other.components, &self.components) {
+            return Some(nearest.reversed());
+        }
+
+        let mut nearest: Option<NearestLocations<F>> = None;
+        let is_pruned = |distance: F, nearest: &Option<NearestLocations<F>>| {
+            threshold.map_or(false, |threshold| distance > threshold)
+                || nearest.map_or(false, |nearest| distance >= nearest.distance())
+        };
+        let mut queue = BinaryHeap::new();
+        queue.push(NodePair::new(root_a, root_b));
+        while let Some(pair) = queue.pop() {
+            // the queue is ordered by distance, so no other pair can be nearer
+            if is_pruned(pair.distance, &nearest) {
+                break;
+            }
+            match (pair.a, pair.b) {
+                (Node::Leaf(a), Node::Leaf(b)) => {
+                    let (coord_a, coord_b) = nearest_on_lines(*a.geom(), *b.geom());
+                    let distance = coord_distance(coord_a, coord_b);
+                    if nearest.map_or(true, |nearest| distance < nearest.distance()) {
+                        nearest = Some(NearestLocations {
+                            from: location(coord_a, a),
+                            to: location(coord_b, b),
+                        });
+                    }
+                    if distance == F::zero()
+                        || threshold.map_or(false, |threshold| distance <= threshold)
+                    {
+                        return nearest;
+                    }
+                }
+                (a, b) => {
+                    let children = if a.expands_before(&b) {
+                        a.children()
+                            .map(|a| NodePair::new(a, b))
+                            .collect::<Vec<_>>()
+                    } else {
+                        b.children().map(|b| NodePair::new(a, b)).collect()
+                    };
+                    queue.extend(
+                        children
+                            .into_iter()
+                            .filter(|pair| !is_pruned(pair.distance, &nearest)),
+                    );
+                }
+            }
+        }
+        match threshold {
+            // every pair within the threshold returns early
+            Some(_) => None,
+            None => nearest,
+        }
+    }
+}
+
+impl<F: GeoFloat + RTreeNum> NearestPoints<F, DistanceIndex<F>> for DistanceIndex<F> {
+    fn nearest_points(&self, other: &DistanceIndex<F>) -> Option<NearestLocations<F>> {
+        DistanceIndex::nearest_points(self, other)
+    }
+}
+
+fn location<F: GeoFloat + RTreeNum>(
+    coord: Coord<F>,
+    segment: &IndexedSegment<F>,
+) -> GeometryLocation<F> {
+    let (component_index, segment_index) = segment.data;
+    GeometryLocation {
+        coord,
+        component_index,
+        segment_index,
+    }
+}
+
+fn envelope_distance<F: GeoFloat + RTreeNum>(a: &AABB<Point<F>>, b: &AABB<Point<F>>) -> F {
+    rect_distance(
+        Rect::new(a.lower().0, a.upper().0),
+        Rect::new(b.lower().0, b.upper().0),
+    )
+}
+
+/// A branch or segment of a `DistanceIndex`'s tree
+#[derive(Clone, Copy)]
+enum Node<'a, F: GeoFloat + RTreeNum> {
+    Parent(&'a ParentNode<IndexedSegment<F>>),
+    Leaf(&'a IndexedSegment<F>),
+}
+
+impl<'a, F: GeoFloat + RTreeNum> Node<'a, F> {
+    fn envelope(&self) -> AABB<Point<F>> {
+        match self {
+            Node::Parent(parent) => parent.envelope(),
+            Node::Leaf(segment) => segment.envelope(),
+        }
+    }
+
+    /// Whether to expand this node before `other`, preferring to split the larger branch.
+    fn expands_before(&self, other: &Node<'a, F>) -> bool {
+        match (self, other) {
+            (Node::Parent(_), Node::Leaf(_)) => true,
+            (Node::Leaf(_), _) => false,
+            (Node::Parent(a), Node::Parent(b)) => a.envelope().area() >= b.envelope().area(),
+        }
+    }
+
+    fn children(&self) -> impl Iterator<Item = Node<'a, F>> {
+        let children = match self {
+            Node::Parent(parent) => parent.children(),
+            Node::Leaf(_) => &[],
+        };
+        children.iter().map(|child| match child {
+            RTreeNode::Parent(parent) => Node::Parent(parent),
+            RTreeNode::Leaf(segment) => Node::Leaf(segment),
+        })
+    }
+}
+
+/// A pair of nodes from two trees, and the distance between their envelopes
+struct NodePair<'a, F: GeoFloat + RTreeNum> {
+    distance: F,
+    a: Node<'a, F>,
+    b: Node<'a, F>,
+}
+
+impl<'a, F: GeoFloat + RTreeNum> NodePair<'a, F> {
+    fn new(a: Node<'a, F>, b: Node<'a, F>) -> Self {
+        NodePair {
+            distance: envelope_distance(&a.envelope(), &b.envelope()),
+            a,
+            b,
+        }
+    }
+}
+
+// These impls give us a min-heap
+impl<F: GeoFloat + RTreeNum> Ord for NodePair<'_, F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.partial_cmp(&self.distance).unwrap()
+    }
+}
+
+impl<F: GeoFloat + RTreeNum> PartialOrd for NodePair<'_, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: GeoFloat + RTreeNum> Eq for NodePair<'_, F> {}
+
+impl<F: GeoFloat + RTreeNum> PartialEq for NodePair<'_, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance
+    }
+}
+
+macro_rules! impl_from {
+    ($($type:ident),*) => {
+        $(
+            impl<F: GeoFloat + RTreeNum> From<&$type<F>> for DistanceIndex<F> {
+                fn from(geometry: &$type<F>) -> Self {
+                    DistanceIndex::new(components(&GeometryCow::from(geometry)))
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    GeometryCollection,
+    Geometry
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{point, polygon, EuclideanDistance, Translate};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn agrees_with_euclidean_distance() {
+        let norway = Polygon::new(geo_test_fixtures::norway_main::<f64>(), vec![]);
+        let index = DistanceIndex::from(&norway);
+        let others: Vec<Geometry> = vec![
+            norway.translate(3., 2.).into(),
+            norway.translate(-0.5, 0.1).into(),
+            geo_test_fixtures::louisiana::<f64>().into(),
+            point!(x: 5., y: 60.).into(),
+            // inside
+            point!(x: 10., y: 61.).into(),
+            MultiPoint::new(vec![point!(x: 5., y: 60.), point!(x: 4., y: 62.)]).into(),
+        ];
+        for other in &others {
+            let expected = norway.euclidean_distance(other);
+            let nearest = index.nearest_points(&DistanceIndex::from(other)).unwrap();
+            assert_relative_eq!(nearest.distance(), expected);
+            assert!(norway.euclidean_distance(&Point::from(nearest.from.coord)) < 1e-12);
+            assert!(other.euclidean_distance(&Point::from(nearest.to.coord)) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn is_within_distance() {
+        let a = DistanceIndex::from(&polygon![
+            (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)
+        ]);
+        let b = DistanceIndex::from(&polygon![
+            (x: 13., y: 4.), (x: 20., y: 0.), (x: 20., y: 10.)
+        ]);
+        assert!(a.is_within_distance(&b, 3.));
+        assert!(a.is_within_distance(&b, 4.));
+        assert!(!a.is_within_distance(&b, 2.9));
+        assert!(!b.is_within_distance(&a, 2.9));
+
+        let inside = DistanceIndex::from(&point!(x: 5., y: 5.));
+        assert!(a.is_within_distance(&inside, 0.));
+        assert!(!b.is_within_distance(&inside, 7.));
+    }
+
+    #[test]
+    fn locations() {
+        let a = DistanceIndex::from(&MultiPoint::new(vec![
+            point!(x: 0., y: 0.),
+            point!(x: 10., y: 5.),
+        ]));
+        let b = DistanceIndex::from(&Line::from([(12., 0.), (12., 10.)]));
+        let nearest = a.nearest_points(&b).unwrap();
+        assert_eq!(nearest.from.component_index, 1);
+        assert_eq!(nearest.from.segment_index, None);
+        assert_eq!(nearest.to.coord, Coord::from((12., 5.)));
+        assert_eq!(nearest.to.segment_index, Some(0));
+    }
+
+    #[test]
+    fn empty() {
+        let empty = DistanceIndex::from(&LineString::<f64>::new(vec![]));
+        let point = DistanceIndex::from(&point!(x: 0., y: 0.));
+        assert_eq!(empty.distance(&point), None);
+        assert_eq!(point.nearest_points(&empty), None);
+        assert!(!empty.is_within_distance(&point, 1.));
+    }
+}
@@ -0,0 +1,26 @@
+/// Tests if two geometries are topologically equal: they cover the same points, regardless of
+/// their vertices or the order of their components.
+///
+/// In other words, the [DE-9IM] intersection matrix for (Self, Rhs) is `[T*F**FFF*]`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon};
+/// use geo::algorithm::EqualsTopo;
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// // The same square, starting from another corner and with an extra vertex.
+/// let other = polygon![(x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+/// assert!(polygon.equals_topo(&other));
+/// assert_ne!(polygon, other);
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+/// let reversed = line_string![(x: 2., y: 2.), (x: 0., y: 0.)];
+/// assert!(line_string.equals_topo(&reversed));
+/// ```
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+pub trait EqualsTopo<Rhs = Self> {
+    fn equals_topo(&self, rhs: &Rhs) -> bool;
+}
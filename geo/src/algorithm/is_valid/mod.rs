@@ -0,0 +1,512 @@
+use std::fmt;
+
+use crate::geometry::*;
+use crate::{CoordNum, GeoFloat};
+
+mod polygon;
+
+/// Determine whether a geometry is valid according to the [OGC Simple Feature Access][OGC-SFA]
+/// rules, and explain why it is not.
+///
+/// Many algorithms, such as [`BooleanOps`](crate::BooleanOps) and [`Relate`](crate::Relate),
+/// are only well defined on valid input. The rules checked are:
+///
+/// - All coordinates are finite (neither `NaN` nor infinite).
+/// - `Line`s and `LineString`s have at least two distinct points.
+/// - Polygon rings are closed and have at least three distinct points.
+/// - Polygon rings do not cross or overlap themselves or each other, and do not touch
+///   themselves.
+/// - Holes lie inside the exterior of their polygon, and are not nested inside each other.
+/// - The interior of each polygon is connected.
+/// - The polygons of a `MultiPolygon` do not overlap, and are not nested inside each other.
+///   They may touch at a finite number of points.
+///
+/// Repeated consecutive points are allowed. `GeometryCollection`s are valid when all of their
+/// members are valid.
+///
+/// The semantics follow those of [JTS's `IsValidOp`](https://github.com/locationtech/jts/blob/master/modules/core/src/main/java/org/locationtech/jts/operation/valid/IsValidOp.java).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon};
+/// use geo::is_valid::{IsValid, ValidationErrorKind};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// assert!(square.is_valid());
+///
+/// let bow_tie = polygon![(x: 0., y: 0.), (x: 10., y: 10.), (x: 10., y: 0.), (x: 0., y: 10.)];
+/// assert!(!bow_tie.is_valid());
+///
+/// let errors = bow_tie.validation_errors();
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].kind, ValidationErrorKind::SelfIntersection);
+/// assert_eq!(errors[0].location, coord! { x: 5., y: 5. });
+/// ```
+///
+/// [OGC-SFA]: https://www.ogc.org/standards/sfa
+pub trait IsValid {
+    type Scalar: GeoFloat;
+
+    /// Returns `true` if the geometry is valid.
+    fn is_valid(&self) -> bool {
+        self.validation_errors().is_empty()
+    }
+
+    /// Returns the reasons the geometry is invalid, or an empty `Vec` if it is valid.
+    ///
+    /// Checks run in stages: malformed coordinates and rings are reported first, then
+    /// intersections between rings, and finally the relative placement of rings and polygons.
+    /// Later stages assume the earlier ones passed, so they only run if no errors were found.
+    fn validation_errors(&self) -> Vec<ValidationError<Self::Scalar>>;
+}
+
+/// A reason a geometry is invalid, along with the location of the problem.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValidationError<T: CoordNum> {
+    pub kind: ValidationErrorKind,
+    pub location: Coord<T>,
+}
+
+impl<T: CoordNum> ValidationError<T> {
+    pub fn new(kind: ValidationErrorKind, location: Coord<T>) -> Self {
+        Self { kind, location }
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for ValidationError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at or near ({}, {})",
+            self.kind, self.location.x, self.location.y
+        )
+    }
+}
+
+impl<T: CoordNum + fmt::Display> std::error::Error for ValidationError<T> {}
+
+/// The kinds of problem reported by [`IsValid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationErrorKind {
+    /// A coordinate is `NaN` or infinite.
+    NonFiniteCoord,
+    /// A line has fewer than two distinct points, or a ring fewer than three.
+    TooFewPoints,
+    /// A polygon ring does not end at its start point.
+    UnclosedRing,
+    /// Segments of the geometry cross or overlap each other.
+    SelfIntersection,
+    /// A polygon ring touches itself at a point.
+    RingSelfIntersection,
+    /// A hole is not inside the exterior ring of its polygon.
+    HoleOutsideShell,
+    /// A hole lies inside another hole of the same polygon.
+    NestedHoles,
+    /// The holes of a polygon touch each other or the exterior in a way that splits the
+    /// interior into more than one piece.
+    DisconnectedInterior,
+    /// A polygon of a `MultiPolygon` lies inside another.
+    NestedShells,
+    /// Two rings are identical.
+    DuplicateRings,
+}
+
+impl fmt::Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            ValidationErrorKind::NonFiniteCoord => "Non-finite coordinate",
+            ValidationErrorKind::TooFewPoints => "Too few distinct points in geometry component",
+            ValidationErrorKind::UnclosedRing => "Ring is not closed",
+            ValidationErrorKind::SelfIntersection => "Self-intersection",
+            ValidationErrorKind::RingSelfIntersection => "Ring self-intersection",
+            ValidationErrorKind::HoleOutsideShell => "Hole lies outside shell",
+            ValidationErrorKind::NestedHoles => "Holes are nested",
+            ValidationErrorKind::DisconnectedInterior => "Interior is disconnected",
+            ValidationErrorKind::NestedShells => "Nested shells",
+            ValidationErrorKind::DuplicateRings => "Duplicate rings",
+        };
+        f.write_str(text)
+    }
+}
+
+fn non_finite_coords<'a, T: GeoFloat + 'a>(
+    coords: impl IntoIterator<Item = &'a Coord<T>>,
+) -> Vec<ValidationError<T>> {
+    coords
+        .into_iter()
+        .filter(|c| !(c.x.is_finite() && c.y.is_finite()))
+        .map(|c| ValidationError::new(ValidationErrorKind::NonFiniteCoord, *c))
+        .collect()
+}
+
+impl<T: GeoFloat> IsValid for Coord<T> {
+    type Scalar = T;
+
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        non_finite_coords([self])
+    }
+}
+
+impl<T: GeoFloat> IsValid for Point<T> {
+    type Scalar = T;
+
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        self.0.validation_errors()
+    }
+}
+
+impl<T: GeoFloat> IsValid for MultiPoint<T> {
+    type Scalar = T;
+
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        non_finite_coords(self.0.iter().map(|p| &p.0))
+    }
+}
+
+impl<T: GeoFloat> IsValid for Line<T> {
+    type Scalar = T;
+
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        let errors = non_finite_coords([&self.start, &self.end]);
+        if !errors.is_empty() {
+            return errors;
+        }
+        if self.start == self.end {
+            return vec![ValidationError::new(
+                ValidationErrorKind::TooFewPoints,
+                self.start,
+            )];
+        }
+        vec![]
+    }
+}
+
+impl<T: GeoFloat> IsValid for LineString<T> {
+    type Scalar = T;
+
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        let errors = non_finite_coords(&self.0);
+        if !errors.is_empty() {
+            return errors;
+        }
+        match self.0.first() {
+            Some(first) if self.0.iter().all(|c| c == first) => {
+                vec![ValidationError::new(
+                    ValidationErrorKind::TooFewPoints,
+                    *first,
+                )]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl<T: GeoFloat> IsValid for MultiLineString<T> {
+    type Scalar = T;
+
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        self.0
+            .iter()
+            .flat_map(|ls| ls.validation_errors())
+            .collect()
+    }
+}
+
+impl<T: GeoFloat> IsValid for Polygon<T> {
+    type Scalar = T;
+
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        polygon::polygonal_validation_errors(&[self])
+    }
+}
+
+impl<T: GeoFloat> IsValid for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        let polygons: Vec<_> = self.0.iter().collect();
+        polygon::polygonal_validation_errors(&polygons)
+    }
+}
+
+impl<T: GeoFloat> IsValid for Rect<T> {
+    type Scalar = T;
+
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        self.to_polygon().validation_errors()
+    }
+}
+
+impl<T: GeoFloat> IsValid for Triangle<T> {
+    type Scalar = T;
+
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        self.to_polygon().validation_errors()
+    }
+}
+
+impl<T: GeoFloat> IsValid for GeometryCollection<T> {
+    type Scalar = T;
+
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        self.0.iter().flat_map(|g| g.validation_errors()).collect()
+    }
+}
+
+impl<T: GeoFloat> IsValid for Geometry<T> {
+    type Scalar = T;
+
+    crate::geometry_delegate_impl! {
+        fn validation_errors(&self) -> Vec<ValidationError<T>>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, line_string, polygon};
+    use wkt::TryFromWkt;
+
+    fn kinds<G: IsValid>(geom: &G) -> Vec<ValidationErrorKind> {
+        geom.validation_errors().iter().map(|e| e.kind).collect()
+    }
+
+    #[test]
+    fn valid_simple_geometries() {
+        assert!(Point::new(1., 2.).is_valid());
+        assert!(
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 1., y: 0.), (x: 0., y: 1.)].is_valid()
+        );
+        assert!(LineString::<f64>::new(vec![]).is_valid());
+        assert!(MultiPoint::from(vec![(1., 1.), (1., 1.)]).is_valid());
+        assert!(Rect::new((0., 0.), (1., 1.)).is_valid());
+    }
+
+    #[test]
+    fn non_finite_coords() {
+        let point = Point::new(f64::NAN, 1.);
+        assert_eq!(kinds(&point), vec![ValidationErrorKind::NonFiniteCoord]);
+
+        let ls = line_string![(x: 0., y: 0.), (x: f64::INFINITY, y: 1.)];
+        assert_eq!(kinds(&ls), vec![ValidationErrorKind::NonFiniteCoord]);
+    }
+
+    #[test]
+    fn too_few_points() {
+        let ls = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(kinds(&ls), vec![ValidationErrorKind::TooFewPoints]);
+
+        let line = Line::new(coord! { x: 1., y: 1. }, coord! { x: 1., y: 1. });
+        assert_eq!(kinds(&line), vec![ValidationErrorKind::TooFewPoints]);
+
+        let poly = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(kinds(&poly), vec![ValidationErrorKind::TooFewPoints]);
+
+        let rect = Rect::new((0., 0.), (0., 1.));
+        assert!(!rect.is_valid());
+    }
+
+    #[test]
+    fn repeated_points_are_valid() {
+        let poly = polygon![(x: 0., y: 0.), (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        assert!(poly.is_valid());
+    }
+
+    #[test]
+    fn bow_tie() {
+        let poly = polygon![(x: 0., y: 0.), (x: 10., y: 10.), (x: 10., y: 0.), (x: 0., y: 10.)];
+        assert_eq!(
+            poly.validation_errors(),
+            vec![ValidationError::new(
+                ValidationErrorKind::SelfIntersection,
+                coord! { x: 5., y: 5. }
+            )]
+        );
+    }
+
+    #[test]
+    fn spike() {
+        let poly = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 10., y: 20.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        assert_eq!(kinds(&poly), vec![ValidationErrorKind::SelfIntersection]);
+    }
+
+    #[test]
+    fn self_touching_shell() {
+        // The shell touches itself at (5, 0), forming an "inverted" hole.
+        let poly =
+            Polygon::<f64>::try_from_wkt_str("POLYGON((0 0,5 0,3 3,7 3,5 0,10 0,10 10,0 10,0 0))")
+                .unwrap();
+        assert_eq!(
+            poly.validation_errors(),
+            vec![ValidationError::new(
+                ValidationErrorKind::RingSelfIntersection,
+                coord! { x: 5., y: 0. }
+            )]
+        );
+    }
+
+    #[test]
+    fn crossing_at_vertex() {
+        // The shell passes through (5, 0) twice, crossing itself there.
+        let poly =
+            Polygon::<f64>::try_from_wkt_str("POLYGON((0 0,5 0,7 3,3 3,5 0,10 0,10 10,0 10,0 0))")
+                .unwrap();
+        assert_eq!(kinds(&poly), vec![ValidationErrorKind::SelfIntersection]);
+
+        // Touching without crossing splits the polygon into two lobes.
+        let poly =
+            Polygon::<f64>::try_from_wkt_str("POLYGON((0 0,5 5,10 0,10 10,5 5,0 10,0 0))").unwrap();
+        assert_eq!(
+            kinds(&poly),
+            vec![ValidationErrorKind::RingSelfIntersection]
+        );
+    }
+
+    #[test]
+    fn hole_touching_shell_once_is_valid() {
+        let poly = Polygon::<f64>::try_from_wkt_str(
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(5 0,7 3,3 3,5 0))",
+        )
+        .unwrap();
+        assert!(poly.is_valid());
+    }
+
+    #[test]
+    fn hole_outside_shell() {
+        let poly = Polygon::<f64>::try_from_wkt_str(
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(20 20,21 20,21 21,20 21,20 20))",
+        )
+        .unwrap();
+        assert_eq!(
+            poly.validation_errors(),
+            vec![ValidationError::new(
+                ValidationErrorKind::HoleOutsideShell,
+                coord! { x: 20., y: 20. }
+            )]
+        );
+    }
+
+    #[test]
+    fn nested_holes() {
+        let poly = Polygon::<f64>::try_from_wkt_str(
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,9 1,9 9,1 9,1 1),(2 2,3 2,3 3,2 3,2 2))",
+        )
+        .unwrap();
+        assert_eq!(kinds(&poly), vec![ValidationErrorKind::NestedHoles]);
+    }
+
+    #[test]
+    fn overlapping_holes() {
+        let poly = Polygon::<f64>::try_from_wkt_str(
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,5 1,5 5,1 5,1 1),(3 3,7 3,7 7,3 7,3 3))",
+        )
+        .unwrap();
+        assert_eq!(
+            kinds(&poly),
+            vec![
+                ValidationErrorKind::SelfIntersection,
+                ValidationErrorKind::SelfIntersection
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_rings() {
+        let poly = Polygon::<f64>::try_from_wkt_str(
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,5 1,5 5,1 5,1 1),(5 1,1 1,1 5,5 5,5 1))",
+        )
+        .unwrap();
+        assert_eq!(kinds(&poly), vec![ValidationErrorKind::DuplicateRings]);
+    }
+
+    #[test]
+    fn disconnected_interior() {
+        // The hole touches the shell at two points, cutting the polygon in two.
+        let poly = Polygon::<f64>::try_from_wkt_str(
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(5 0,10 5,5 10,2 5,5 0))",
+        )
+        .unwrap();
+        assert_eq!(
+            kinds(&poly),
+            vec![ValidationErrorKind::DisconnectedInterior]
+        );
+
+        // Two holes touching each other and the shell form a cycle.
+        let poly = Polygon::<f64>::try_from_wkt_str(
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(5 0,6 4,5 5,4 4,5 0),(5 5,6 6,5 10,4 6,5 5))",
+        )
+        .unwrap();
+        assert_eq!(
+            kinds(&poly),
+            vec![ValidationErrorKind::DisconnectedInterior]
+        );
+    }
+
+    #[test]
+    fn holes_touching_at_shared_shell_point_is_valid() {
+        let poly = Polygon::<f64>::try_from_wkt_str(
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(5 0,4 3,1 3,5 0),(5 0,9 3,6 3,5 0))",
+        )
+        .unwrap();
+        assert!(poly.is_valid());
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let touching = MultiPolygon::<f64>::try_from_wkt_str(
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0)),((10 10,20 10,20 20,10 20,10 10)))",
+        )
+        .unwrap();
+        assert!(touching.is_valid());
+
+        let sharing_edge = MultiPolygon::<f64>::try_from_wkt_str(
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0)),((10 0,20 0,20 10,10 10,10 0)))",
+        )
+        .unwrap();
+        assert_eq!(
+            kinds(&sharing_edge),
+            vec![ValidationErrorKind::SelfIntersection]
+        );
+
+        let nested = MultiPolygon::<f64>::try_from_wkt_str(
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0)),((2 2,8 2,8 8,2 8,2 2)))",
+        )
+        .unwrap();
+        assert_eq!(
+            nested.validation_errors(),
+            vec![ValidationError::new(
+                ValidationErrorKind::NestedShells,
+                coord! { x: 2., y: 2. }
+            )]
+        );
+
+        let island_in_lake = MultiPolygon::<f64>::try_from_wkt_str(
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(1 1,9 1,9 9,1 9,1 1)),((2 2,8 2,8 8,2 8,2 2)))",
+        )
+        .unwrap();
+        assert!(island_in_lake.is_valid());
+    }
+
+    #[test]
+    fn geometry_collection() {
+        let gc = GeometryCollection::<f64>::try_from_wkt_str(
+            "GEOMETRYCOLLECTION(POINT(1 1),POLYGON((0 0,10 10,10 0,0 10,0 0)))",
+        )
+        .unwrap();
+        assert_eq!(kinds(&gc), vec![ValidationErrorKind::SelfIntersection]);
+        assert_eq!(
+            kinds(&Geometry::GeometryCollection(gc)),
+            vec![ValidationErrorKind::SelfIntersection]
+        );
+    }
+
+    #[test]
+    fn display() {
+        let error = ValidationError::new(
+            ValidationErrorKind::SelfIntersection,
+            coord! { x: 5., y: 5. },
+        );
+        assert_eq!(error.to_string(), "Self-intersection at or near (5, 5)");
+    }
+}
@@ -0,0 +1,500 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{non_finite_coords, ValidationError, ValidationErrorKind};
+use crate::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::sweep::{Cross, Intersections, LineOrPoint, SweepPoint};
+use crate::{
+    BoundingRect, Coord, GeoFloat, Intersects, Kernel, Line, LineIntersection, LineString, Polygon,
+    Rect,
+};
+
+/// Identifies a ring by the index of its polygon, and its index within the polygon (the
+/// exterior is `0`, followed by the interiors).
+type RingId = (usize, usize);
+
+/// A segment of a ring, tagged with where it came from.
+#[derive(Debug, Clone)]
+struct RingSegment<T: GeoFloat> {
+    line: Line<T>,
+    ring: RingId,
+    idx: usize,
+}
+
+impl<T: GeoFloat> Cross for RingSegment<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<Self::Scalar> {
+        self.line.into()
+    }
+}
+
+/// A ring with its consecutive repeated points removed.
+struct Ring<T: GeoFloat> {
+    id: RingId,
+    ls: LineString<T>,
+    bounds: Rect<T>,
+}
+
+impl<T: GeoFloat> Ring<T> {
+    fn num_segments(&self) -> usize {
+        self.ls.0.len() - 1
+    }
+
+    fn are_adjacent(&self, i: usize, j: usize) -> bool {
+        let n = self.num_segments();
+        (i + 1) % n == j || (j + 1) % n == i
+    }
+
+    /// The neighbours of the `idx`th vertex along the ring.
+    fn vertex_neighbours(&self, idx: usize) -> [Coord<T>; 2] {
+        let n = self.num_segments();
+        [self.ls.0[(idx + n - 1) % n], self.ls.0[idx + 1]]
+    }
+
+    /// Whether `other` has the same vertices as `self`, up to starting point and direction.
+    fn is_duplicate_of(&self, other: &Ring<T>) -> bool {
+        let n = self.num_segments();
+        if n != other.num_segments() {
+            return false;
+        }
+        let this = &self.ls.0[..n];
+        let that = &other.ls.0[..n];
+        (0..n).any(|shift| {
+            (0..n).all(|i| this[i] == that[(i + shift) % n])
+                || (0..n).all(|i| this[n - 1 - i] == that[(i + shift) % n])
+        })
+    }
+
+    /// Find a point of the ring that is not on the boundary of any of `others`.
+    ///
+    /// Vertices are tried first, followed by segment midpoints.
+    fn test_point<'a>(&self, others: impl Iterator<Item = &'a Ring<T>> + Clone) -> Option<Coord<T>>
+    where
+        T: 'a,
+    {
+        let two = T::one() + T::one();
+        let vertices = self.ls.0.iter().copied();
+        let midpoints = self
+            .ls
+            .lines()
+            .map(|l| (l.start + l.end) / two)
+            .collect::<Vec<_>>();
+        vertices.chain(midpoints).find(|c| {
+            others
+                .clone()
+                .all(|other| coord_pos_relative_to_ring(*c, &other.ls) != CoordPos::OnBoundary)
+        })
+    }
+}
+
+/// Validate a set of polygons that together form a polygonal geometry.
+pub(super) fn polygonal_validation_errors<T: GeoFloat>(
+    polygons: &[&Polygon<T>],
+) -> Vec<ValidationError<T>> {
+    let errors = non_finite_coords(
+        polygons
+            .iter()
+            .flat_map(|p| std::iter::once(p.exterior()).chain(p.interiors()))
+            .flat_map(|ring| &ring.0),
+    );
+    if !errors.is_empty() {
+        return errors;
+    }
+
+    let (rings, errors) = prepare_rings(polygons);
+    if !errors.is_empty() {
+        return errors;
+    }
+
+    let (touches, errors) = check_intersections(&rings);
+    if !errors.is_empty() {
+        return errors;
+    }
+
+    let mut errors = vec![];
+    for (poly_idx, poly_rings) in rings.iter().enumerate() {
+        check_connected_interior(poly_idx, &touches, &mut errors);
+        check_holes(poly_rings, &mut errors);
+    }
+    check_nested_shells(&rings, &mut errors);
+    errors
+}
+
+/// Check that rings are closed and large enough, and remove repeated points.
+///
+/// Returns the rings of each polygon; empty polygons and rings are omitted.
+fn prepare_rings<T: GeoFloat>(
+    polygons: &[&Polygon<T>],
+) -> (Vec<Vec<Ring<T>>>, Vec<ValidationError<T>>) {
+    let mut errors = vec![];
+    let mut rings = Vec::with_capacity(polygons.len());
+    for (poly_idx, polygon) in polygons.iter().enumerate() {
+        let mut poly_rings = vec![];
+        if !polygon.exterior().0.is_empty() {
+            let all_rings = std::iter::once(polygon.exterior()).chain(polygon.interiors());
+            for ring in all_rings.filter(|r| !r.0.is_empty()) {
+                if !ring.is_closed() {
+                    errors.push(ValidationError::new(
+                        ValidationErrorKind::UnclosedRing,
+                        ring.0[0],
+                    ));
+                    continue;
+                }
+                let mut coords = ring.0.clone();
+                coords.dedup();
+                if coords.len() < 4 {
+                    errors.push(ValidationError::new(
+                        ValidationErrorKind::TooFewPoints,
+                        ring.0[0],
+                    ));
+                    continue;
+                }
+                let ls = LineString::new(coords);
+                let bounds = ls.bounding_rect().expect("ring is not empty");
+                poly_rings.push(Ring {
+                    id: (poly_idx, poly_rings.len()),
+                    ls,
+                    bounds,
+                });
+            }
+        }
+        rings.push(poly_rings);
+    }
+    (rings, errors)
+}
+
+/// The rings of one polygon which touch at a point.
+struct Touch<T: GeoFloat> {
+    location: SweepPoint<T>,
+    polygon: usize,
+    rings: BTreeSet<usize>,
+}
+
+/// Find all crossings, overlaps and touches between the segments of the rings.
+///
+/// Crossings and overlaps are always invalid. Points where a ring touches itself are
+/// invalid, as are points where the rings cross over each other at a shared vertex. The
+/// remaining points where distinct rings of a polygon touch are returned.
+fn check_intersections<T: GeoFloat>(
+    rings: &[Vec<Ring<T>>],
+) -> (Vec<Touch<T>>, Vec<ValidationError<T>>) {
+    let ring = |id: RingId| &rings[id.0][id.1];
+
+    let segments: Vec<RingSegment<T>> = rings
+        .iter()
+        .flatten()
+        .flat_map(|r| {
+            r.ls.lines().enumerate().map(|(idx, line)| RingSegment {
+                line,
+                ring: r.id,
+                idx,
+            })
+        })
+        .collect();
+
+    let mut errors = vec![];
+    let mut overlapping_rings = BTreeSet::new();
+    let mut nodes: BTreeMap<SweepPoint<T>, BTreeSet<(RingId, usize)>> = BTreeMap::new();
+
+    for (a, b, intersection) in Intersections::from_iter(segments.iter()) {
+        if a.ring == b.ring && ring(a.ring).are_adjacent(a.idx, b.idx) {
+            if let LineIntersection::Collinear { intersection } = intersection {
+                // The ring doubles back on itself
+                errors.push(ValidationError::new(
+                    ValidationErrorKind::SelfIntersection,
+                    intersection.start,
+                ));
+            }
+            continue;
+        }
+        match intersection {
+            LineIntersection::Collinear { intersection } => {
+                let key = (a.ring.min(b.ring), a.ring.max(b.ring));
+                if !overlapping_rings.insert(key) {
+                    continue;
+                }
+                let kind = if a.ring != b.ring && ring(a.ring).is_duplicate_of(ring(b.ring)) {
+                    ValidationErrorKind::DuplicateRings
+                } else {
+                    ValidationErrorKind::SelfIntersection
+                };
+                errors.push(ValidationError::new(kind, intersection.start));
+            }
+            LineIntersection::SinglePoint {
+                intersection,
+                is_proper: true,
+            } => {
+                errors.push(ValidationError::new(
+                    ValidationErrorKind::SelfIntersection,
+                    intersection,
+                ));
+            }
+            LineIntersection::SinglePoint {
+                intersection,
+                is_proper: false,
+            } => {
+                let node = nodes.entry(intersection.into()).or_default();
+                node.insert((a.ring, a.idx));
+                node.insert((b.ring, b.idx));
+            }
+        }
+    }
+
+    let mut touches = vec![];
+    for (location, node_segments) in nodes {
+        let passes = passes_through_node(*location, &node_segments, ring);
+        if passes.len() < 2 || has_overlapping_edges(*location, &passes) {
+            // Overlapping edges are reported separately
+            continue;
+        }
+        if passes_cross(*location, &passes) {
+            errors.push(ValidationError::new(
+                ValidationErrorKind::SelfIntersection,
+                *location,
+            ));
+            continue;
+        }
+
+        let mut seen = BTreeSet::new();
+        let mut self_touching = BTreeSet::new();
+        for (id, _) in &passes {
+            if !seen.insert(*id) && self_touching.insert(*id) {
+                errors.push(ValidationError::new(
+                    ValidationErrorKind::RingSelfIntersection,
+                    *location,
+                ));
+            }
+        }
+
+        let mut by_polygon: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+        for (poly_idx, ring_idx) in seen {
+            by_polygon.entry(poly_idx).or_default().insert(ring_idx);
+        }
+        for (polygon, rings) in by_polygon {
+            if rings.len() > 1 {
+                touches.push(Touch {
+                    location,
+                    polygon,
+                    rings,
+                });
+            }
+        }
+    }
+
+    (touches, errors)
+}
+
+/// The ways the rings pass through a node.
+///
+/// Each pass is described by the two points adjacent to the node along the ring: either the
+/// neighbouring vertices, or the end points of the segment containing the node.
+fn passes_through_node<'a, T: GeoFloat + 'a>(
+    node: Coord<T>,
+    segments: &BTreeSet<(RingId, usize)>,
+    ring: impl Fn(RingId) -> &'a Ring<T>,
+) -> Vec<(RingId, [Coord<T>; 2])> {
+    let mut passes = vec![];
+    let mut vertices = BTreeSet::new();
+    for &(id, seg_idx) in segments {
+        let r = ring(id);
+        let start = r.ls.0[seg_idx];
+        let end = r.ls.0[seg_idx + 1];
+        if start == node {
+            vertices.insert((id, seg_idx));
+        } else if end == node {
+            vertices.insert((id, (seg_idx + 1) % r.num_segments()));
+        } else {
+            passes.push((id, [start, end]));
+        }
+    }
+    passes.extend(
+        vertices
+            .into_iter()
+            .map(|(id, vertex_idx)| (id, ring(id).vertex_neighbours(vertex_idx))),
+    );
+    passes
+}
+
+/// Whether any two edges of the passes through `node` leave it in the same direction.
+fn has_overlapping_edges<T: GeoFloat>(node: Coord<T>, passes: &[(RingId, [Coord<T>; 2])]) -> bool {
+    let mut edges: Vec<_> = passes.iter().flat_map(|(_, ends)| ends.iter()).collect();
+    edges.sort_by(|a, b| compare_angle(node, **a, **b));
+    edges
+        .windows(2)
+        .any(|pair| compare_angle(node, *pair[0], *pair[1]) == Ordering::Equal)
+}
+
+/// Whether any two passes through `node` cross each other.
+///
+/// Two passes cross if their edges alternate when ordered by angle around the node. The
+/// edges are assumed not to overlap.
+fn passes_cross<T: GeoFloat>(node: Coord<T>, passes: &[(RingId, [Coord<T>; 2])]) -> bool {
+    let mut edges: Vec<(Coord<T>, usize)> = passes
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, (_, ends))| ends.iter().map(move |end| (*end, idx)))
+        .collect();
+    edges.sort_by(|a, b| compare_angle(node, a.0, b.0));
+
+    let mut positions = vec![vec![]; passes.len()];
+    for (pos, (_, idx)) in edges.iter().enumerate() {
+        positions[*idx].push(pos);
+    }
+    positions.iter().enumerate().any(|(i, pos_i)| {
+        let between = |pos: usize| pos_i[0] < pos && pos < pos_i[1];
+        positions[i + 1..]
+            .iter()
+            .any(|pos_j| between(pos_j[0]) != between(pos_j[1]))
+    })
+}
+
+/// Order points by the angle they make around `origin`, counter-clockwise from the positive
+/// x-axis.
+fn compare_angle<T: GeoFloat>(origin: Coord<T>, a: Coord<T>, b: Coord<T>) -> Ordering {
+    fn quadrant<T: GeoFloat>(d: Coord<T>) -> u8 {
+        match (d.x >= T::zero(), d.y >= T::zero()) {
+            (true, true) => 0,
+            (false, true) => 1,
+            (false, false) => 2,
+            (true, false) => 3,
+        }
+    }
+    quadrant(a - origin)
+        .cmp(&quadrant(b - origin))
+        .then_with(|| T::Ker::orient2d(origin, a, b).as_ordering())
+}
+
+/// Check that the rings of a polygon which touch each other do not enclose part of the
+/// interior.
+///
+/// Consider the graph connecting each ring to the points it touches other rings at. The
+/// interior is disconnected exactly when this graph has a cycle.
+fn check_connected_interior<T: GeoFloat>(
+    poly_idx: usize,
+    touches: &[Touch<T>],
+    errors: &mut Vec<ValidationError<T>>,
+) {
+    let touches = touches.iter().filter(|t| t.polygon == poly_idx);
+    let num_rings = touches
+        .clone()
+        .flat_map(|t| t.rings.iter())
+        .max()
+        .map_or(0, |max| max + 1);
+    let mut components = DisjointSets::new(num_rings);
+    for touch in touches {
+        let point = components.push();
+        for &ring in &touch.rings {
+            if !components.union(ring, point) {
+                errors.push(ValidationError::new(
+                    ValidationErrorKind::DisconnectedInterior,
+                    *touch.location,
+                ));
+                return;
+            }
+        }
+    }
+}
+
+/// Check that the holes of a polygon are inside its exterior, and not inside each other.
+fn check_holes<T: GeoFloat>(rings: &[Ring<T>], errors: &mut Vec<ValidationError<T>>) {
+    let (shell, holes) = match rings.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    for hole in holes {
+        if let Some(pt) = hole.test_point(std::iter::once(shell)) {
+            if coord_pos_relative_to_ring(pt, &shell.ls) == CoordPos::Outside {
+                errors.push(ValidationError::new(
+                    ValidationErrorKind::HoleOutsideShell,
+                    pt,
+                ));
+            }
+        }
+    }
+    for inner in holes {
+        for outer in holes {
+            if inner.id == outer.id || !outer.bounds.intersects(&inner.bounds) {
+                continue;
+            }
+            if let Some(pt) = inner.test_point(std::iter::once(outer)) {
+                if coord_pos_relative_to_ring(pt, &outer.ls) == CoordPos::Inside {
+                    errors.push(ValidationError::new(ValidationErrorKind::NestedHoles, pt));
+                }
+            }
+        }
+    }
+}
+
+/// Check that no polygon lies inside another, unless it is inside one of its holes.
+fn check_nested_shells<T: GeoFloat>(rings: &[Vec<Ring<T>>], errors: &mut Vec<ValidationError<T>>) {
+    for inner in rings {
+        let inner_shell = match inner.first() {
+            Some(shell) => shell,
+            None => continue,
+        };
+        for outer in rings {
+            let outer_shell = match outer.first() {
+                Some(shell) => shell,
+                None => continue,
+            };
+            if inner_shell.id == outer_shell.id
+                || !outer_shell.bounds.intersects(&inner_shell.bounds)
+            {
+                continue;
+            }
+            let pt = match inner_shell.test_point(outer.iter()) {
+                Some(pt) => pt,
+                None => continue,
+            };
+            let in_shell = coord_pos_relative_to_ring(pt, &outer_shell.ls) == CoordPos::Inside;
+            let in_hole = || {
+                outer[1..]
+                    .iter()
+                    .any(|hole| coord_pos_relative_to_ring(pt, &hole.ls) == CoordPos::Inside)
+            };
+            if in_shell && !in_hole() {
+                errors.push(ValidationError::new(ValidationErrorKind::NestedShells, pt));
+            }
+        }
+    }
+}
+
+/// A minimal union-find structure.
+struct DisjointSets {
+    parents: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+        }
+    }
+
+    /// Add a new singleton set, returning its element.
+    fn push(&mut self) -> usize {
+        let idx = self.parents.len();
+        self.parents.push(idx);
+        idx
+    }
+
+    fn find(&mut self, mut idx: usize) -> usize {
+        while self.parents[idx] != idx {
+            self.parents[idx] = self.parents[self.parents[idx]];
+            idx = self.parents[idx];
+        }
+        idx
+    }
+
+    /// Merge the sets containing `a` and `b`. Returns `false` if they were already the same
+    /// set.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        self.parents[a] = b;
+        true
+    }
+}
@@ -0,0 +1,488 @@
+use geo_types::private_utils::line_segment_distance;
+use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
+use num_traits::FromPrimitive;
+
+use crate::line_intersection::line_intersection;
+use crate::nearest_points::{components, containment_locations, rect_distance, Component};
+use crate::{
+    Closest, Coord, GeoFloat, Geometry, GeometryCollection, GeometryCow, HaversineClosestPoint,
+    HaversineDistance, Intersects, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon, Rect, Triangle, MEAN_EARTH_RADIUS,
+};
+
+/// Determine whether two geometries are within a euclidean distance of each other, like
+/// PostGIS's `ST_DWithin`.
+///
+/// This is much faster than comparing the [`EuclideanDistance`](crate::EuclideanDistance) of the
+/// geometries with `distance` when they're near each other. It rejects geometries whose bounding
+/// rectangles are further apart than `distance`, and otherwise stops at the first pair of points
+/// or segments within `distance` of each other.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, polygon, IsWithinDistance};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let line_string = line_string![(x: 13., y: 20.), (x: 13., y: 5.), (x: 20., y: 5.)];
+///
+/// assert!(polygon.is_within_distance(&line_string, 3.));
+/// assert!(!polygon.is_within_distance(&line_string, 2.));
+///
+/// // a point inside the polygon is within any distance
+/// assert!(polygon.is_within_distance(&point!(x: 5., y: 5.), 0.));
+/// ```
+pub trait IsWithinDistance<F, Rhs = Self> {
+    fn is_within_distance(&self, rhs: &Rhs, distance: F) -> bool;
+}
+
+/// Determine whether two geometries are within a [haversine](crate::HaversineDistance) distance
+/// of each other, in meters.
+///
+/// Coordinates are longitudes (x) and latitudes (y) in degrees. Vertices are joined by great
+/// circle arcs, as for [`HaversineClosestPoint`], but, as there, whether a point is inside a
+/// polygon and whether two segments cross is decided in longitude and latitude coordinates.
+///
+/// This rejects geometries whose bounding rectangles are further apart than `distance`, and
+/// otherwise stops at the first pair of points or segments within `distance` of each other.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, IsWithinHaversineDistance};
+///
+/// let road = line_string![(x: -0.1, y: 51.5), (x: 0.1, y: 51.5)];
+/// let station = point!(x: 0., y: 51.505);
+///
+/// // the station is about 556 meters from the road
+/// assert!(road.is_within_haversine_distance(&station, 560.));
+/// assert!(!road.is_within_haversine_distance(&station, 550.));
+/// ```
+pub trait IsWithinHaversineDistance<F, Rhs = Self> {
+    fn is_within_haversine_distance(&self, rhs: &Rhs, distance: F) -> bool;
+}
+
+/// Determine whether two geometries are within a [geodesic](crate::GeodesicDistance) distance
+/// of each other on the WGS84 ellipsoid, in meters.
+///
+/// Coordinates are longitudes (x) and latitudes (y) in degrees. Vertices are joined by
+/// geodesics, but whether a point is inside a polygon and whether two segments cross is decided
+/// in longitude and latitude coordinates.
+///
+/// This rejects geometries whose bounding rectangles are further apart than `distance`, and
+/// otherwise stops at the first pair of points or segments within `distance` of each other.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, IsWithinGeodesicDistance};
+///
+/// let road = line_string![(x: -0.1, y: 51.5), (x: 0.1, y: 51.5)];
+/// let station = point!(x: 0., y: 51.505);
+///
+/// // the station is about 552 meters from the road
+/// assert!(road.is_within_geodesic_distance(&station, 560.));
+/// assert!(!road.is_within_geodesic_distance(&station, 550.));
+/// ```
+pub trait IsWithinGeodesicDistance<F, Rhs = Self> {
+    fn is_within_geodesic_distance(&self, rhs: &Rhs, distance: F) -> bool;
+}
+
+/// How distances are measured between the components of geometries
+trait Metric<F: GeoFloat> {
+    /// Whether any points of the rectangles may be within `distance` of each other.
+    fn rects_may_be_within(&self, a: Rect<F>, b: Rect<F>, distance: F) -> bool;
+
+    /// The distance between two segments, either of which may have zero length.
+    fn segment_distance(&self, a: Line<F>, b: Line<F>) -> F;
+}
+
+struct Euclidean;
+
+impl<F: GeoFloat> Metric<F> for Euclidean {
+    fn rects_may_be_within(&self, a: Rect<F>, b: Rect<F>, distance: F) -> bool {
+        rect_distance(a, b) <= distance
+    }
+
+    /// Measured as [`EuclideanDistance`](crate::EuclideanDistance) measures it, so that the two
+    /// agree exactly at the threshold.
+    fn segment_distance(&self, a: Line<F>, b: Line<F>) -> F {
+        if a.intersects(&b) {
+            return F::zero();
+        }
+        line_segment_distance(a.start, b.start, b.end)
+            .min(line_segment_distance(a.end, b.start, b.end))
+            .min(line_segment_distance(b.start, a.start, a.end))
+            .min(line_segment_distance(b.end, a.start, a.end))
+    }
+}
+
+/// A sphere with `radius`, on which distances are measured along great circles or geodesics.
+trait Spherical<F: GeoFloat + FromPrimitive> {
+    /// A radius of the sphere which doesn't overestimate the distance between any two points.
+    fn radius(&self) -> F;
+
+    fn point_segment_distance(&self, coord: Coord<F>, segment: Line<F>) -> F;
+}
+
+struct Haversine;
+
+impl<F: GeoFloat + FromPrimitive> Spherical<F> for Haversine {
+    fn radius(&self) -> F {
+        F::from(MEAN_EARTH_RADIUS).unwrap()
+    }
+
+    fn point_segment_distance(&self, coord: Coord<F>, segment: Line<F>) -> F {
+        let point = Point::from(coord);
+        if segment.start == segment.end {
+            return point.haversine_distance(&segment.start_point());
+        }
+        match segment.haversine_closest_point(&point) {
+            Closest::Intersection(closest) | Closest::SinglePoint(closest) => {
+                point.haversine_distance(&closest)
+            }
+            Closest::Indeterminate => point
+                .haversine_distance(&segment.start_point())
+                .min(point.haversine_distance(&segment.end_point())),
+        }
+    }
+}
+
+struct Geodesics;
+
+/// The smallest radius of curvature of the WGS84 ellipsoid, at the equator along a meridian,
+/// rounded down so that distances on a sphere with this radius are never longer than geodesics.
+const LEAST_WGS84_RADIUS: f64 = 6_300_000.;
+
+/// The precision, in meters, to which the nearest point of a geodesic segment is found
+const GEODESIC_TOLERANCE: f64 = 1e-3;
+
+impl Spherical<f64> for Geodesics {
+    fn radius(&self) -> f64 {
+        LEAST_WGS84_RADIUS
+    }
+
+    /// Find the nearest point of the segment with a golden section search along it.
+    fn point_segment_distance(&self, coord: Coord<f64>, segment: Line<f64>) -> f64 {
+        let geodesic = Geodesic::wgs84();
+        let distance_to =
+            |lat: f64, lon: f64| -> f64 { geodesic.inverse(coord.y, coord.x, lat, lon) };
+        let (start, end) = (segment.start, segment.end);
+        let (length, azimuth, _, _): (f64, f64, f64, f64) =
+            geodesic.inverse(start.y, start.x, end.y, end.x);
+        let distance_along = |s: f64| -> f64 {
+            let (lat, lon) = geodesic.direct(start.y, start.x, azimuth, s);
+            distance_to(lat, lon)
+        };
+
+        let ratio = (5f64.sqrt() - 1.) / 2.;
+        let (mut low, mut high) = (0., length);
+        let mut middle_low = high - ratio * (high - low);
+        let mut middle_high = low + ratio * (high - low);
+        let (mut distance_low, mut distance_high) =
+            (distance_along(middle_low), distance_along(middle_high));
+        while high - low > GEODESIC_TOLERANCE {
+            if distance_low < distance_high {
+                high = middle_high;
+                middle_high = middle_low;
+                distance_high = distance_low;
+                middle_low = high - ratio * (high - low);
+                distance_low = distance_along(middle_low);
+            } else {
+                low = middle_low;
+                middle_low = middle_high;
+                distance_low = distance_high;
+                middle_high = low + ratio * (high - low);
+                distance_high = distance_along(middle_high);
+            }
+        }
+        distance_low
+            .min(distance_high)
+            .min(distance_to(start.y, start.x))
+            .min(distance_to(end.y, end.x))
+    }
+}
+
+/// Measures distances on a [`Spherical`] model of the earth
+struct OnSphere<S>(S);
+
+impl<F: GeoFloat + FromPrimitive, S: Spherical<F>> Metric<F> for OnSphere<S> {
+    /// A point within `distance` of a point at latitude `φ` lies within `distance / radius`
+    /// radians of latitude, and within `asin(sin(distance / radius) / cos(φ))` radians of
+    /// longitude, unless that reaches a pole.
+    fn rects_may_be_within(&self, a: Rect<F>, b: Rect<F>, distance: F) -> bool {
+        let angle = distance / self.0.radius();
+        let latitude_gap = (b.min().y - a.max().y)
+            .max(a.min().y - b.max().y)
+            .max(F::zero());
+        if latitude_gap.to_radians() > angle {
+            return false;
+        }
+
+        let max_latitude = a.min().y.abs().max(a.max().y.abs()).to_radians();
+        if angle >= F::from(90).unwrap().to_radians() - max_latitude {
+            return true;
+        }
+        let longitude_angle = (angle.sin() / max_latitude.cos()).asin();
+        let full_turn = F::from(360).unwrap();
+        let longitude_gap = if a.min().x <= b.max().x && b.min().x <= a.max().x {
+            F::zero()
+        } else {
+            let eastwards = |gap: F| {
+                let gap = gap % full_turn;
+                if gap < F::zero() {
+                    gap + full_turn
+                } else {
+                    gap
+                }
+            };
+            eastwards(b.min().x - a.max().x).min(eastwards(a.min().x - b.max().x))
+        };
+        longitude_gap.to_radians() <= longitude_angle
+    }
+
+    fn segment_distance(&self, a: Line<F>, b: Line<F>) -> F {
+        if a.start != a.end && b.start != b.end && line_intersection(a, b).is_some() {
+            return F::zero();
+        }
+        let sphere = &self.0;
+        sphere
+            .point_segment_distance(a.start, b)
+            .min(sphere.point_segment_distance(a.end, b))
+            .min(sphere.point_segment_distance(b.start, a))
+            .min(sphere.point_segment_distance(b.end, a))
+    }
+}
+
+/// Whether any components of two geometries are within `distance` of each other.
+fn is_within_distance<F: GeoFloat>(
+    components_a: &[Component<F>],
+    components_b: &[Component<F>],
+    distance: F,
+    metric: &impl Metric<F>,
+) -> bool {
+    let bounding_rect = |components: &[Component<F>]| {
+        components
+            .iter()
+            .map(|component| component.bounding_rect)
+            .reduce(|a, b| {
+                Rect::new(
+                    Coord {
+                        x: a.min().x.min(b.min().x),
+                        y: a.min().y.min(b.min().y),
+                    },
+                    Coord {
+                        x: a.max().x.max(b.max().x),
+                        y: a.max().y.max(b.max().y),
+                    },
+                )
+            })
+    };
+    let (Some(rect_a), Some(rect_b)) = (bounding_rect(components_a), bounding_rect(components_b))
+    else {
+        return false;
+    };
+    if !metric.rects_may_be_within(rect_a, rect_b, distance) {
+        return false;
+    }
+
+    if containment_locations(components_a, components_b).is_some()
+        || containment_locations(components_b, components_a).is_some()
+    {
+        return true;
+    }
+
+    components_a.iter().any(|component_a| {
+        components_b.iter().any(|component_b| {
+            metric.rects_may_be_within(
+                component_a.bounding_rect,
+                component_b.bounding_rect,
+                distance,
+            ) && component_a.elements().any(|(_, segment_a)| {
+                component_b
+                    .elements()
+                    .any(|(_, segment_b)| metric.segment_distance(segment_a, segment_b) <= distance)
+            })
+        })
+    })
+}
+
+macro_rules! impl_is_within_distance {
+    ($($type:ident),*) => {
+        impl_is_within_distance!(@pairs [$($type),*] [$($type),*]);
+    };
+    (@pairs [$($type_a:ident),*] $types_b:tt) => {
+        $(impl_is_within_distance!(@impls $type_a $types_b);)*
+    };
+    (@impls $type_a:ident [$($type_b:ident),*]) => {
+        $(
+            impl<F: GeoFloat> IsWithinDistance<F, $type_b<F>> for $type_a<F> {
+                fn is_within_distance(&self, rhs: &$type_b<F>, distance: F) -> bool {
+                    is_within_distance(
+                        &components(&GeometryCow::from(self)),
+                        &components(&GeometryCow::from(rhs)),
+                        distance,
+                        &Euclidean,
+                    )
+                }
+            }
+
+            impl<F: GeoFloat + FromPrimitive> IsWithinHaversineDistance<F, $type_b<F>>
+                for $type_a<F>
+            {
+                fn is_within_haversine_distance(&self, rhs: &$type_b<F>, distance: F) -> bool {
+                    is_within_distance(
+                        &components(&GeometryCow::from(self)),
+                        &components(&GeometryCow::from(rhs)),
+                        distance,
+                        &OnSphere(Haversine),
+                    )
+                }
+            }
+
+            impl IsWithinGeodesicDistance<f64, $type_b<f64>> for $type_a<f64> {
+                fn is_within_geodesic_distance(&self, rhs: &$type_b<f64>, distance: f64) -> bool {
+                    is_within_distance(
+                        &components(&GeometryCow::from(self)),
+                        &components(&GeometryCow::from(rhs)),
+                        distance,
+                        &OnSphere(Geodesics),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_is_within_distance!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    GeometryCollection,
+    Geometry
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, line_string, point, polygon, EuclideanDistance};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn agrees_with_euclidean_distance() {
+        let polygon: Geometry = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        )
+        .into();
+        let others: Vec<Geometry> = vec![
+            point!(x: 5., y: 5.).into(),
+            point!(x: 15., y: 12.).into(),
+            line_string![(x: 3., y: 3.), (x: 4., y: 5.)].into(),
+            line_string![(x: -3., y: -5.), (x: -1., y: 20.)].into(),
+            Triangle::from([(12., 0.), (20., 1.), (13., 8.)]).into(),
+            Rect::new((1., 3.), (5., 7.)).into(),
+            MultiPoint::new(vec![point!(x: 30., y: 30.), point!(x: 5., y: 12.)]).into(),
+        ];
+        for other in &others {
+            let distance = polygon.euclidean_distance(other);
+            assert!(polygon.is_within_distance(other, distance), "{other:?}");
+            assert!(other.is_within_distance(&polygon, distance), "{other:?}");
+            if distance > 0. {
+                assert!(
+                    !polygon.is_within_distance(other, distance * 0.99),
+                    "{other:?}"
+                );
+            }
+        }
+
+        // points and line strings with random integer coordinates, where rounding must match
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..2000 {
+            let a = random_geometry(&mut rng);
+            let b = random_geometry(&mut rng);
+            let distance = a.euclidean_distance(&b);
+            assert!(a.is_within_distance(&b, distance), "{a:?} {b:?}");
+            assert!(b.is_within_distance(&a, distance), "{a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn agrees_with_euclidean_distance_rounding() {
+        let point = point!(x: 5., y: 1.);
+        let line_string =
+            line_string![(x: 7., y: 1.), (x: 1., y: 7.), (x: 4., y: 9.), (x: 8., y: 0.)];
+        assert!(point.is_within_distance(&line_string, point.euclidean_distance(&line_string)));
+
+        let a = line_string![(x: 5., y: 1.), (x: 2., y: 4.)];
+        let b = line_string![(x: 6., y: 5.), (x: 4., y: 7.)];
+        assert!(a.is_within_distance(&b, a.euclidean_distance(&b)));
+    }
+
+    fn random_geometry(rng: &mut StdRng) -> Geometry {
+        let len = rng.gen_range(1..5);
+        let mut coords = (0..len).map(|_| {
+            coord! {
+                x: f64::from(rng.gen_range(0..10)),
+                y: f64::from(rng.gen_range(0..10)),
+            }
+        });
+        match len {
+            1 => Point::from(coords.next().unwrap()).into(),
+            _ => LineString::new(coords.collect()).into(),
+        }
+    }
+
+    #[test]
+    fn contained() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let inner = polygon![(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.)];
+        assert!(polygon.is_within_distance(&inner, 0.));
+        assert!(inner.is_within_distance(&polygon, 0.));
+        assert!(polygon.is_within_haversine_distance(&inner, 0.));
+    }
+
+    #[test]
+    fn empty() {
+        let empty = LineString::<f64>::new(vec![]);
+        assert!(!empty.is_within_distance(&point!(x: 0., y: 0.), 100.));
+        assert!(!empty.is_within_haversine_distance(&empty, 100.));
+    }
+
+    #[test]
+    fn haversine_agrees_with_points() {
+        let a = MultiPoint::new(vec![point!(x: 179.9, y: 10.), point!(x: 0., y: 89.9)]);
+        let b = point!(x: -179.9, y: 10.);
+        let distance = a.0[0].haversine_distance(&b);
+        // across the antimeridian
+        assert!(a.is_within_haversine_distance(&b, distance * 1.01));
+        assert!(!a.is_within_haversine_distance(&b, distance * 0.99));
+
+        // across the pole
+        let c = point!(x: 180., y: 89.9);
+        let distance = a.0[1].haversine_distance(&c);
+        assert!(a.is_within_haversine_distance(&c, distance * 1.01));
+        assert!(!a.is_within_haversine_distance(&c, distance * 0.99));
+    }
+
+    #[test]
+    fn geodesic_segments() {
+        let geodesic = Geodesic::wgs84();
+        let segment = Line::from([(10., 40.), (11., 41.)]);
+        let (length, azimuth, _, _): (f64, f64, f64, f64) = geodesic.inverse(40., 10., 41., 11.);
+        // a kilometer from the middle of the segment, at right angles to it
+        let (lat, lon, middle_azimuth) = geodesic.direct(40., 10., azimuth, length / 2.);
+        let (lat, lon) = geodesic.direct(lat, lon, middle_azimuth - 90., 1000.);
+        let point = point!(x: lon, y: lat);
+        assert!(segment.is_within_geodesic_distance(&point, 1000.01));
+        assert!(!segment.is_within_geodesic_distance(&point, 999.99));
+        assert!(!segment.is_within_geodesic_distance(&point!(x: 12., y: 42.), 1000.));
+    }
+}
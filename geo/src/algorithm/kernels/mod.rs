@@ -38,6 +38,24 @@ pub trait Kernel<T: CoordNum> {
         }
     }
 
+    /// Gives the position of `d` relative to the circle through `a`, `b` and `c`, which must be
+    /// in counter-clockwise order. The output is `CounterClockwise` if `d` is inside the
+    /// circle, `Clockwise` if it is outside, and `Collinear` if it is on the circle.
+    fn incircle(a: Coord<T>, b: Coord<T>, c: Coord<T>, d: Coord<T>) -> Orientation {
+        let (ad, bd, cd) = (a - d, b - d, c - d);
+        let lift = |p: Coord<T>| p.x * p.x + p.y * p.y;
+        let res = lift(ad) * (bd.x * cd.y - cd.x * bd.y)
+            + lift(bd) * (cd.x * ad.y - ad.x * cd.y)
+            + lift(cd) * (ad.x * bd.y - bd.x * ad.y);
+        if res > Zero::zero() {
+            Orientation::CounterClockwise
+        } else if res < Zero::zero() {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+
     fn square_euclidean_distance(p: Coord<T>, q: Coord<T>) -> T {
         (p.x - q.x) * (p.x - q.x) + (p.y - q.y) * (p.y - q.y)
     }
@@ -41,4 +41,22 @@ where
             Orientation::Collinear
         }
     }
+
+    fn incircle(a: Coord<T>, b: Coord<T>, c: Coord<T>, d: Coord<T>) -> Orientation {
+        use robust::{incircle, Coord};
+
+        let to_robust = |p: crate::Coord<T>| Coord {
+            x: <f64 as NumCast>::from(p.x).unwrap(),
+            y: <f64 as NumCast>::from(p.y).unwrap(),
+        };
+        let position = incircle(to_robust(a), to_robust(b), to_robust(c), to_robust(d));
+
+        if position < 0. {
+            Orientation::Clockwise
+        } else if position > 0. {
+            Orientation::CounterClockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
 }
@@ -0,0 +1,151 @@
+use crate::maximum_inscribed_circle::inscribed;
+use crate::pole_of_inaccessibility::polylabel;
+use crate::{
+    BoundingRect, Closest, ClosestPoint, Coord, EuclideanDistance, GeoFloat, InscribedCircle,
+    InteriorPoint, Intersects, Point, Polygon,
+};
+
+/// Calculate the largest circle which doesn't overlap a set of obstacles, with its centre
+/// inside a boundary polygon.
+///
+/// The obstacles may be any geometry which has a [`ClosestPoint`], such as a `MultiPoint` of
+/// sites or a `GeometryCollection` of roads and buildings; the interior of the circle misses
+/// them all, while its edge touches the nearest. The centre is found to within `tolerance`,
+/// with the same search as [`PoleOfInaccessibility`](crate::PoleOfInaccessibility), and may be
+/// anywhere in the boundary, including on its edge, although the circle itself may extend
+/// beyond it.
+///
+/// Returns `None` if there are no obstacles, or the boundary is empty.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, LargestEmptyCircle, MultiPoint};
+///
+/// let boundary = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let sites = MultiPoint::from(vec![(2., 2.), (8., 2.), (2., 8.), (8., 8.)]);
+///
+/// let empty = sites.largest_empty_circle(&boundary, 1e-3).unwrap();
+/// // The farthest point from the sites is the middle, as the corners are nearer to them.
+/// assert!((empty.circle.radius - 18_f64.sqrt()).abs() < 1e-3);
+/// assert!((empty.circle.center.x - 5.).abs() < 1e-2);
+/// ```
+pub trait LargestEmptyCircle<T: GeoFloat> {
+    /// Returns the largest circle missing `self`, with its centre in `boundary`, to within
+    /// `tolerance`.
+    fn largest_empty_circle(
+        &self,
+        boundary: &Polygon<T>,
+        tolerance: T,
+    ) -> Option<InscribedCircle<T>>;
+}
+
+impl<T, G> LargestEmptyCircle<T> for G
+where
+    T: GeoFloat,
+    G: ClosestPoint<T>,
+{
+    fn largest_empty_circle(
+        &self,
+        boundary: &Polygon<T>,
+        tolerance: T,
+    ) -> Option<InscribedCircle<T>> {
+        let bounds = boundary.bounding_rect()?;
+        let start = boundary.interior_point()?;
+        if matches!(self.closest_point(&start), Closest::Indeterminate) {
+            return None;
+        }
+
+        let sqrt_2 = T::from(2).unwrap().sqrt();
+        let (center, _) = polylabel(bounds, [start.0], tolerance, |center, half| {
+            let point = Point::from(center);
+            let distance = obstacle_distance(self, point);
+            if boundary.intersects(&point) {
+                return (distance, distance + half * sqrt_2);
+            }
+            // Only a centre in the boundary is a candidate, but a cell which reaches the
+            // boundary may still have one.
+            let outside = point.euclidean_distance(boundary);
+            if outside <= half * sqrt_2 {
+                (-outside, distance + half * sqrt_2)
+            } else {
+                (-outside, -outside)
+            }
+        });
+        inscribed(center.into(), [self])
+    }
+}
+
+fn obstacle_distance<T: GeoFloat>(obstacles: &impl ClosestPoint<T>, point: Point<T>) -> T {
+    match obstacles.closest_point(&point) {
+        Closest::Intersection(_) | Closest::Indeterminate => T::zero(),
+        Closest::SinglePoint(p) => {
+            let d: Coord<T> = p.0 - point.0;
+            d.x.hypot(d.y)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, line_string, polygon, Contains, Geometry, GeometryCollection, MultiPoint};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn points() {
+        let boundary = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let sites = MultiPoint::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.)]);
+        let empty = sites.largest_empty_circle(&boundary, 1e-6).unwrap();
+        assert_relative_eq!(empty.circle.center, coord! { x: 5., y: 5. }, epsilon = 1e-5);
+        assert_relative_eq!(empty.circle.radius, 50_f64.sqrt(), epsilon = 1e-6);
+        assert!(sites.iter().any(|p| p.0 == empty.nearest));
+    }
+
+    #[test]
+    fn centre_in_boundary() {
+        // The obstacle is in the corner of the boundary, so the centre is in the opposite
+        // corner, although the circle extends outside.
+        let boundary = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let obstacle = Point::new(1., 1.);
+        let empty = obstacle.largest_empty_circle(&boundary, 1e-6).unwrap();
+        assert_relative_eq!(empty.circle.center, coord! { x: 4., y: 4. }, epsilon = 1e-5);
+        assert_relative_eq!(empty.circle.radius, 18_f64.sqrt(), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn mixed_obstacles() {
+        let boundary = polygon![(x: 0., y: 0.), (x: 20., y: 0.), (x: 20., y: 10.), (x: 0., y: 10.)];
+        let obstacles = GeometryCollection::new_from(vec![
+            Geometry::from(line_string![(x: 0., y: 0.), (x: 20., y: 0.)]),
+            Geometry::from(line_string![(x: 0., y: 10.), (x: 20., y: 10.)]),
+            Geometry::from(
+                polygon![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 10.), (x: 0., y: 10.)],
+            ),
+            Geometry::from(Point::new(20., 5.)),
+        ]);
+        // Any centre from (10, 5) to (15, 5) will do.
+        let empty = obstacles.largest_empty_circle(&boundary, 1e-3).unwrap();
+        assert_relative_eq!(empty.circle.radius, 5., epsilon = 1e-3);
+        assert!(boundary.contains(&Point::from(empty.circle.center)));
+        for obstacle in &obstacles {
+            assert!(
+                Point::from(empty.circle.center).euclidean_distance(obstacle)
+                    >= empty.circle.radius - 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let boundary = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(
+            MultiPoint::<f64>::new(vec![]).largest_empty_circle(&boundary, 1.),
+            None
+        );
+        assert_eq!(
+            Point::new(0., 0.).largest_empty_circle(&polygon![], 1.),
+            None
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoFloat, LineString, MultiLineString};
+
+/// Merge lines which meet end to end into maximal `LineString`s.
+///
+/// Lines are joined wherever exactly two of their ends meet, so the result only has ends where
+/// one, or more than two, lines end. Lines which form a closed loop are merged into a closed
+/// `LineString`. The merged lines follow the direction of the first of their parts, reversing
+/// the others as needed.
+///
+/// The lines are not split where they cross or touch, so they should be
+/// [noded](crate::Node) first.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, LineMerge, MultiLineString};
+///
+/// let lines = MultiLineString::new(vec![
+///     line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+///     line_string![(x: 2., y: 0.), (x: 1., y: 0.)],
+///     line_string![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.)],
+/// ]);
+/// assert_eq!(
+///     lines.line_merge(),
+///     MultiLineString::new(vec![line_string![
+///         (x: 0., y: 0.),
+///         (x: 1., y: 0.),
+///         (x: 2., y: 0.),
+///         (x: 3., y: 0.),
+///         (x: 3., y: 1.),
+///     ]])
+/// );
+/// ```
+pub trait LineMerge {
+    type Scalar: GeoFloat;
+
+    /// Returns the lines merged at every point where exactly two of them meet.
+    fn line_merge(&self) -> MultiLineString<Self::Scalar>;
+}
+
+impl<T: GeoFloat> LineMerge for MultiLineString<T> {
+    type Scalar = T;
+
+    fn line_merge(&self) -> MultiLineString<T> {
+        MultiLineString::new(merge(&self.0))
+    }
+}
+
+impl<T: GeoFloat> LineMerge for [LineString<T>] {
+    type Scalar = T;
+
+    fn line_merge(&self) -> MultiLineString<T> {
+        MultiLineString::new(merge(self))
+    }
+}
+
+/// Merges `lines` into maximal `LineString`s, through the points where exactly two meet.
+pub(crate) fn merge<T: GeoFloat>(lines: &[LineString<T>]) -> Vec<LineString<T>> {
+    let lines: Vec<_> = lines.iter().filter(|ls| !ls.0.is_empty()).collect();
+    let nodes = Nodes::new(lines.iter().map(|ls| (ls.0[0], ls.0[ls.0.len() - 1])));
+    let mut is_visited = vec![false; lines.len()];
+
+    // Follow the lines on from `node`, as long as it joins exactly two of them.
+    let follow = |mut node: usize, is_visited: &mut Vec<bool>| {
+        let mut coords = vec![];
+        while nodes.edges[node].len() == 2 {
+            let Some(&(idx, is_start)) =
+                nodes.edges[node].iter().find(|(idx, _)| !is_visited[*idx])
+            else {
+                break;
+            };
+            is_visited[idx] = true;
+            let line = &lines[idx].0;
+            if is_start {
+                coords.extend(line.iter().skip(1));
+                node = nodes.end[idx];
+            } else {
+                coords.extend(line.iter().rev().skip(1));
+                node = nodes.start[idx];
+            }
+        }
+        coords
+    };
+
+    let mut merged = vec![];
+    for idx in 0..lines.len() {
+        if is_visited[idx] {
+            continue;
+        }
+        is_visited[idx] = true;
+        let forwards = follow(nodes.end[idx], &mut is_visited);
+        let backwards = follow(nodes.start[idx], &mut is_visited);
+        let coords: Vec<_> = backwards
+            .into_iter()
+            .rev()
+            .chain(lines[idx].0.iter().copied())
+            .chain(forwards)
+            .collect();
+        merged.push(LineString::new(coords));
+    }
+    merged
+}
+
+/// The distinct end points of a list of lines.
+pub(crate) struct Nodes<T: GeoFloat> {
+    pub(crate) coords: Vec<Coord<T>>,
+    /// The lines at each node, and whether they start there.
+    pub(crate) edges: Vec<Vec<(usize, bool)>>,
+    /// The node at the start of each line.
+    pub(crate) start: Vec<usize>,
+    /// The node at the end of each line.
+    pub(crate) end: Vec<usize>,
+}
+
+impl<T: GeoFloat> Nodes<T> {
+    /// Finds the nodes at the start and end of each line.
+    pub(crate) fn new(lines: impl Iterator<Item = (Coord<T>, Coord<T>)>) -> Self {
+        let mut ends: Vec<_> = lines
+            .enumerate()
+            .flat_map(|(idx, (start, end))| [(start, idx, true), (end, idx, false)])
+            .collect();
+        let num_lines = ends.len() / 2;
+        ends.sort_by(|a, b| lex_cmp(&a.0, &b.0));
+
+        let mut nodes = Nodes {
+            coords: vec![],
+            edges: vec![],
+            start: vec![0; num_lines],
+            end: vec![0; num_lines],
+        };
+        for (coord, idx, is_start) in ends {
+            if nodes.coords.last() != Some(&coord) {
+                nodes.coords.push(coord);
+                nodes.edges.push(vec![]);
+            }
+            let node = nodes.coords.len() - 1;
+            nodes.edges[node].push((idx, is_start));
+            if is_start {
+                nodes.start[idx] = node;
+            } else {
+                nodes.end[idx] = node;
+            }
+        }
+        nodes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn stops_at_junctions() {
+        // Three lines meet at (1, 0), so none of them are merged there.
+        let lines = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.), (x: 1., y: 2.)],
+            line_string![(x: 1., y: 3.), (x: 1., y: 2.)],
+        ]);
+        assert_eq!(
+            lines.line_merge().0,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 1., y: 1.), (x: 1., y: 2.), (x: 1., y: 3.)],
+            ]
+        );
+    }
+
+    #[test]
+    fn closes_rings() {
+        let lines = [
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.)],
+            line_string![(x: 5., y: 5.), (x: 6., y: 5.), (x: 5., y: 6.), (x: 5., y: 5.)],
+        ];
+        let merged = lines.line_merge();
+        assert_eq!(merged.0.len(), 2);
+        assert_eq!(
+            merged.0[0],
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.), (x: 0., y: 0.)]
+        );
+        assert_eq!(merged.0[1], lines[2]);
+    }
+}
@@ -0,0 +1,305 @@
+use rstar::RTreeNum;
+
+use crate::algorithm::bool_ops::exterior_lines;
+use crate::geometry::*;
+use crate::{unary_union, BooleanOps, GeoFloat, Intersects};
+
+/// Repair invalid polygonal geometries.
+///
+/// The result is a valid [`MultiPolygon`] covering the same area as the input, where the area
+/// of the input is interpreted as follows:
+///
+/// - The area of each ring is the region it encloses using the even-odd rule. A self-crossing
+///   ring such as a bow-tie is split into its lobes, and a ring touching itself forms a hole
+///   (an "inverted hole") where the loop encloses area the rest of the ring doesn't.
+/// - The area of a polygon is the area of its exterior, minus the union of the areas of its
+///   holes. Holes that overlap, nest, or lie outside the exterior are handled accordingly.
+/// - The area of a multi-polygon is the union of the areas of its polygons.
+///
+/// Non-finite coordinates are dropped, unclosed rings are closed, and repeated points are
+/// removed. Parts of the input that enclose no area, such as spikes or rings with fewer than
+/// three distinct points, are dropped by [`make_valid`](Self::make_valid); use
+/// [`make_valid_keep_collapsed`](Self::make_valid_keep_collapsed) to keep them as lines and
+/// points.
+///
+/// The repair is implemented with [`BooleanOps`], so the result is subject to the same
+/// floating-point behaviour.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, IsValid, MakeValid};
+///
+/// let bow_tie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// assert!(!bow_tie.is_valid());
+///
+/// let repaired = bow_tie.make_valid();
+/// assert!(repaired.is_valid());
+/// assert_eq!(repaired.0.len(), 2);
+/// ```
+pub trait MakeValid {
+    type Scalar: GeoFloat;
+
+    /// Returns the valid polygonal part of the repaired geometry.
+    fn make_valid(&self) -> MultiPolygon<Self::Scalar>;
+
+    /// Returns the repaired geometry, including the parts which have collapsed to lines or
+    /// points.
+    ///
+    /// The collection holds the polygons of [`make_valid`](Self::make_valid), followed by
+    /// the collapsed parts of the input which are not covered by them.
+    fn make_valid_keep_collapsed(&self) -> GeometryCollection<Self::Scalar>;
+}
+
+impl<T: GeoFloat + RTreeNum> MakeValid for Polygon<T> {
+    type Scalar = T;
+
+    fn make_valid(&self) -> MultiPolygon<T> {
+        repair(std::iter::once(self), false).0
+    }
+
+    fn make_valid_keep_collapsed(&self) -> GeometryCollection<T> {
+        let (area, collapsed) = repair(std::iter::once(self), true);
+        collect(area, collapsed)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> MakeValid for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn make_valid(&self) -> MultiPolygon<T> {
+        repair(self.0.iter(), false).0
+    }
+
+    fn make_valid_keep_collapsed(&self) -> GeometryCollection<T> {
+        let (area, collapsed) = repair(self.0.iter(), true);
+        collect(area, collapsed)
+    }
+}
+
+/// The parts of a ring, after cleaning, that enclose no area.
+struct Collapsed<T: GeoFloat> {
+    lines: Vec<LineString<T>>,
+    points: Vec<Point<T>>,
+}
+
+/// Repair the polygons, returning the repaired area and, if requested, the collapsed parts
+/// of the input that the area doesn't cover.
+fn repair<'a, T: GeoFloat + RTreeNum + 'a>(
+    polygons: impl Iterator<Item = &'a Polygon<T>>,
+    keep_collapsed: bool,
+) -> (MultiPolygon<T>, Collapsed<T>) {
+    let mut parts = vec![];
+    let mut collapsed = Collapsed {
+        lines: vec![],
+        points: vec![],
+    };
+
+    for polygon in polygons {
+        let mut ring_area = |ring: &LineString<T>| {
+            let ring = clean_ring(ring);
+            let ring_area = even_odd_area(&ring);
+            if keep_collapsed {
+                collect_collapsed(&ring, &ring_area, &mut collapsed);
+            }
+            ring_area
+        };
+        let exterior = ring_area(polygon.exterior());
+        let holes: Vec<_> = polygon
+            .interiors()
+            .iter()
+            .flat_map(&mut ring_area)
+            .collect();
+        parts.extend(exterior.difference(&unary_union(&holes)));
+    }
+    let area = unary_union(&parts);
+
+    if keep_collapsed {
+        let lines = exterior_lines(&area, &MultiLineString::new(collapsed.lines));
+        collapsed.lines = lines.0.into_iter().filter(|l| l.0.len() > 1).collect();
+        collapsed.points.retain(|p| !area.intersects(p));
+    }
+    (area, collapsed)
+}
+
+/// Drop non-finite and repeated coordinates, and close the ring.
+fn clean_ring<T: GeoFloat>(ring: &LineString<T>) -> LineString<T> {
+    let mut coords: Vec<_> = ring
+        .0
+        .iter()
+        .filter(|c| c.x.is_finite() && c.y.is_finite())
+        .copied()
+        .collect();
+    coords.dedup();
+    let mut ring = LineString::new(coords);
+    ring.close();
+    ring
+}
+
+/// The region enclosed by a closed ring, using the even-odd rule.
+fn even_odd_area<T: GeoFloat>(ring: &LineString<T>) -> MultiPolygon<T> {
+    if ring.0.len() < 4 {
+        return MultiPolygon::new(vec![]);
+    }
+    MultiPolygon::new(vec![Polygon::new(ring.clone(), vec![])]).union(&MultiPolygon::new(vec![]))
+}
+
+/// Record the parts of `ring` which don't bound `ring_area`.
+fn collect_collapsed<T: GeoFloat>(
+    ring: &LineString<T>,
+    ring_area: &MultiPolygon<T>,
+    collapsed: &mut Collapsed<T>,
+) {
+    match ring.0.len() {
+        0 => {}
+        1 | 2 => collapsed.points.push(Point(ring.0[0])),
+        _ => {
+            let lines = exterior_lines(ring_area, &MultiLineString::new(vec![ring.clone()]));
+            collapsed.lines.extend(lines);
+        }
+    }
+}
+
+fn collect<T: GeoFloat>(area: MultiPolygon<T>, collapsed: Collapsed<T>) -> GeometryCollection<T> {
+    let polygons = area.0.into_iter().map(Geometry::Polygon);
+    let lines = collapsed.lines.into_iter().map(Geometry::LineString);
+    let points = collapsed.points.into_iter().map(Geometry::Point);
+    GeometryCollection::new_from(polygons.chain(lines).chain(points).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{polygon, Area, IsValid, Relate};
+    use wkt::TryFromWkt;
+
+    fn assert_repaired(input: &str, expected: &str) {
+        let input = MultiPolygon::<f64>::try_from_wkt_str(input).unwrap();
+        let expected = MultiPolygon::<f64>::try_from_wkt_str(expected).unwrap();
+        let actual = input.make_valid();
+        assert!(actual.is_valid(), "{:?}", actual.validation_errors());
+        assert!(
+            actual.relate(&expected).matches("T*F**FFF*").unwrap(),
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn valid_input_is_unchanged() {
+        assert_repaired(
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(2 2,2 4,4 4,4 2,2 2)),((20 0,30 0,30 10,20 0)))",
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(2 2,2 4,4 4,4 2,2 2)),((20 0,30 0,30 10,20 0)))",
+        );
+    }
+
+    #[test]
+    fn bow_tie() {
+        assert_repaired(
+            "MULTIPOLYGON(((0 0,2 2,2 0,0 2,0 0)))",
+            "MULTIPOLYGON(((0 0,1 1,0 2,0 0)),((1 1,2 0,2 2,1 1)))",
+        );
+    }
+
+    #[test]
+    fn self_touching_ring() {
+        // The loop at (5 0) encloses an "inverted hole".
+        assert_repaired(
+            "MULTIPOLYGON(((0 0,5 0,3 3,7 3,5 0,10 0,10 10,0 10,0 0)))",
+            "MULTIPOLYGON(((0 0,5 0,10 0,10 10,0 10,0 0),(5 0,3 3,7 3,5 0)))",
+        );
+    }
+
+    #[test]
+    fn overlapping_holes() {
+        assert_repaired(
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(1 1,5 1,5 5,1 5,1 1),(3 3,7 3,7 7,3 7,3 3)))",
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(1 1,5 1,5 3,7 3,7 7,3 7,3 5,1 5,1 1)))",
+        );
+    }
+
+    #[test]
+    fn nested_and_outside_holes() {
+        assert_repaired(
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(1 1,9 1,9 9,1 9,1 1),(2 2,3 2,3 3,2 3,2 2),(20 20,21 20,21 21,20 21,20 20)))",
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(1 1,9 1,9 9,1 9,1 1)))",
+        );
+    }
+
+    #[test]
+    fn hole_splitting_polygon() {
+        assert_repaired(
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(5 0,10 5,5 10,0 5,5 0)))",
+            "MULTIPOLYGON(((0 0,5 0,0 5,0 0)),((5 0,10 0,10 5,5 0)),((10 5,10 10,5 10,10 5)),((0 5,5 10,0 10,0 5)))",
+        );
+    }
+
+    #[test]
+    fn overlapping_polygons() {
+        assert_repaired(
+            "MULTIPOLYGON(((0 0,4 0,4 4,0 4,0 0)),((2 2,6 2,6 6,2 6,2 2)),((1 1,2 1,2 2,1 1)))",
+            "MULTIPOLYGON(((0 0,4 0,4 2,6 2,6 6,2 6,2 4,0 4,0 0)))",
+        );
+    }
+
+    #[test]
+    fn unclosed_and_non_finite() {
+        let poly = Polygon::new(
+            LineString::from(vec![
+                (0., 0.),
+                (10., 0.),
+                (f64::NAN, 3.),
+                (10., 10.),
+                (0., 10.),
+            ]),
+            vec![],
+        );
+        let repaired = poly.make_valid();
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.unsigned_area(), 100.);
+    }
+
+    #[test]
+    fn collapsed_parts() {
+        // A square with an outward spike, and a ring collapsed to a line
+        let poly = polygon![
+            (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 5.), (x: 15., y: 5.),
+            (x: 10., y: 5.), (x: 10., y: 10.), (x: 0., y: 10.)
+        ];
+        assert_eq!(poly.make_valid().unsigned_area(), 100.);
+
+        let collection = poly.make_valid_keep_collapsed();
+        assert_eq!(collection.0.len(), 2);
+        assert!(matches!(collection.0[0], Geometry::Polygon(_)));
+        let expected = Geometry::LineString(LineString::from(vec![(10., 5.), (15., 5.)]));
+        assert!(collection.0[1]
+            .relate(&expected)
+            .matches("T*F**FFF*")
+            .unwrap());
+
+        let line = polygon![(x: 0., y: 0.), (x: 5., y: 0.), (x: 0., y: 0.)];
+        assert!(line.make_valid().0.is_empty());
+        let collection = line.make_valid_keep_collapsed();
+        assert_eq!(collection.0.len(), 1);
+        let expected = Geometry::LineString(LineString::from(vec![(0., 0.), (5., 0.)]));
+        assert!(collection.0[0]
+            .relate(&expected)
+            .matches("T*F**FFF*")
+            .unwrap());
+
+        let point = polygon![(x: 1., y: 1.), (x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(
+            point.make_valid_keep_collapsed(),
+            GeometryCollection::new_from(vec![Point::new(1., 1.).into()])
+        );
+    }
+
+    #[test]
+    fn collapsed_parts_inside_area_are_dropped() {
+        let mp = MultiPolygon::<f64>::try_from_wkt_str(
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0)),((2 2,4 2,2 2)),((5 5,5 5,5 5,5 5)))",
+        )
+        .unwrap();
+        let collection = mp.make_valid_keep_collapsed();
+        assert_eq!(collection.0.len(), 1);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    Circle, Closest, ClosestPoint, Coord, GeoFloat, Line, LineString, MultiPolygon, Point,
+    PoleOfInaccessibility, Polygon,
+};
+
+/// Calculate the largest circle which fits inside a polygon.
+///
+/// Its centre is the [pole of inaccessibility](PoleOfInaccessibility), found to within
+/// `tolerance`, and its radius is the distance from there to the nearest point of the
+/// polygon's boundary, which is also returned. Holes are part of the boundary, so the circle
+/// doesn't overlap them. It's `None` for empty polygons.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, MaximumInscribedCircle, Polygon};
+///
+/// let polygon: Polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 4.), (x: 0., y: 4.)];
+/// let inscribed = polygon.maximum_inscribed_circle(1e-3).unwrap();
+/// assert!((inscribed.circle.radius - 2.).abs() < 1e-3);
+///
+/// // The circle touches the long sides of the rectangle.
+/// assert!(inscribed.nearest.y.abs() < 1e-3 || (inscribed.nearest.y - 4.).abs() < 1e-3);
+/// ```
+pub trait MaximumInscribedCircle {
+    type Scalar: GeoFloat;
+
+    /// Returns the largest circle inside the polygon, to within `tolerance`.
+    fn maximum_inscribed_circle(
+        &self,
+        tolerance: Self::Scalar,
+    ) -> Option<InscribedCircle<Self::Scalar>>;
+}
+
+/// A circle, and a point where it touches the geometries which bound it.
+///
+/// Returned by [`MaximumInscribedCircle`] and [`LargestEmptyCircle`](crate::LargestEmptyCircle).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InscribedCircle<T: GeoFloat> {
+    pub circle: Circle<T>,
+    /// The nearest point to the centre of the circle, which is on its edge.
+    pub nearest: Coord<T>,
+}
+
+impl<T: GeoFloat> InscribedCircle<T> {
+    /// Returns a radius of the circle, from the centre to the nearest point.
+    pub fn radius_line(&self) -> Line<T> {
+        Line::new(self.circle.center, self.nearest)
+    }
+}
+
+impl<T: GeoFloat> MaximumInscribedCircle for Polygon<T> {
+    type Scalar = T;
+
+    fn maximum_inscribed_circle(&self, tolerance: T) -> Option<InscribedCircle<T>> {
+        let (center, _) = self.pole_of_inaccessibility(tolerance)?;
+        inscribed(center, rings(self))
+    }
+}
+
+impl<T: GeoFloat> MaximumInscribedCircle for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn maximum_inscribed_circle(&self, tolerance: T) -> Option<InscribedCircle<T>> {
+        let (center, _) = self.pole_of_inaccessibility(tolerance)?;
+        inscribed(center, self.iter().flat_map(rings))
+    }
+}
+
+fn rings<T: GeoFloat>(polygon: &Polygon<T>) -> impl Iterator<Item = &LineString<T>> {
+    std::iter::once(polygon.exterior()).chain(polygon.interiors())
+}
+
+/// The circle around `center` which reaches the nearest of the `boundaries`.
+pub(crate) fn inscribed<T: GeoFloat, G: ClosestPoint<T>>(
+    center: Point<T>,
+    boundaries: impl IntoIterator<Item = G>,
+) -> Option<InscribedCircle<T>> {
+    let closest = boundaries
+        .into_iter()
+        .fold(Closest::Indeterminate, |best, boundary| {
+            boundary.closest_point(&center).best_of_two(&best, center)
+        });
+    let nearest = match closest {
+        Closest::Intersection(p) | Closest::SinglePoint(p) => p.0,
+        Closest::Indeterminate => return None,
+    };
+    let d = nearest - center.0;
+    Some(InscribedCircle {
+        circle: Circle::new(center.0, d.x.hypot(d.y)),
+        nearest,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, polygon, Contains, EuclideanLength};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn triangle() {
+        // A 3-4-5 triangle, whose incircle has a radius of 1 and its centre at (1, 1).
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 3.)];
+        let inscribed = polygon.maximum_inscribed_circle(1e-6).unwrap();
+        assert_relative_eq!(inscribed.circle.radius, 1., epsilon = 1e-6);
+        assert_relative_eq!(
+            inscribed.circle.center,
+            coord! { x: 1., y: 1. },
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(
+            inscribed.radius_line().euclidean_length(),
+            inscribed.circle.radius
+        );
+    }
+
+    #[test]
+    fn hole() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        );
+        let inscribed = polygon.maximum_inscribed_circle(1e-3).unwrap();
+        // The circle fits in a corner, touching two outer edges and a corner of the hole.
+        assert_relative_eq!(inscribed.circle.radius, 4. - 8_f64.sqrt(), epsilon = 1e-3);
+        assert!(polygon.contains(&Point::from(inscribed.circle.center)));
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            polygon![(x: 10., y: 0.), (x: 16., y: 0.), (x: 16., y: 6.), (x: 10., y: 6.)],
+        ]);
+        let inscribed = multi.maximum_inscribed_circle(1e-6).unwrap();
+        assert_relative_eq!(inscribed.circle.radius, 3., epsilon = 1e-6);
+        assert!(inscribed.nearest.x >= 10.);
+    }
+
+    #[test]
+    fn empty() {
+        let empty: Polygon = polygon![];
+        assert_eq!(empty.maximum_inscribed_circle(1.), None);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::triangulate_delaunay::circumcenter;
+use crate::{ConvexHull, Coord, CoordsIter, GeoFloat, Kernel, LineString, Orientation, Polygon};
+
+/// Calculate the smallest circle enclosing a geometry.
+///
+/// The circle is found with [Welzl's algorithm](https://en.wikipedia.org/wiki/Smallest-circle_problem#Welzl's_algorithm)
+/// over the vertices of the [convex hull](ConvexHull), in expected linear time. It's `None` for
+/// empty geometries, and has a radius of zero for a single point.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon, MinimumBoundingCircle};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)];
+/// let circle = polygon.minimum_bounding_circle().unwrap();
+/// assert_eq!(circle.center, coord! { x: 2., y: 1. });
+/// assert_eq!(circle.radius, 5_f64.sqrt());
+///
+/// // The circle can be approximated by a polygon.
+/// assert_eq!(circle.to_polygon(32).exterior().0.len(), 33);
+/// ```
+pub trait MinimumBoundingCircle {
+    type Scalar: GeoFloat;
+
+    /// Returns the smallest circle containing every point of the geometry.
+    fn minimum_bounding_circle(&self) -> Option<Circle<Self::Scalar>>;
+}
+
+impl<T, G> MinimumBoundingCircle for G
+where
+    T: GeoFloat,
+    G: CoordsIter<Scalar = T>,
+{
+    type Scalar = T;
+
+    fn minimum_bounding_circle(&self) -> Option<Circle<T>> {
+        let hull = self.convex_hull();
+        let mut coords = hull.exterior().0.clone();
+        coords.pop();
+        if coords.is_empty() {
+            coords.extend(self.coords_iter().take(1));
+        }
+        welzl(coords)
+    }
+}
+
+/// A circle, given by its centre and radius.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle<T: GeoFloat> {
+    pub center: Coord<T>,
+    pub radius: T,
+}
+
+impl<T: GeoFloat> Circle<T> {
+    pub fn new(center: Coord<T>, radius: T) -> Self {
+        Circle { center, radius }
+    }
+
+    /// Returns `true` if `coord` is inside the circle, or on its edge.
+    pub fn contains_coord(&self, coord: &Coord<T>) -> bool {
+        let d = *coord - self.center;
+        d.x.hypot(d.y) <= self.radius
+    }
+
+    /// Approximates the circle with a regular polygon, whose `segments` vertices are on the
+    /// circle, going counter-clockwise from the point directly east of the centre. At least
+    /// three segments are used.
+    pub fn to_polygon(&self, segments: usize) -> Polygon<T> {
+        let segments = segments.max(3);
+        let step = T::from(std::f64::consts::TAU).unwrap() / T::from(segments).unwrap();
+        let mut coords: Vec<_> = (0..segments)
+            .map(|i| {
+                let (sin, cos) = (step * T::from(i).unwrap()).sin_cos();
+                Coord {
+                    x: self.center.x + self.radius * cos,
+                    y: self.center.y + self.radius * sin,
+                }
+            })
+            .collect();
+        coords.push(coords[0]);
+        Polygon::new(LineString::new(coords), vec![])
+    }
+}
+
+/// The smallest circle containing the `coords`.
+fn welzl<T: GeoFloat>(mut coords: Vec<Coord<T>>) -> Option<Circle<T>> {
+    // Visit the coordinates in a fixed pseudo-random order, for the expected running time.
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    for i in (1..coords.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        coords.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+
+    let mut circle = Circle::new(*coords.first()?, T::zero());
+    for i in 1..coords.len() {
+        if circle.contains_coord(&coords[i]) {
+            continue;
+        }
+        circle = Circle::new(coords[i], T::zero());
+        for j in 0..i {
+            if circle.contains_coord(&coords[j]) {
+                continue;
+            }
+            circle = diameter_circle(coords[i], coords[j]);
+            for k in 0..j {
+                if !circle.contains_coord(&coords[k]) {
+                    circle = circumcircle(coords[i], coords[j], coords[k]);
+                }
+            }
+        }
+    }
+    Some(circle)
+}
+
+/// The circle with `a` and `b` at opposite ends of a diameter.
+fn diameter_circle<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> Circle<T> {
+    let center = (a + b) / T::from(2).unwrap();
+    let d = a - center;
+    Circle::new(center, d.x.hypot(d.y))
+}
+
+/// The circle through `a`, `b` and `c`, or the smallest circle containing them if they're
+/// collinear.
+fn circumcircle<T: GeoFloat>(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> Circle<T> {
+    if T::Ker::orient2d(a, b, c) == Orientation::Collinear {
+        return [
+            diameter_circle(a, b),
+            diameter_circle(b, c),
+            diameter_circle(a, c),
+        ]
+        .into_iter()
+        .max_by(|x, y| x.radius.partial_cmp(&y.radius).unwrap())
+        .unwrap();
+    }
+    let center = circumcenter(a, b, c);
+    // The largest distance, so that all three points are inside despite rounding.
+    let radius = [a, b, c]
+        .iter()
+        .map(|p| (*p - center).x.hypot((*p - center).y))
+        .fold(T::zero(), T::max);
+    Circle::new(center, radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, line_string, point, Area, MultiPoint, Point};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn obtuse_triangle() {
+        // The circle is set by the longest side.
+        let line = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 5., y: 1.)];
+        let circle = line.minimum_bounding_circle().unwrap();
+        assert_eq!(circle, Circle::new(coord! { x: 5., y: 0. }, 5.));
+    }
+
+    #[test]
+    fn contains_all_points() {
+        let points: MultiPoint = (0..100)
+            .map(|i| {
+                let i = i as f64;
+                Point::new((i * 7.3) % 13. - 2., (i * 3.1) % 17. + (i * 0.7).sin())
+            })
+            .collect();
+        let circle = points.minimum_bounding_circle().unwrap();
+        for p in &points {
+            let d = p.0 - circle.center;
+            assert!(d.x.hypot(d.y) <= circle.radius + 1e-12);
+        }
+        // At least two of the points are on the circle.
+        let on_circle = points
+            .iter()
+            .filter(|p| {
+                let d = p.0 - circle.center;
+                (d.x.hypot(d.y) - circle.radius).abs() < 1e-9
+            })
+            .count();
+        assert!(on_circle >= 2);
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(
+            MultiPoint::<f64>::new(vec![]).minimum_bounding_circle(),
+            None
+        );
+        assert_eq!(
+            point!(x: 1., y: 2.).minimum_bounding_circle(),
+            Some(Circle::new(coord! { x: 1., y: 2. }, 0.))
+        );
+        let collinear = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.)];
+        let circle = collinear.minimum_bounding_circle().unwrap();
+        assert_eq!(circle.center, coord! { x: 1.5, y: 1.5 });
+    }
+
+    #[test]
+    fn to_polygon() {
+        let circle = Circle::new(coord! { x: 1., y: 1. }, 2.);
+        let polygon = circle.to_polygon(1000);
+        assert_relative_eq!(
+            polygon.unsigned_area(),
+            std::f64::consts::PI * 4.,
+            max_relative = 1e-4
+        );
+        assert_eq!(circle.to_polygon(0).exterior().0.len(), 4);
+    }
+}
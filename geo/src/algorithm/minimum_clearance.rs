@@ -0,0 +1,259 @@
+use rstar::primitives::GeomWithData;
+use rstar::{PointDistance, RTree, RTreeNum};
+
+use crate::{
+    Closest, ClosestPoint, Coord, EuclideanLength, GeoFloat, Geometry, GeometryCollection, Line,
+    LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Calculate the minimum clearance of a geometry: the smallest distance by which a vertex could
+/// be moved to make the geometry invalid, or to collapse it.
+///
+/// It's the smallest distance between two distinct vertices, or between a vertex and a segment
+/// which doesn't end at it, as in [JTS](https://locationtech.github.io/jts/javadoc/org/locationtech/jts/precision/MinimumClearance.html).
+/// Rounding the coordinates to a grid much finer than the minimum clearance can't change the
+/// topology of the geometry, so it measures how robust the geometry is to a loss of precision.
+///
+/// It's `None` if there are fewer than two distinct vertices, as no vertex movement could make
+/// the geometry invalid.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon, Line, MinimumClearance};
+///
+/// // A narrow spike, whose tip is 2 from the opposite edge.
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 100., y: 0.),
+///     (x: 100., y: 100.),
+///     (x: 0., y: 100.),
+///     (x: 98., y: 50.),
+/// ];
+/// assert_eq!(polygon.minimum_clearance(), Some(2.));
+/// assert_eq!(
+///     polygon.minimum_clearance_line(),
+///     Some(Line::new(coord! { x: 98., y: 50. }, coord! { x: 100., y: 50. }))
+/// );
+/// ```
+pub trait MinimumClearance {
+    type Scalar: GeoFloat;
+
+    /// Returns the minimum clearance.
+    fn minimum_clearance(&self) -> Option<Self::Scalar> {
+        self.minimum_clearance_line()
+            .map(|line| line.euclidean_length())
+    }
+
+    /// Returns a line whose length is the minimum clearance, from a vertex to the nearest
+    /// point of another vertex or segment.
+    fn minimum_clearance_line(&self) -> Option<Line<Self::Scalar>>;
+}
+
+/// The vertices and segments of a geometry.
+trait Facets<T: GeoFloat> {
+    fn add_facets(&self, vertices: &mut Vec<Coord<T>>, segments: &mut Vec<Line<T>>);
+}
+
+impl<T: GeoFloat> Facets<T> for Point<T> {
+    fn add_facets(&self, vertices: &mut Vec<Coord<T>>, _segments: &mut Vec<Line<T>>) {
+        vertices.push(self.0);
+    }
+}
+
+impl<T: GeoFloat> Facets<T> for Line<T> {
+    fn add_facets(&self, vertices: &mut Vec<Coord<T>>, segments: &mut Vec<Line<T>>) {
+        vertices.extend([self.start, self.end]);
+        segments.push(*self);
+    }
+}
+
+impl<T: GeoFloat> Facets<T> for LineString<T> {
+    fn add_facets(&self, vertices: &mut Vec<Coord<T>>, segments: &mut Vec<Line<T>>) {
+        vertices.extend(&self.0);
+        segments.extend(self.lines());
+    }
+}
+
+impl<T: GeoFloat> Facets<T> for Polygon<T> {
+    fn add_facets(&self, vertices: &mut Vec<Coord<T>>, segments: &mut Vec<Line<T>>) {
+        self.exterior().add_facets(vertices, segments);
+        for interior in self.interiors() {
+            interior.add_facets(vertices, segments);
+        }
+    }
+}
+
+impl<T: GeoFloat> Facets<T> for Rect<T> {
+    fn add_facets(&self, vertices: &mut Vec<Coord<T>>, segments: &mut Vec<Line<T>>) {
+        self.to_polygon().add_facets(vertices, segments);
+    }
+}
+
+impl<T: GeoFloat> Facets<T> for Triangle<T> {
+    fn add_facets(&self, vertices: &mut Vec<Coord<T>>, segments: &mut Vec<Line<T>>) {
+        self.to_polygon().add_facets(vertices, segments);
+    }
+}
+
+macro_rules! impl_facets_for_collection {
+    ($type:ident) => {
+        impl<T: GeoFloat> Facets<T> for $type<T> {
+            fn add_facets(&self, vertices: &mut Vec<Coord<T>>, segments: &mut Vec<Line<T>>) {
+                for g in self {
+                    g.add_facets(vertices, segments);
+                }
+            }
+        }
+    };
+}
+
+impl_facets_for_collection!(MultiPoint);
+impl_facets_for_collection!(MultiLineString);
+impl_facets_for_collection!(MultiPolygon);
+impl_facets_for_collection!(GeometryCollection);
+
+impl<T: GeoFloat> Facets<T> for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn add_facets(&self, vertices: &mut Vec<Coord<T>>, segments: &mut Vec<Line<T>>) -> ();
+    }
+}
+
+macro_rules! impl_minimum_clearance {
+    ($type:ident) => {
+        impl<T: GeoFloat + RTreeNum> MinimumClearance for $type<T> {
+            type Scalar = T;
+
+            fn minimum_clearance_line(&self) -> Option<Line<T>> {
+                let mut vertices = vec![];
+                let mut segments = vec![];
+                self.add_facets(&mut vertices, &mut segments);
+                clearance_line(vertices, segments)
+            }
+        }
+    };
+}
+
+impl_minimum_clearance!(Point);
+impl_minimum_clearance!(Line);
+impl_minimum_clearance!(LineString);
+impl_minimum_clearance!(Polygon);
+impl_minimum_clearance!(Rect);
+impl_minimum_clearance!(Triangle);
+impl_minimum_clearance!(MultiPoint);
+impl_minimum_clearance!(MultiLineString);
+impl_minimum_clearance!(MultiPolygon);
+impl_minimum_clearance!(GeometryCollection);
+impl_minimum_clearance!(Geometry);
+
+fn clearance_line<T: GeoFloat + RTreeNum>(
+    vertices: Vec<Coord<T>>,
+    segments: Vec<Line<T>>,
+) -> Option<Line<T>> {
+    // The shortest line found so far, and its squared length.
+    let mut best: Option<(Line<T>, T)> = None;
+    let mut update = |line: Line<T>, distance_2: T| {
+        if best.map_or(true, |(_, best_2)| distance_2 < best_2) {
+            best = Some((line, distance_2));
+        }
+    };
+
+    let segments = RTree::bulk_load(
+        segments
+            .into_iter()
+            .enumerate()
+            .map(|(idx, segment)| GeomWithData::new(segment, idx))
+            .collect(),
+    );
+    for vertex in &vertices {
+        // The nearest segment which doesn't end at the vertex, and the first of those in the
+        // input if several are as near.
+        let mut nearest = segments
+            .nearest_neighbor_iter_with_distance_2(&Point::from(*vertex))
+            .filter(|(segment, _)| {
+                segment.geom().start != *vertex && segment.geom().end != *vertex
+            });
+        let Some(mut best_segment) = nearest.next() else {
+            continue;
+        };
+        for (segment, distance_2) in nearest {
+            if distance_2 > best_segment.1 {
+                break;
+            }
+            if segment.data < best_segment.0.data {
+                best_segment = (segment, distance_2);
+            }
+        }
+        let (segment, distance_2) = best_segment;
+        let point = match segment.geom().closest_point(&Point::from(*vertex)) {
+            Closest::Intersection(p) | Closest::SinglePoint(p) => p.0,
+            Closest::Indeterminate => segment.geom().start,
+        };
+        update(Line::new(*vertex, point), distance_2);
+    }
+
+    let tree = RTree::bulk_load(vertices.clone());
+    for vertex in &vertices {
+        let nearest = tree
+            .nearest_neighbor_iter(vertex)
+            .find(|other| *other != vertex);
+        if let Some(other) = nearest {
+            update(Line::new(*vertex, *other), other.distance_2(vertex));
+        }
+    }
+    best.map(|(line, _)| line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, line_string, point, polygon};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn points() {
+        let points = MultiPoint::from(vec![(100., 100.), (10., 100.), (30., 100.)]);
+        assert_eq!(points.minimum_clearance(), Some(20.));
+        let same = MultiPoint::from(vec![(100., 100.), (100., 100.)]);
+        assert_eq!(same.minimum_clearance_line(), None);
+        assert_eq!(point!(x: 1., y: 1.).minimum_clearance(), None);
+    }
+
+    #[test]
+    fn line_string() {
+        let line_string = line_string![(x: 100., y: 100.), (x: 200., y: 100.), (x: 200., y: 200.), (x: 150., y: 150.)];
+        assert_eq!(
+            line_string.minimum_clearance_line(),
+            Some(Line::new(
+                coord! { x: 150., y: 150. },
+                coord! { x: 150., y: 100. }
+            ))
+        );
+        // The shortest segment.
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 1.)];
+        assert_eq!(line_string.minimum_clearance(), Some(1.));
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi = MultiPolygon::new(vec![
+            polygon![(x: 100., y: 100.), (x: 300., y: 100.), (x: 200., y: 200.)],
+            polygon![(x: 150., y: 250.), (x: 250., y: 250.), (x: 200., y: 220.)],
+        ]);
+        assert_eq!(multi.minimum_clearance(), Some(20.));
+        let geometry = Geometry::from(multi);
+        assert_eq!(geometry.minimum_clearance(), Some(20.));
+    }
+
+    #[test]
+    fn vertex_on_segment() {
+        let touching = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.)];
+        assert_relative_eq!(touching.minimum_clearance().unwrap(), 0.);
+    }
+
+    #[test]
+    fn empty() {
+        let empty: Polygon = polygon![];
+        assert_eq!(empty.minimum_clearance(), None);
+    }
+}
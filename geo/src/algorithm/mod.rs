@@ -18,16 +18,28 @@ pub use bearing::Bearing;
 
 /// Boolean Ops such as union, xor, difference;
 pub mod bool_ops;
-pub use bool_ops::{BooleanOps, OpType};
+pub use bool_ops::{unary_union, BooleanOps, LineBooleanOps, OpType, Overlay, UnaryUnion};
+
+/// Calculate the buffer of a `Geometry`.
+pub mod buffer;
+pub use buffer::Buffer;
 
 /// Calculate the bounding rectangle of a `Geometry`.
 pub mod bounding_rect;
 pub use bounding_rect::BoundingRect;
 
+/// Calculate the combinatorial boundary of a `Geometry`.
+pub mod boundary;
+pub use boundary::Boundary;
+
 /// Calculate the minimum rotated rectangle of a `Geometry`.
 pub mod minimum_rotated_rect;
 pub use minimum_rotated_rect::MinimumRotatedRect;
 
+/// Calculate the smallest circle enclosing a geometry.
+pub mod minimum_bounding_circle;
+pub use minimum_bounding_circle::{Circle, MinimumBoundingCircle};
+
 /// Calculate the centroid of a `Geometry`.
 pub mod centroid;
 pub use centroid::Centroid;
@@ -64,6 +76,18 @@ pub use convert_angle_unit::{ToDegrees, ToRadians};
 pub mod convex_hull;
 pub use convex_hull::ConvexHull;
 
+/// Determine whether `Geometry` `A` covers `Geometry` `B`.
+pub mod covers;
+pub use covers::Covers;
+
+/// Determine whether `Geometry` `A` is covered by `Geometry` `B`.
+pub mod covered_by;
+pub use covered_by::CoveredBy;
+
+/// Determine whether `Geometry` `A` crosses `Geometry` `B`.
+pub mod crosses;
+pub use crosses::Crosses;
+
 /// Cross track distance
 pub mod cross_track_distance;
 pub use cross_track_distance::CrossTrackDistance;
@@ -84,6 +108,14 @@ pub use densify::Densify;
 pub mod dimensions;
 pub use dimensions::HasDimensions;
 
+/// Index the segments of a geometry for fast distance queries against other large geometries.
+pub mod distance_index;
+pub use distance_index::DistanceIndex;
+
+/// Determine whether two `Geometries` are topologically equal.
+pub mod equals_topo;
+pub use equals_topo::EqualsTopo;
+
 /// Calculate the minimum Euclidean distance between two `Geometries`.
 pub mod euclidean_distance;
 pub use euclidean_distance::EuclideanDistance;
@@ -156,6 +188,18 @@ pub use haversine_closest_point::HaversineClosestPoint;
 pub mod interior_point;
 pub use interior_point::InteriorPoint;
 
+/// Find the point of a polygon farthest from its boundary.
+pub mod pole_of_inaccessibility;
+pub use pole_of_inaccessibility::PoleOfInaccessibility;
+
+/// Calculate the largest circle inside a polygon.
+pub mod maximum_inscribed_circle;
+pub use maximum_inscribed_circle::{InscribedCircle, MaximumInscribedCircle};
+
+/// Calculate the largest circle which misses a set of obstacles.
+pub mod largest_empty_circle;
+pub use largest_empty_circle::LargestEmptyCircle;
+
 /// Determine whether `Geometry` `A` intersects `Geometry` `B`.
 pub mod intersects;
 pub use intersects::Intersects;
@@ -164,6 +208,20 @@ pub use intersects::Intersects;
 pub mod is_convex;
 pub use is_convex::IsConvex;
 
+/// Determine whether a `Geometry` is valid according to the OGC Simple Features rules.
+pub mod is_valid;
+pub use is_valid::IsValid;
+
+/// Determine whether two geometries are within a distance of each other.
+pub mod is_within_distance;
+pub use is_within_distance::{
+    IsWithinDistance, IsWithinGeodesicDistance, IsWithinHaversineDistance,
+};
+
+/// Calculate how far a vertex can move before a geometry becomes invalid.
+pub mod minimum_clearance;
+pub use minimum_clearance::MinimumClearance;
+
 /// Calculate concave hull using k-nearest algorithm
 pub mod k_nearest_concave_hull;
 pub use k_nearest_concave_hull::KNearestConcaveHull;
@@ -188,21 +246,49 @@ pub use lines_iter::LinesIter;
 pub mod linestring_segment;
 pub use linestring_segment::LineStringSegmentize;
 
+/// Merge lines which meet end to end into maximal `LineString`s.
+pub mod line_merge;
+pub use line_merge::LineMerge;
+
+/// Repair invalid `Polygon`s and `MultiPolygon`s.
+pub mod make_valid;
+pub use make_valid::MakeValid;
+
 /// Apply a function to all `Coord`s of a `Geometry`.
 pub mod map_coords;
 pub use map_coords::{MapCoords, MapCoordsInPlace};
 
+/// Find the nearest points of two geometries, and where they lie on each geometry.
+pub mod nearest_points;
+pub use nearest_points::{GeometryLocation, NearestLocations, NearestPoints};
+
+/// Split lines at every point where they cross or touch.
+pub mod node;
+pub use node::Node;
+
+/// Calculate the curve parallel to a line, at a distance to one side of it.
+pub mod offset_curve;
+pub use offset_curve::OffsetCurve;
+
 /// Orient a `Polygon`'s exterior and interior rings.
 pub mod orient;
 pub use orient::Orient;
 
+/// Determine whether two `Geometries` overlap.
+pub mod overlaps;
+pub use overlaps::Overlaps;
+
+/// Form the polygons enclosed by a set of noded lines.
+pub mod polygonize;
+pub use polygonize::{polygonize, Polygonization, Polygonize};
+
 /// Coordinate projections and transformations using the current stable version of [PROJ](http://proj.org).
 #[cfg(feature = "use-proj")]
 pub mod proj;
 
 /// Relate two geometries based on DE-9IM
 pub mod relate;
-pub use relate::Relate;
+pub use relate::{BoundaryNodeRule, PreparedGeometry, Relate};
 
 /// Remove (consecutive) repeated points
 pub mod remove_repeated_points;
@@ -232,6 +318,10 @@ pub use simplify::{Simplify, SimplifyIdx};
 pub mod simplify_vw;
 pub use simplify_vw::{SimplifyVw, SimplifyVwIdx, SimplifyVwPreserve};
 
+/// Determine whether `Geometry` `A` touches `Geometry` `B`.
+pub mod touches;
+pub use touches::Touches;
+
 /// Transform a geometry using PROJ.
 #[cfg(feature = "use-proj")]
 pub mod transform;
@@ -242,12 +332,24 @@ pub use transform::Transform;
 pub mod translate;
 pub use translate::Translate;
 
+/// Triangulate a set of points with a Delaunay triangulation.
+pub mod triangulate_delaunay;
+pub use triangulate_delaunay::TriangulateDelaunay;
+
+/// Triangulate polygons with a constrained Delaunay triangulation, optionally refined.
+pub mod triangulate_constrained;
+pub use triangulate_constrained::{Refinement, TriangulateConstrained};
+
 /// Triangulate polygons using an [ear-cutting algorithm](https://www.geometrictools.com/Documentation/TriangulationByEarClipping.pdf).
 #[cfg(feature = "earcutr")]
 pub mod triangulate_earcut;
 #[cfg(feature = "earcutr")]
 pub use triangulate_earcut::TriangulateEarcut;
 
+/// Triangulate polygons by splitting them into monotone pieces.
+pub mod triangulate_monotone;
+pub use triangulate_monotone::TriangulateMonotone;
+
 /// Vector Operations for 2D coordinates
 mod vector_ops;
 pub use vector_ops::Vector2DOps;
@@ -260,6 +362,10 @@ pub use vincenty_distance::VincentyDistance;
 pub mod vincenty_length;
 pub use vincenty_length::VincentyLength;
 
+/// Calculate the Voronoi cells of a set of points.
+pub mod voronoi;
+pub use voronoi::{Voronoi, VoronoiCell};
+
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
 pub use winding_order::Winding;
@@ -0,0 +1,547 @@
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{
+    BoundingRect, Closest, ClosestPoint, Coord, GeoFloat, Geometry, GeometryCollection,
+    GeometryCow, Intersects, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon, Rect, Triangle,
+};
+
+/// Find the nearest points of two geometries, and the parts of each geometry they lie on.
+///
+/// The line between the two points is a shortest line between the geometries, so its length
+/// is their [`EuclideanDistance`](crate::EuclideanDistance). If the geometries intersect, the
+/// two points are the same point of their intersection.
+///
+/// Each point is reported as a [`GeometryLocation`]. It gives the index of the point, line
+/// string or polygon which the point lies on, among the components of its geometry. When that
+/// component is linear or a polygon's boundary, it also gives the index of the segment.
+///
+/// It's `None` if either geometry is empty.
+///
+/// Based on [JTS's `DistanceOp`](https://locationtech.github.io/jts/javadoc/org/locationtech/jts/operation/distance/DistanceOp.html).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, line_string, polygon, Line, NearestPoints};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let line_string = line_string![(x: 20., y: 20.), (x: 15., y: 5.), (x: 20., y: 0.)];
+///
+/// let nearest = polygon.nearest_points(&line_string).unwrap();
+/// assert_eq!(nearest.distance(), 5.);
+/// // the line to draw between them
+/// assert_eq!(
+///     nearest.line(),
+///     Line::new(coord! { x: 10., y: 5. }, coord! { x: 15., y: 5. })
+/// );
+/// // on the polygon's second segment, and at the end of the line string's first segment
+/// assert_eq!(nearest.from.segment_index, Some(1));
+/// assert_eq!(nearest.to.segment_index, Some(0));
+/// ```
+pub trait NearestPoints<F: GeoFloat, Rhs = Self> {
+    fn nearest_points(&self, other: &Rhs) -> Option<NearestLocations<F>>;
+}
+
+/// Where one of the [nearest points](NearestPoints) of two geometries lies on its geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeometryLocation<F: GeoFloat> {
+    /// The point
+    pub coord: Coord<F>,
+    /// The index of the point, line string or polygon containing the point, among the
+    /// components of the geometry.
+    ///
+    /// Multi-geometries and geometry collections are flattened, depth first, into their
+    /// components. A `Line` counts as a line string, and a `Rect` or `Triangle` as a polygon.
+    pub component_index: usize,
+    /// The index of the segment containing the point, if the component is linear or the point is
+    /// on a polygon's boundary.
+    ///
+    /// A polygon's segments are numbered along its exterior, and then along each of its
+    /// interiors. It's `None` for a point component, or a point in the interior of a polygon.
+    pub segment_index: Option<usize>,
+}
+
+/// The nearest points of two geometries, as found by [`NearestPoints`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NearestLocations<F: GeoFloat> {
+    /// The nearest point of the first geometry
+    pub from: GeometryLocation<F>,
+    /// The nearest point of the second geometry
+    pub to: GeometryLocation<F>,
+}
+
+impl<F: GeoFloat> NearestLocations<F> {
+    /// The distance between the geometries.
+    pub fn distance(&self) -> F {
+        coord_distance(self.from.coord, self.to.coord)
+    }
+
+    /// The shortest line from the first geometry to the second.
+    pub fn line(&self) -> Line<F> {
+        Line::new(self.from.coord, self.to.coord)
+    }
+
+    pub(crate) fn reversed(self) -> Self {
+        NearestLocations {
+            from: self.to,
+            to: self.from,
+        }
+    }
+}
+
+/// A point, line string or polygon of a geometry
+pub(crate) enum Shape<F: GeoFloat> {
+    Point(Coord<F>),
+    LineString(LineString<F>),
+    Polygon(Polygon<F>),
+}
+
+/// A non-empty component of a geometry, with its index among all of the geometry's components
+pub(crate) struct Component<F: GeoFloat> {
+    pub(crate) index: usize,
+    pub(crate) shape: Shape<F>,
+    pub(crate) bounding_rect: Rect<F>,
+}
+
+impl<F: GeoFloat> Component<F> {
+    /// The segments of the component, numbered along a polygon's exterior and then its
+    /// interiors.
+    pub(crate) fn segments(&self) -> impl Iterator<Item = Line<F>> + '_ {
+        let (first, rest): (Option<&LineString<F>>, &[LineString<F>]) = match &self.shape {
+            Shape::Point(_) => (None, &[]),
+            Shape::LineString(line_string) => (Some(line_string), &[]),
+            Shape::Polygon(polygon) => (Some(polygon.exterior()), polygon.interiors()),
+        };
+        first
+            .into_iter()
+            .chain(rest)
+            .flat_map(|line_string| line_string.lines())
+    }
+
+    /// The segments of the component with their indexes, or a zero length segment without an
+    /// index for a point.
+    pub(crate) fn elements(&self) -> Box<dyn Iterator<Item = (Option<usize>, Line<F>)> + '_> {
+        match self.shape {
+            Shape::Point(coord) => Box::new(std::iter::once((None, Line::new(coord, coord)))),
+            _ => Box::new(
+                self.segments()
+                    .enumerate()
+                    .map(|(index, segment)| (Some(index), segment)),
+            ),
+        }
+    }
+
+    /// The location of a coordinate of the component.
+    fn first_location(&self) -> GeometryLocation<F> {
+        let (coord, segment_index) = match &self.shape {
+            Shape::Point(coord) => (*coord, None),
+            Shape::LineString(line_string) => (line_string.0[0], Some(0)),
+            Shape::Polygon(polygon) => (polygon.exterior().0[0], Some(0)),
+        };
+        GeometryLocation {
+            coord,
+            component_index: self.index,
+            segment_index,
+        }
+    }
+}
+
+/// Flatten a geometry into its non-empty components.
+pub(crate) fn components<F: GeoFloat>(geometry: &GeometryCow<F>) -> Vec<Component<F>> {
+    let mut components = vec![];
+    add_components(geometry, &mut 0, &mut components);
+    components
+}
+
+fn add_components<F: GeoFloat>(
+    geometry: &GeometryCow<F>,
+    next_index: &mut usize,
+    components: &mut Vec<Component<F>>,
+) {
+    let mut push = |shape: Shape<F>| {
+        let bounding_rect = match &shape {
+            Shape::Point(coord) => Some(Rect::new(*coord, *coord)),
+            Shape::LineString(line_string) => line_string.bounding_rect(),
+            Shape::Polygon(polygon) => polygon.bounding_rect(),
+        };
+        if let Some(bounding_rect) = bounding_rect {
+            components.push(Component {
+                index: *next_index,
+                shape,
+                bounding_rect,
+            });
+        }
+        *next_index += 1;
+    };
+    match geometry {
+        GeometryCow::Point(point) => push(Shape::Point(point.0)),
+        GeometryCow::Line(line) => push(Shape::LineString(LineString::new(vec![
+            line.start, line.end,
+        ]))),
+        GeometryCow::LineString(line_string) => push(line_string_shape(line_string)),
+        GeometryCow::Polygon(polygon) => push(Shape::Polygon(polygon.clone().into_owned())),
+        GeometryCow::MultiPoint(multi_point) => {
+            for point in multi_point.iter() {
+                push(Shape::Point(point.0));
+            }
+        }
+        GeometryCow::MultiLineString(multi_line_string) => {
+            for line_string in multi_line_string.iter() {
+                push(line_string_shape(line_string));
+            }
+        }
+        GeometryCow::MultiPolygon(multi_polygon) => {
+            for polygon in multi_polygon.iter() {
+                push(Shape::Polygon(polygon.clone()));
+            }
+        }
+        GeometryCow::Rect(rect) => push(Shape::Polygon(rect.to_polygon())),
+        GeometryCow::Triangle(triangle) => push(Shape::Polygon(triangle.to_polygon())),
+        GeometryCow::GeometryCollection(geometry_collection) => {
+            for geometry in geometry_collection.iter() {
+                add_components(&GeometryCow::from(geometry), next_index, components);
+            }
+        }
+    }
+}
+
+/// A line string with a single coordinate has no segments, so is treated as a point.
+fn line_string_shape<F: GeoFloat>(line_string: &LineString<F>) -> Shape<F> {
+    match line_string.0.as_slice() {
+        [coord] => Shape::Point(*coord),
+        _ => Shape::LineString(line_string.clone()),
+    }
+}
+
+/// Find the nearest points of the components of two geometries, or `None` if either has no
+/// components.
+pub(crate) fn nearest_locations<F: GeoFloat>(
+    components_a: &[Component<F>],
+    components_b: &[Component<F>],
+) -> Option<NearestLocations<F>> {
+    if let Some(nearest) = containment_locations(components_a, components_b) {
+        return Some(nearest);
+    }
+    if let Some(nearest) = containment_locations(components_b, components_a) {
+        return Some(nearest.reversed());
+    }
+
+    let mut nearest: Option<NearestLocations<F>> = None;
+    for component_a in components_a {
+        for component_b in components_b {
+            if let Some(nearest) = &nearest {
+                let rect_distance =
+                    rect_distance(component_a.bounding_rect, component_b.bounding_rect);
+                if rect_distance >= nearest.distance() {
+                    continue;
+                }
+            }
+            component_locations(component_a, component_b, &mut nearest);
+            if nearest.map_or(false, |nearest| nearest.distance() == F::zero()) {
+                return nearest;
+            }
+        }
+    }
+    nearest
+}
+
+/// If the first coordinate of a component of `components_b` is inside a polygon of
+/// `components_a`, the geometries intersect there, so it's a nearest point of both.
+pub(crate) fn containment_locations<F: GeoFloat>(
+    components_a: &[Component<F>],
+    components_b: &[Component<F>],
+) -> Option<NearestLocations<F>> {
+    for component_a in components_a {
+        let Shape::Polygon(polygon) = &component_a.shape else {
+            continue;
+        };
+        for component_b in components_b {
+            let location_b = component_b.first_location();
+            if component_a.bounding_rect.intersects(&location_b.coord)
+                && polygon.coordinate_position(&location_b.coord) == CoordPos::Inside
+            {
+                let location_a = GeometryLocation {
+                    coord: location_b.coord,
+                    component_index: component_a.index,
+                    segment_index: None,
+                };
+                return Some(NearestLocations {
+                    from: location_a,
+                    to: location_b,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Update `nearest` with the nearest points of two components, if they're nearer.
+fn component_locations<F: GeoFloat>(
+    component_a: &Component<F>,
+    component_b: &Component<F>,
+    nearest: &mut Option<NearestLocations<F>>,
+) {
+    let mut update = |coord_a, segment_a, coord_b, segment_b| {
+        let distance = coord_distance(coord_a, coord_b);
+        if nearest.map_or(true, |nearest| distance < nearest.distance()) {
+            *nearest = Some(NearestLocations {
+                from: GeometryLocation {
+                    coord: coord_a,
+                    component_index: component_a.index,
+                    segment_index: segment_a,
+                },
+                to: GeometryLocation {
+                    coord: coord_b,
+                    component_index: component_b.index,
+                    segment_index: segment_b,
+                },
+            });
+        }
+        distance == F::zero()
+    };
+    match (&component_a.shape, &component_b.shape) {
+        (Shape::Point(coord_a), Shape::Point(coord_b)) => {
+            update(*coord_a, None, *coord_b, None);
+        }
+        (Shape::Point(coord_a), _) => {
+            for (index, segment) in component_b.segments().enumerate() {
+                let coord_b = closest_on_segment(*coord_a, segment);
+                if update(*coord_a, None, coord_b, Some(index)) {
+                    return;
+                }
+            }
+        }
+        (_, Shape::Point(coord_b)) => {
+            for (index, segment) in component_a.segments().enumerate() {
+                let coord_a = closest_on_segment(*coord_b, segment);
+                if update(coord_a, Some(index), *coord_b, None) {
+                    return;
+                }
+            }
+        }
+        _ => {
+            for (index_a, segment_a) in component_a.segments().enumerate() {
+                for (index_b, segment_b) in component_b.segments().enumerate() {
+                    let (coord_a, coord_b) = nearest_on_segments(segment_a, segment_b);
+                    if update(coord_a, Some(index_a), coord_b, Some(index_b)) {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The nearest points of two segments.
+pub(crate) fn nearest_on_segments<F: GeoFloat>(
+    segment_a: Line<F>,
+    segment_b: Line<F>,
+) -> (Coord<F>, Coord<F>) {
+    match line_intersection(segment_a, segment_b) {
+        Some(LineIntersection::SinglePoint { intersection, .. }) => (intersection, intersection),
+        Some(LineIntersection::Collinear { intersection }) => {
+            (intersection.start, intersection.start)
+        }
+        None => [
+            (
+                segment_a.start,
+                closest_on_segment(segment_a.start, segment_b),
+            ),
+            (segment_a.end, closest_on_segment(segment_a.end, segment_b)),
+            (
+                closest_on_segment(segment_b.start, segment_a),
+                segment_b.start,
+            ),
+            (closest_on_segment(segment_b.end, segment_a), segment_b.end),
+        ]
+        .into_iter()
+        .min_by(|(a_0, b_0), (a_1, b_1)| {
+            coord_distance(*a_0, *b_0)
+                .partial_cmp(&coord_distance(*a_1, *b_1))
+                .unwrap()
+        })
+        .expect("there are four candidates"),
+    }
+}
+
+/// The nearest points of two segments, either of which may have zero length.
+pub(crate) fn nearest_on_lines<F: GeoFloat>(a: Line<F>, b: Line<F>) -> (Coord<F>, Coord<F>) {
+    if a.start == a.end {
+        (a.start, closest_on_segment(a.start, b))
+    } else if b.start == b.end {
+        (closest_on_segment(b.start, a), b.start)
+    } else {
+        nearest_on_segments(a, b)
+    }
+}
+
+/// The nearest point of a segment to `coord`.
+pub(crate) fn closest_on_segment<F: GeoFloat>(coord: Coord<F>, segment: Line<F>) -> Coord<F> {
+    match segment.closest_point(&Point::from(coord)) {
+        Closest::Intersection(point) | Closest::SinglePoint(point) => point.0,
+        // the segment has zero length
+        Closest::Indeterminate => segment.start,
+    }
+}
+
+pub(crate) fn coord_distance<F: GeoFloat>(a: Coord<F>, b: Coord<F>) -> F {
+    let delta = b - a;
+    delta.x.hypot(delta.y)
+}
+
+/// The distance between two rectangles, which is zero if they intersect.
+pub(crate) fn rect_distance<F: GeoFloat>(a: Rect<F>, b: Rect<F>) -> F {
+    let gap =
+        |min_a: F, max_a: F, min_b: F, max_b: F| (min_b - max_a).max(min_a - max_b).max(F::zero());
+    let dx = gap(a.min().x, a.max().x, b.min().x, b.max().x);
+    let dy = gap(a.min().y, a.max().y, b.min().y, b.max().y);
+    dx.hypot(dy)
+}
+
+macro_rules! impl_nearest_points {
+    ($($type:ident),*) => {
+        impl_nearest_points!(@pairs [$($type),*] [$($type),*]);
+    };
+    (@pairs [$($type_a:ident),*] $types_b:tt) => {
+        $(impl_nearest_points!(@impls $type_a $types_b);)*
+    };
+    (@impls $type_a:ident [$($type_b:ident),*]) => {
+        $(
+            impl<F: GeoFloat> NearestPoints<F, $type_b<F>> for $type_a<F> {
+                fn nearest_points(&self, other: &$type_b<F>) -> Option<NearestLocations<F>> {
+                    nearest_locations(
+                        &components(&GeometryCow::from(self)),
+                        &components(&GeometryCow::from(other)),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_nearest_points!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    GeometryCollection,
+    Geometry
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, line_string, point, polygon, EuclideanDistance};
+
+    #[test]
+    fn points() {
+        let nearest = point!(x: 0., y: 0.)
+            .nearest_points(&point!(x: 3., y: 4.))
+            .unwrap();
+        assert_eq!(nearest.distance(), 5.);
+        assert_eq!(nearest.from.segment_index, None);
+        assert_eq!(nearest.to.segment_index, None);
+    }
+
+    #[test]
+    fn crossing_lines() {
+        let a = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let b = line_string![(x: 5., y: 5.), (x: 15., y: 5.)];
+        let nearest = a.nearest_points(&b).unwrap();
+        assert_eq!(nearest.distance(), 0.);
+        assert_eq!(nearest.from.coord, coord! { x: 10., y: 5. });
+        assert_eq!(nearest.from.segment_index, Some(1));
+        assert_eq!(nearest.to.segment_index, Some(0));
+    }
+
+    #[test]
+    fn contained_in_polygon() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let line_string = line_string![(x: 2., y: 2.), (x: 8., y: 8.)];
+        let nearest = polygon.nearest_points(&line_string).unwrap();
+        assert_eq!(nearest.distance(), 0.);
+        assert_eq!(nearest.from.coord, coord! { x: 2., y: 2. });
+        assert_eq!(nearest.from.segment_index, None);
+        assert_eq!(nearest.to.segment_index, Some(0));
+
+        // in a hole isn't inside the polygon
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        );
+        let nearest = polygon.nearest_points(&point!(x: 5., y: 4.)).unwrap();
+        assert_eq!(nearest.distance(), 2.);
+        assert_eq!(nearest.from.coord, coord! { x: 5., y: 2. });
+        // the first segment of the interior follows the four of the exterior
+        assert_eq!(nearest.from.segment_index, Some(4));
+    }
+
+    #[test]
+    fn multi_polygons() {
+        let a = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            polygon![(x: 10., y: 0.), (x: 11., y: 0.), (x: 11., y: 1.), (x: 10., y: 1.)],
+        ]);
+        let b = MultiPolygon::new(vec![
+            polygon![(x: 20., y: 0.), (x: 21., y: 0.), (x: 21., y: 1.)],
+            polygon![(x: 13., y: 3.), (x: 14., y: 3.), (x: 14., y: 4.), (x: 13., y: 4.)],
+        ]);
+        let nearest = a.nearest_points(&b).unwrap();
+        assert_eq!(
+            nearest.line(),
+            Line::new(coord! { x: 11., y: 1. }, coord! { x: 13., y: 3. })
+        );
+        assert_eq!(nearest.from.component_index, 1);
+        assert_eq!(nearest.to.component_index, 1);
+    }
+
+    #[test]
+    fn geometry_collection_components() {
+        let collection = GeometryCollection::new_from(vec![
+            point!(x: 100., y: 100.).into(),
+            MultiPoint::new(vec![point!(x: 50., y: 50.), point!(x: 1., y: 1.)]).into(),
+            LineString::<f64>::new(vec![]).into(),
+            line_string![(x: 0., y: 5.), (x: 10., y: 5.)].into(),
+        ]);
+        let nearest = collection.nearest_points(&point!(x: 5., y: 6.)).unwrap();
+        assert_eq!(nearest.from.coord, coord! { x: 5., y: 5. });
+        // the empty line string counts as a component
+        assert_eq!(nearest.from.component_index, 4);
+        assert_eq!(nearest.from.segment_index, Some(0));
+    }
+
+    #[test]
+    fn empty() {
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(empty.nearest_points(&point!(x: 0., y: 0.)), None);
+    }
+
+    #[test]
+    fn distance_matches_euclidean_distance() {
+        let polygon: Geometry = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        )
+        .into();
+        let others: Vec<Geometry> = vec![
+            point!(x: 5., y: 5.).into(),
+            point!(x: 15., y: 12.).into(),
+            line_string![(x: 3., y: 3.), (x: 4., y: 5.)].into(),
+            line_string![(x: -3., y: -5.), (x: -1., y: 20.)].into(),
+            Triangle::from([(12., 0.), (20., 1.), (13., 8.)]).into(),
+            Rect::new(coord! { x: 1., y: 3. }, coord! { x: 5., y: 7. }).into(),
+        ];
+        for other in &others {
+            let nearest = polygon.nearest_points(other).unwrap();
+            approx::assert_relative_eq!(nearest.distance(), polygon.euclidean_distance(other));
+            assert!(polygon.euclidean_distance(&Point::from(nearest.from.coord)) < 1e-12);
+            assert!(other.euclidean_distance(&Point::from(nearest.to.coord)) < 1e-12);
+        }
+    }
+}
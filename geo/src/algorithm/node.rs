@@ -0,0 +1,231 @@
+use crate::sweep::{Cross, Intersections, LineOrPoint};
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoFloat, Line, LineIntersection, LineString, MultiLineString};
+
+/// Split lines at every point where they cross or touch.
+///
+/// The intersections are found with a sweep over all the segments, using
+/// [`Intersections`](crate::sweep::Intersections). Each line is split into pieces which only
+/// meet other lines, or themselves, at their ends. A piece is split at both ends of any
+/// linework it shares with another line, but overlapping pieces are kept, once for each line.
+///
+/// The original vertices of the lines are kept, and the pieces follow the direction of their
+/// lines. To join up the pieces again where exactly two of them meet, use
+/// [`LineMerge`](crate::LineMerge).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, MultiLineString, Node};
+///
+/// let lines = MultiLineString::new(vec![
+///     line_string![(x: 0., y: 0.), (x: 10., y: 10.)],
+///     line_string![(x: 0., y: 10.), (x: 5., y: 10.), (x: 10., y: 0.)],
+/// ]);
+/// assert_eq!(
+///     lines.node(),
+///     MultiLineString::new(vec![
+///         line_string![(x: 0., y: 0.), (x: 20. / 3., y: 20. / 3.)],
+///         line_string![(x: 20. / 3., y: 20. / 3.), (x: 10., y: 10.)],
+///         line_string![(x: 0., y: 10.), (x: 5., y: 10.), (x: 20. / 3., y: 20. / 3.)],
+///         line_string![(x: 20. / 3., y: 20. / 3.), (x: 10., y: 0.)],
+///     ])
+/// );
+/// ```
+pub trait Node {
+    type Scalar: GeoFloat;
+
+    /// Returns the lines split at every point where they meet.
+    fn node(&self) -> MultiLineString<Self::Scalar>;
+}
+
+impl<T: GeoFloat> Node for LineString<T> {
+    type Scalar = T;
+
+    fn node(&self) -> MultiLineString<T> {
+        node([self])
+    }
+}
+
+impl<T: GeoFloat> Node for MultiLineString<T> {
+    type Scalar = T;
+
+    fn node(&self) -> MultiLineString<T> {
+        node(&self.0)
+    }
+}
+
+/// A segment of one of the lines.
+#[derive(Debug, Clone)]
+struct Segment<T: GeoFloat> {
+    line: Line<T>,
+    idx: usize,
+}
+
+impl<T: GeoFloat> Cross for Segment<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<Self::Scalar> {
+        self.line.into()
+    }
+}
+
+fn node<'a, T: GeoFloat + 'a>(
+    lines: impl IntoIterator<Item = &'a LineString<T>>,
+) -> MultiLineString<T> {
+    let lines: Vec<_> = lines
+        .into_iter()
+        .map(|ls| {
+            let mut coords = ls.0.clone();
+            coords.dedup();
+            LineString::new(coords)
+        })
+        .filter(|ls| ls.0.len() > 1)
+        .collect();
+    // The line, and position in the line, of each segment.
+    let positions: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .flat_map(|(line_idx, ls)| (0..ls.0.len() - 1).map(move |idx| (line_idx, idx)))
+        .collect();
+    let segments = positions.iter().enumerate().map(|(idx, &(line_idx, pos))| {
+        let coords = &lines[line_idx].0;
+        Segment {
+            line: Line::new(coords[pos], coords[pos + 1]),
+            idx,
+        }
+    });
+
+    // Consecutive segments of a line always meet at their shared vertex, which isn't a node.
+    let is_consecutive = |a: usize, b: usize, point: Coord<T>| {
+        let ((a_line, a_pos), (b_line, b_pos)) = (positions[a], positions[b]);
+        if a_line != b_line {
+            return false;
+        }
+        let coords = &lines[a_line].0;
+        let (first, second) = if a_pos < b_pos {
+            (a_pos, b_pos)
+        } else {
+            (b_pos, a_pos)
+        };
+        (second == first + 1 && point == coords[second])
+            || (first == 0
+                && second == coords.len() - 2
+                && point == coords[0]
+                && coords[0] == coords[coords.len() - 1])
+    };
+
+    let mut splits = vec![vec![]; positions.len()];
+    let mut nodes = vec![];
+    for (a, b, intersection) in Intersections::from_iter(segments) {
+        let points = match intersection {
+            LineIntersection::SinglePoint { intersection, .. } => {
+                if is_consecutive(a.idx, b.idx, intersection) {
+                    continue;
+                }
+                vec![intersection]
+            }
+            LineIntersection::Collinear { intersection } => {
+                vec![intersection.start, intersection.end]
+            }
+        };
+        for point in points {
+            splits[a.idx].push(point);
+            splits[b.idx].push(point);
+            nodes.push(point);
+        }
+    }
+    nodes.sort_by(lex_cmp);
+    nodes.dedup();
+    let is_node = |c: &Coord<T>| nodes.binary_search_by(|n| lex_cmp(n, c)).is_ok();
+
+    let mut pieces = vec![];
+    let mut splits = splits.into_iter();
+    for ls in &lines {
+        let mut coords = vec![ls.0[0]];
+        for line in ls.lines() {
+            let split = split_line(line, splits.next().unwrap());
+            for coord in split.into_iter().skip(1) {
+                coords.push(coord);
+                if is_node(&coord) {
+                    pieces.push(LineString::new(std::mem::replace(&mut coords, vec![coord])));
+                }
+            }
+        }
+        if coords.len() > 1 {
+            pieces.push(LineString::new(coords));
+        }
+    }
+    MultiLineString::new(pieces)
+}
+
+/// The coordinates along `line`, from its start, through each of the `splits` in order, to its
+/// end.
+pub(crate) fn split_line<T: GeoFloat>(line: Line<T>, mut splits: Vec<Coord<T>>) -> Vec<Coord<T>> {
+    let length = |c: &Coord<T>| (*c - line.start).x.hypot((*c - line.start).y);
+    splits.retain(|c| *c != line.start && *c != line.end);
+    splits.sort_by(|a, b| length(a).partial_cmp(&length(b)).unwrap());
+    splits.dedup();
+    std::iter::once(line.start)
+        .chain(splits)
+        .chain(std::iter::once(line.end))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, LineMerge};
+
+    #[test]
+    fn self_intersection() {
+        let ls = line_string![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+        assert_eq!(
+            ls.node().0,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 1., y: 1.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 1., y: 1.), (x: 0., y: 2.)],
+            ]
+        );
+    }
+
+    #[test]
+    fn closed_ring_is_not_split() {
+        let ring = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert_eq!(ring.node().0, vec![ring]);
+    }
+
+    #[test]
+    fn overlap_and_touch() {
+        let lines = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 5., y: 0.), (x: 15., y: 0.)],
+            // Ends on the first line.
+            line_string![(x: 2., y: 5.), (x: 2., y: 0.)],
+        ]);
+        assert_eq!(
+            lines.node().0,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 5., y: 0.)],
+                line_string![(x: 5., y: 0.), (x: 10., y: 0.)],
+                line_string![(x: 5., y: 0.), (x: 10., y: 0.)],
+                line_string![(x: 10., y: 0.), (x: 15., y: 0.)],
+                line_string![(x: 2., y: 5.), (x: 2., y: 0.)],
+            ]
+        );
+    }
+
+    #[test]
+    fn node_then_merge() {
+        // A street crossing a loop.
+        let lines = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.)],
+            line_string![(x: -2., y: 2.), (x: 6., y: 2.)],
+        ]);
+        let merged = lines.node().line_merge();
+        // The loop is split in two where the street crosses it, and the street in three.
+        assert_eq!(merged.0.len(), 5);
+    }
+}
@@ -0,0 +1,677 @@
+use rstar::primitives::GeomWithData;
+use rstar::{PointDistance, RTree, RTreeNum};
+
+use crate::buffer::{
+    cap, join, perp, remove_straight_vertices, unit, BufferStyle, CapStyle, JoinStyle,
+};
+use crate::geometry::*;
+use crate::sweep::{Cross, Intersections, LineOrPoint};
+use crate::{Contains, GeoFloat, Kernel, LineIntersection, Orientation};
+
+/// Compute the curve parallel to a line, at a given distance to one side of it.
+///
+/// A positive distance offsets to the left of the line, looking along its direction, and a
+/// negative distance to the right. Corners on the outside of the curve are joined in the same
+/// way as a [`Buffer`](crate::Buffer), controlled by the join style, quadrant segments and mitre
+/// limit of a [`BufferStyle`]; its cap style is not used.
+///
+/// Where the line turns towards the offset side, the offsets of its segments overlap, and any
+/// part of the curve which comes closer to the line than the distance is removed. This may
+/// split the curve into several pieces, or remove it entirely, so `LineString`s and
+/// `MultiLineString`s are offset to a `MultiLineString`. A closed `LineString` is offset as a
+/// ring, with a join where it closes.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, OffsetCurve};
+///
+/// let line = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+///
+/// // The inside of the corner is trimmed.
+/// let left = line.offset_curve(1.);
+/// assert_eq!(
+///     left.0,
+///     vec![line_string![(x: 0., y: 1.), (x: 9., y: 1.), (x: 9., y: 10.)]]
+/// );
+/// ```
+pub trait OffsetCurve {
+    type Scalar: GeoFloat;
+    type Output;
+
+    /// Returns the curve at `distance` to the left of the geometry, using the default
+    /// [`BufferStyle`].
+    fn offset_curve(&self, distance: Self::Scalar) -> Self::Output {
+        self.offset_curve_with_style(distance, &BufferStyle::default())
+    }
+
+    /// Returns the curve at `distance` to the left of the geometry, joining the outside of
+    /// corners with the given `style`.
+    fn offset_curve_with_style(
+        &self,
+        distance: Self::Scalar,
+        style: &BufferStyle<Self::Scalar>,
+    ) -> Self::Output;
+}
+
+impl<T: GeoFloat> OffsetCurve for Line<T> {
+    type Scalar = T;
+    type Output = Line<T>;
+
+    fn offset_curve_with_style(&self, distance: T, _style: &BufferStyle<T>) -> Line<T> {
+        if self.start == self.end {
+            return *self;
+        }
+        let offset = perp(unit(self.delta())) * distance;
+        Line::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> OffsetCurve for LineString<T> {
+    type Scalar = T;
+    type Output = MultiLineString<T>;
+
+    fn offset_curve_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiLineString<T> {
+        let mut coords = self.0.clone();
+        coords.dedup();
+        if coords.len() < 2 || distance.is_nan() {
+            return MultiLineString::new(vec![]);
+        }
+        if distance == T::zero() {
+            return MultiLineString::new(vec![LineString::new(coords)]);
+        }
+        let coords = remove_straight_vertices(&coords);
+        let raw = RawCurve::new(&coords, distance, style);
+        let opposite = RawCurve::new(&coords, -distance, style);
+        MultiLineString::new(raw.trim(&opposite, &coords, distance.abs(), style))
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> OffsetCurve for MultiLineString<T> {
+    type Scalar = T;
+    type Output = MultiLineString<T>;
+
+    fn offset_curve_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiLineString<T> {
+        self.iter()
+            .flat_map(|ls| ls.offset_curve_with_style(distance, style))
+            .collect()
+    }
+}
+
+/// The offsets of the segments of a line, connected by joins on the outside of corners, and
+/// directly on the inside of corners.
+struct RawCurve<T: GeoFloat> {
+    coords: Vec<Coord<T>>,
+    /// The part of the line generating each segment of the curve.
+    generators: Vec<Generator>,
+    is_closed: bool,
+}
+
+/// The part of the line which a segment of the raw offset curve comes from, which is ignored
+/// when checking whether the segment is too close to the line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Generator {
+    /// The offset of the segment with this index.
+    Segment(usize),
+    /// The join at the corner between two segments.
+    Join(usize, usize),
+    /// The connection across the inside of the corner after a segment, which is always too
+    /// close to the line.
+    Connector(usize),
+}
+
+impl<T: GeoFloat> RawCurve<T> {
+    fn new(line: &[Coord<T>], distance: T, style: &BufferStyle<T>) -> Self {
+        let is_closed = line.len() > 3 && line.first() == line.last();
+        let num_segments = line.len() - 1;
+        let normals: Vec<_> = line
+            .windows(2)
+            .map(|w| perp(unit(w[1] - w[0])) * distance.signum())
+            .collect();
+        let is_left = distance > T::zero();
+        let distance = distance.abs();
+
+        let mut raw = RawCurve {
+            coords: vec![line[0] + normals[0] * distance],
+            generators: vec![],
+            is_closed,
+        };
+        for idx in 0..num_segments {
+            raw.push(
+                line[idx + 1] + normals[idx] * distance,
+                Generator::Segment(idx),
+            );
+
+            let next = if idx + 1 < num_segments {
+                idx + 1
+            } else if is_closed {
+                0
+            } else {
+                break;
+            };
+            let (prev, corner, after) = (line[idx], line[idx + 1], line[next + 1]);
+            let (normal, next_normal) = (normals[idx], normals[next]);
+            let is_outside = match T::Ker::orient2d(prev, corner, after) {
+                Orientation::Clockwise => is_left,
+                Orientation::CounterClockwise => !is_left,
+                // Straight on, which only happens where a ring closes, or back on itself.
+                Orientation::Collinear => {
+                    let (dir, next_dir) = (corner - prev, after - corner);
+                    dir.x * next_dir.x + dir.y * next_dir.y < T::zero()
+                }
+            };
+            if is_outside {
+                let mut points = vec![];
+                if is_left {
+                    join(corner, distance, next_normal, normal, style, &mut points);
+                    points.reverse();
+                } else {
+                    join(corner, distance, normal, next_normal, style, &mut points);
+                }
+                for point in points {
+                    raw.push(point, Generator::Join(idx, next));
+                }
+            }
+            let generator = if is_outside {
+                Generator::Join(idx, next)
+            } else {
+                Generator::Connector(idx)
+            };
+            raw.push(corner + next_normal * distance, generator);
+        }
+        raw
+    }
+
+    fn push(&mut self, coord: Coord<T>, generator: Generator) {
+        if self.coords.last() != Some(&coord) {
+            self.coords.push(coord);
+            self.generators.push(generator);
+        }
+    }
+}
+
+/// A segment of the raw offset curve with its index, or of the boundary of the buffer around
+/// an end of the line.
+#[derive(Debug, Clone)]
+struct RawSegment<T: GeoFloat> {
+    line: Line<T>,
+    idx: Option<usize>,
+}
+
+impl<T: GeoFloat> Cross for RawSegment<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<Self::Scalar> {
+        self.line.into()
+    }
+}
+
+/// A piece of the raw curve between places where it meets the boundary of the buffer of the
+/// line.
+struct Piece<T: GeoFloat> {
+    coords: Vec<Coord<T>>,
+    generators: Vec<Generator>,
+}
+
+impl<T: GeoFloat + RTreeNum> RawCurve<T> {
+    /// Split the raw curve where it meets the boundary of the buffer of the line, and keep the
+    /// pieces which are outside the buffer.
+    fn trim(
+        &self,
+        opposite: &RawCurve<T>,
+        line: &[Coord<T>],
+        distance: T,
+        style: &BufferStyle<T>,
+    ) -> Vec<LineString<T>> {
+        let buffer = LineBuffer::new(line, distance, style, self.is_closed);
+        let mut result: Vec<Vec<Coord<T>>> = vec![];
+        for piece in self.pieces(opposite, line, distance, style) {
+            if !piece.is_outside(&buffer) {
+                continue;
+            }
+            match result.last_mut() {
+                // Either the next piece, or the piece after a loop which has been removed.
+                Some(last) if last.last() == piece.coords.first() => {
+                    last.extend(&piece.coords[1..]);
+                }
+                _ => result.push(piece.coords),
+            }
+        }
+
+        // Reconnect the ends of a ring.
+        if self.is_closed && result.len() > 1 && result[0].first() == result.last().unwrap().last()
+        {
+            let first = result.remove(0);
+            result.last_mut().unwrap().extend(&first[1..]);
+        }
+        result
+            .into_iter()
+            .map(|coords| LineString::new(remove_straight_vertices(&coords)))
+            .collect()
+    }
+
+    /// The raw curve, split at the points where it meets the boundary of the buffer of the
+    /// line: itself, the raw curve on the other side of the line, or the ends of the buffer.
+    fn pieces(
+        &self,
+        opposite: &RawCurve<T>,
+        line: &[Coord<T>],
+        distance: T,
+        style: &BufferStyle<T>,
+    ) -> Vec<Piece<T>> {
+        let num_segments = self.generators.len();
+        let is_adjacent = |a: usize, b: usize| {
+            a + 1 == b
+                || b + 1 == a
+                || (self.is_closed && a.min(b) == 0 && a.max(b) == num_segments - 1)
+        };
+        let mut splits = vec![vec![]; num_segments];
+        let mut nodes = vec![false; self.coords.len()];
+        let mut add_node = |segment: &RawSegment<T>, coord: Coord<T>| {
+            let Some(idx) = segment.idx else {
+                return;
+            };
+            if coord == segment.line.start {
+                nodes[idx] = true;
+            } else if coord == segment.line.end {
+                nodes[idx + 1] = true;
+            } else {
+                splits[idx].push(coord);
+            }
+        };
+        let segments = self
+            .coords
+            .windows(2)
+            .enumerate()
+            .map(|(idx, w)| RawSegment {
+                line: Line::new(w[0], w[1]),
+                idx: Some(idx),
+            });
+        let mut boundary: Vec<_> = opposite
+            .coords
+            .windows(2)
+            .map(|w| RawSegment {
+                line: Line::new(w[0], w[1]),
+                idx: None,
+            })
+            .collect();
+        if !self.is_closed {
+            boundary.extend(end_caps(line, distance, style));
+        }
+        for (a, b, intersection) in Intersections::from_iter(segments.chain(boundary)) {
+            let (Some(a_idx), Some(b_idx)) = (a.idx, b.idx) else {
+                if let LineIntersection::SinglePoint { intersection, .. } = intersection {
+                    add_node(&a, intersection);
+                    add_node(&b, intersection);
+                }
+                continue;
+            };
+            match intersection {
+                LineIntersection::SinglePoint { intersection, .. } => {
+                    let is_shared_end = is_adjacent(a_idx, b_idx)
+                        && [a.line.start, a.line.end].contains(&intersection)
+                        && [b.line.start, b.line.end].contains(&intersection);
+                    if !is_shared_end {
+                        add_node(&a, intersection);
+                        add_node(&b, intersection);
+                    }
+                }
+                LineIntersection::Collinear { intersection } => {
+                    for coord in [intersection.start, intersection.end] {
+                        add_node(&a, coord);
+                        add_node(&b, coord);
+                    }
+                }
+            }
+        }
+
+        let mut pieces = vec![];
+        let mut piece = Piece {
+            coords: vec![self.coords[0]],
+            generators: vec![],
+        };
+        for (idx, segment_splits) in splits.iter_mut().enumerate() {
+            let start = self.coords[idx];
+            let generators = self.generators[idx];
+            segment_splits.sort_by(|a, b| {
+                let da = (*a - start).x.hypot((*a - start).y);
+                let db = (*b - start).x.hypot((*b - start).y);
+                da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+            });
+            segment_splits.dedup();
+            for &split in segment_splits.iter() {
+                piece.coords.push(split);
+                piece.generators.push(generators);
+                pieces.push(std::mem::replace(
+                    &mut piece,
+                    Piece {
+                        coords: vec![split],
+                        generators: vec![],
+                    },
+                ));
+            }
+            let end = self.coords[idx + 1];
+            piece.coords.push(end);
+            piece.generators.push(generators);
+            if nodes[idx + 1] && idx + 1 < num_segments {
+                pieces.push(std::mem::replace(
+                    &mut piece,
+                    Piece {
+                        coords: vec![end],
+                        generators: vec![],
+                    },
+                ));
+            }
+        }
+        pieces.push(piece);
+        pieces
+    }
+}
+
+/// The segments of the boundary of the buffer around each end of an open line, where the
+/// offset curve may stop being too close to the line without meeting itself. With round joins
+/// these are circles, and otherwise the flat ends of the buffers of the first and last segments.
+fn end_caps<T: GeoFloat>(
+    line: &[Coord<T>],
+    distance: T,
+    style: &BufferStyle<T>,
+) -> Vec<RawSegment<T>> {
+    let cap_style = match style.get_join_style() {
+        JoinStyle::Round => CapStyle::Round,
+        JoinStyle::Mitre | JoinStyle::Bevel => CapStyle::Flat,
+    };
+    let style = style.cap_style(cap_style);
+    let n = line.len();
+    [(line[0], line[1]), (line[n - 1], line[n - 2])]
+        .into_iter()
+        .flat_map(|(end, towards)| {
+            let normal = perp(unit(towards - end));
+            let mut ring = vec![end + normal * distance];
+            cap(end, distance, normal, &style, &mut ring);
+            ring.push(end - normal * distance);
+            if cap_style == CapStyle::Round {
+                cap(end, distance, -normal, &style, &mut ring);
+                ring.push(end + normal * distance);
+            }
+            ring.windows(2)
+                .map(|w| RawSegment {
+                    line: Line::new(w[0], w[1]),
+                    idx: None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+impl<T: GeoFloat + RTreeNum> Piece<T> {
+    /// Whether the piece is outside the buffer of the line, apart from the part of the line
+    /// it was generated from.
+    ///
+    /// The check is made at the middle of the longest segment of the piece which isn't a
+    /// connector, since the raw curve can only cross into the buffer where it meets its
+    /// boundary.
+    fn is_outside(&self, buffer: &LineBuffer<T>) -> bool {
+        let length = |idx: usize| {
+            let delta = self.coords[idx + 1] - self.coords[idx];
+            delta.x.hypot(delta.y)
+        };
+        let Some(longest) = (0..self.generators.len()).max_by(|&a, &b| {
+            let is_connector = |idx| matches!(self.generators[idx], Generator::Connector(_));
+            is_connector(b).cmp(&is_connector(a)).then(
+                length(a)
+                    .partial_cmp(&length(b))
+                    .unwrap_or(std::cmp::Ordering::Equal),
+            )
+        }) else {
+            return false;
+        };
+        let (start, end) = (self.coords[longest], self.coords[longest + 1]);
+        let mid = (start + end) / (T::one() + T::one());
+        !buffer.contains(mid, self.generators[longest])
+    }
+}
+
+/// The buffer of a line on both sides, without its ends. With round joins, this is the area
+/// within the distance of the line, and otherwise the area alongside each segment, plus the
+/// joins on the outside of each corner.
+struct LineBuffer<T: GeoFloat + RTreeNum> {
+    segments: RTree<GeomWithData<Line<T>, usize>>,
+    /// The join at the start of each segment, if it isn't round.
+    joins: Vec<Option<Polygon<T>>>,
+    distance: T,
+    /// How far the buffer extends from the line.
+    reach: T,
+    is_round: bool,
+}
+
+impl<T: GeoFloat + RTreeNum> LineBuffer<T> {
+    fn new(line: &[Coord<T>], distance: T, style: &BufferStyle<T>, is_closed: bool) -> Self {
+        let segments = RTree::bulk_load(
+            line.windows(2)
+                .enumerate()
+                .map(|(idx, w)| GeomWithData::new(Line::new(w[0], w[1]), idx))
+                .collect(),
+        );
+        let num_segments = line.len() - 1;
+        let joins = (0..num_segments)
+            .map(|idx| {
+                let prev = match idx {
+                    0 if is_closed => num_segments - 1,
+                    0 => return None,
+                    _ => idx - 1,
+                };
+                if style.get_join_style() == JoinStyle::Round {
+                    return None;
+                }
+                let (before, corner, after) = (line[prev], line[idx], line[idx + 1]);
+                let (left, next_left) = (perp(unit(corner - before)), perp(unit(after - corner)));
+                let (from, to) = match T::Ker::orient2d(before, corner, after) {
+                    Orientation::Clockwise => (next_left, left),
+                    Orientation::CounterClockwise => (-left, -next_left),
+                    Orientation::Collinear if left == next_left => return None,
+                    Orientation::Collinear => (-left, -next_left),
+                };
+                let mut ring = vec![corner, corner + from * distance];
+                join(corner, distance, from, to, style, &mut ring);
+                ring.push(corner + to * distance);
+                Some(Polygon::new(LineString::new(ring), vec![]))
+            })
+            .collect();
+        let reach = match style.get_join_style() {
+            JoinStyle::Mitre => distance * style.get_mitre_limit().max(T::one()),
+            JoinStyle::Round | JoinStyle::Bevel => distance,
+        };
+        LineBuffer {
+            segments,
+            joins,
+            distance,
+            reach,
+            is_round: style.get_join_style() == JoinStyle::Round,
+        }
+    }
+
+    /// Whether the buffer contains `coord`, ignoring the part of the line generating it.
+    fn contains(&self, coord: Coord<T>, generator: Generator) -> bool {
+        let shrink = T::one() - T::from(1e-9).unwrap();
+        let limit = self.distance * shrink;
+        let reach = self.reach * shrink;
+        let is_ignored = |idx: usize| match generator {
+            Generator::Segment(segment) | Generator::Connector(segment) => idx == segment,
+            Generator::Join(before, after) => idx == before || idx == after,
+        };
+        self.segments
+            .locate_within_distance(Point::from(coord), reach * reach)
+            .any(|segment| {
+                let idx = segment.data;
+                if self.is_round {
+                    return !is_ignored(idx)
+                        && segment.geom().distance_2(&Point::from(coord)) < limit * limit;
+                }
+                let line = segment.geom();
+                let delta = line.delta();
+                let along = (coord - line.start).x * delta.x + (coord - line.start).y * delta.y;
+                let is_alongside = along > T::zero()
+                    && along < delta.x * delta.x + delta.y * delta.y
+                    && segment.geom().distance_2(&Point::from(coord)) < limit * limit;
+                if is_alongside && !is_ignored(idx) {
+                    return true;
+                }
+                [idx, (idx + 1) % self.joins.len()].into_iter().any(|join| {
+                    let is_generator =
+                        matches!(generator, Generator::Join(_, after) if after == join);
+                    !is_generator
+                        && self.joins[join]
+                            .as_ref()
+                            .map_or(false, |polygon| polygon.contains(&coord))
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, line_string, EuclideanDistance};
+
+    /// Assert that all the points of the offset are at `distance` from the line, allowing for
+    /// the approximation of round joins.
+    fn assert_at_distance(offset: &MultiLineString<f64>, line: &LineString<f64>, distance: f64) {
+        let chord = (std::f64::consts::FRAC_PI_2 / 8. / 2.).cos();
+        for coord in offset.iter().flat_map(|ls| ls.coords()) {
+            let d = Point::from(*coord).euclidean_distance(line);
+            assert!(
+                d > distance * chord - 1e-9 && d < distance + 1e-9,
+                "{coord:?} is at {d}"
+            );
+        }
+    }
+
+    #[test]
+    fn line_offset() {
+        let line = Line::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 0. });
+        assert_eq!(
+            line.offset_curve(2.),
+            Line::new(coord! { x: 0., y: 2. }, coord! { x: 10., y: 2. })
+        );
+        assert_eq!(
+            line.offset_curve(-2.),
+            Line::new(coord! { x: 0., y: -2. }, coord! { x: 10., y: -2. })
+        );
+    }
+
+    #[test]
+    fn straight_line_string() {
+        let ls = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 10., y: 0.)];
+        assert_eq!(
+            ls.offset_curve(-1.).0,
+            vec![line_string![(x: 0., y: -1.), (x: 10., y: -1.)]]
+        );
+        assert_eq!(ls.offset_curve(0.).0, vec![ls.clone()]);
+    }
+
+    #[test]
+    fn outside_corner_joins() {
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+
+        let mitre = BufferStyle::new().join_style(JoinStyle::Mitre);
+        assert_eq!(
+            ls.offset_curve_with_style(-1., &mitre).0,
+            vec![line_string![(x: 0., y: -1.), (x: 11., y: -1.), (x: 11., y: 10.)]]
+        );
+
+        let bevel = BufferStyle::new().join_style(JoinStyle::Bevel);
+        assert_eq!(
+            ls.offset_curve_with_style(-1., &bevel).0,
+            vec![
+                line_string![(x: 0., y: -1.), (x: 10., y: -1.), (x: 11., y: 0.), (x: 11., y: 10.)]
+            ]
+        );
+
+        let round = ls.offset_curve(-1.);
+        assert_eq!(round.0.len(), 1);
+        assert_eq!(round.0[0].0.len(), 2 + 9);
+        assert_at_distance(&round, &ls, 1.);
+    }
+
+    #[test]
+    fn concave_corner_loop() {
+        // The short middle segment's offset loops back behind the first segment's.
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 1.), (x: 20., y: 1.)];
+        let offset = ls.offset_curve(2.);
+        assert_eq!(offset.0.len(), 1);
+        let curve = &offset.0[0];
+        assert_eq!(curve.0.first(), Some(&coord! { x: 0., y: 2. }));
+        assert_eq!(curve.0.last(), Some(&coord! { x: 20., y: 3. }));
+        assert_at_distance(&offset, &ls, 2.);
+
+        let mitre = BufferStyle::new().join_style(JoinStyle::Mitre);
+        assert_eq!(
+            ls.offset_curve_with_style(2., &mitre).0,
+            vec![line_string![(x: 0., y: 2.), (x: 8., y: 2.), (x: 8., y: 3.), (x: 20., y: 3.)]]
+        );
+        let bevel = BufferStyle::new().join_style(JoinStyle::Bevel);
+        assert_eq!(
+            ls.offset_curve_with_style(2., &bevel).0,
+            vec![line_string![(x: 0., y: 2.), (x: 9., y: 2.), (x: 10., y: 3.), (x: 20., y: 3.)]]
+        );
+    }
+
+    #[test]
+    fn narrow_u_turn() {
+        // The inside of the U is too narrow to hold any of the offset.
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 1.), (x: 0., y: 1.)];
+        assert!(ls.offset_curve(2.).0.is_empty());
+
+        let outside = ls.offset_curve(-2.);
+        assert_eq!(outside.0.len(), 1);
+        assert_at_distance(&outside, &ls, 2.);
+    }
+
+    #[test]
+    fn disconnected() {
+        // The line comes back to end close to its start, which splits the offset inside it.
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 20., y: 0.),
+            (x: 20., y: 10.),
+            (x: 10., y: 10.),
+            (x: 10., y: 1.5),
+        ];
+        let offset = ls.offset_curve(1.);
+        assert_eq!(offset.0.len(), 2);
+        assert_eq!(offset.0[0].0.first(), Some(&coord! { x: 0., y: 1. }));
+        assert_eq!(offset.0[1].0.last(), Some(&coord! { x: 11., y: 1.5 }));
+        assert_at_distance(&offset, &ls, 1.);
+    }
+
+    #[test]
+    fn closed_ring() {
+        let square = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)];
+        let inside = square.offset_curve(1.);
+        assert_eq!(
+            inside.0,
+            vec![
+                line_string![(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 9.), (x: 1., y: 9.), (x: 1., y: 1.)]
+            ]
+        );
+        let outside = square.offset_curve(-1.);
+        assert_eq!(outside.0.len(), 1);
+        assert!(outside.0[0].is_closed());
+        assert_at_distance(&outside, &square, 1.);
+    }
+
+    #[test]
+    fn multi_line_string() {
+        let mls = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 0., y: 5.), (x: 10., y: 5.)],
+        ]);
+        assert_eq!(
+            mls.offset_curve(1.).0,
+            vec![
+                line_string![(x: 0., y: 1.), (x: 10., y: 1.)],
+                line_string![(x: 0., y: 6.), (x: 10., y: 6.)],
+            ]
+        );
+    }
+}
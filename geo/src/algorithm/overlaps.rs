@@ -0,0 +1,25 @@
+/// Tests if two geometries overlap: they have the same dimension, their interiors intersect in
+/// that dimension, and each has points outside the other.
+///
+/// In other words, the [DE-9IM] intersection matrix for (Self, Rhs) is `[T*T***T**]` for two
+/// points or two areas, or `[1*T***T**]` for two lines.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon};
+/// use geo::algorithm::Overlaps;
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let other = polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)];
+/// assert!(polygon.overlaps(&other));
+///
+/// // A line and a polygon have different dimensions, so never overlap.
+/// let line_string = line_string![(x: 1., y: 1.), (x: 3., y: 3.)];
+/// assert!(!polygon.overlaps(&line_string));
+/// ```
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+pub trait Overlaps<Rhs = Self> {
+    fn overlaps(&self, rhs: &Rhs) -> bool;
+}
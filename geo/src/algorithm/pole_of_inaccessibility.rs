@@ -0,0 +1,282 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::{
+    BoundingRect, Centroid, Contains, Coord, EuclideanDistance, GeoFloat, MultiPolygon, Point,
+    Polygon, Rect,
+};
+
+/// Find the point inside a polygon which is farthest from its boundary.
+///
+/// This is the centre of the largest circle which fits inside the polygon, and is a good
+/// place for a label, unlike an [`InteriorPoint`](crate::InteriorPoint), which may be right
+/// next to an edge. It's found with the [polylabel](https://github.com/mapbox/polylabel)
+/// algorithm: the bounding rectangle is split into square cells, and the cells which might
+/// contain a point farther from the boundary than the best found so far are split again, until
+/// none could improve on it by more than `tolerance`.
+///
+/// Returns the point and its distance from the boundary, or `None` for empty polygons.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, PoleOfInaccessibility};
+///
+/// // An L shape, whose centroid is close to its inner corner.
+/// let polygon = polygon![
+///     (x: 0_f64, y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 2.),
+///     (x: 2., y: 2.),
+///     (x: 2., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+///
+/// let (pole, distance) = polygon.pole_of_inaccessibility(0.01).unwrap();
+/// // The circle fits into the corner, touching both outer edges and the inner corner.
+/// assert!((distance - (4. - 8_f64.sqrt())).abs() < 0.01);
+/// assert!(pole.x() < 2. && pole.y() < 2.);
+/// ```
+pub trait PoleOfInaccessibility {
+    type Scalar: GeoFloat;
+
+    /// Returns the point farthest from the boundary, to within `tolerance`, and its distance
+    /// from the boundary.
+    fn pole_of_inaccessibility(
+        &self,
+        tolerance: Self::Scalar,
+    ) -> Option<(Point<Self::Scalar>, Self::Scalar)>;
+}
+
+impl<T: GeoFloat> PoleOfInaccessibility for Polygon<T> {
+    type Scalar = T;
+
+    fn pole_of_inaccessibility(&self, tolerance: T) -> Option<(Point<T>, T)> {
+        pole(self, tolerance)
+    }
+}
+
+impl<T: GeoFloat> PoleOfInaccessibility for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn pole_of_inaccessibility(&self, tolerance: T) -> Option<(Point<T>, T)> {
+        pole(self, tolerance)
+    }
+}
+
+/// The distance from a point to the boundary of a polygon, which is negative outside it.
+trait SignedDistance<T: GeoFloat>: Contains<Point<T>> {
+    fn boundary_distance(&self, point: &Point<T>) -> T;
+
+    fn signed_distance(&self, point: &Point<T>) -> T {
+        let distance = self.boundary_distance(point);
+        if self.contains(point) {
+            distance
+        } else {
+            -distance
+        }
+    }
+}
+
+impl<T: GeoFloat> SignedDistance<T> for Polygon<T> {
+    fn boundary_distance(&self, point: &Point<T>) -> T {
+        std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .map(|ring| point.euclidean_distance(ring))
+            .fold(T::infinity(), T::min)
+    }
+}
+
+impl<T: GeoFloat> SignedDistance<T> for MultiPolygon<T> {
+    fn boundary_distance(&self, point: &Point<T>) -> T {
+        self.iter()
+            .map(|polygon| polygon.boundary_distance(point))
+            .fold(T::infinity(), T::min)
+    }
+}
+
+fn pole<T, G>(geometry: &G, tolerance: T) -> Option<(Point<T>, T)>
+where
+    T: GeoFloat,
+    G: SignedDistance<T>
+        + BoundingRect<T, Output = Option<Rect<T>>>
+        + Centroid<Output = Option<Point<T>>>,
+{
+    let bounds = geometry.bounding_rect()?;
+    let start = std::iter::once(bounds.center()).chain(geometry.centroid().map(|c| c.0));
+    let (center, distance) = polylabel(bounds, start, tolerance, |center, half| {
+        let distance = geometry.signed_distance(&center.into());
+        (distance, distance + half * T::from(2).unwrap().sqrt())
+    });
+    Some((center.into(), distance))
+}
+
+/// A square cell of the search.
+struct Cell<T: GeoFloat> {
+    center: Coord<T>,
+    /// Half the width of the cell.
+    half: T,
+    /// The distance of the centre, if it's a candidate.
+    distance: T,
+    /// The largest distance of any point in the cell.
+    max_distance: T,
+}
+
+impl<T: GeoFloat> Cell<T> {
+    fn new(center: Coord<T>, half: T, evaluate: &impl Fn(Coord<T>, T) -> (T, T)) -> Self {
+        let (distance, max_distance) = evaluate(center, half);
+        Cell {
+            center,
+            half,
+            distance,
+            max_distance,
+        }
+    }
+}
+
+impl<T: GeoFloat> PartialEq for Cell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: GeoFloat> Eq for Cell<T> {}
+
+impl<T: GeoFloat> PartialOrd for Cell<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Ord for Cell<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max_distance
+            .partial_cmp(&other.max_distance)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Finds the point in `bounds` which maximises a distance, to within `tolerance`, starting
+/// from the best of the `start` points.
+///
+/// `evaluate` takes the centre and half-width of a square cell, and returns the distance of the
+/// centre, which should be negative if it isn't a candidate, and an upper bound on the distance
+/// of any point in the cell.
+pub(crate) fn polylabel<T: GeoFloat>(
+    bounds: Rect<T>,
+    start: impl IntoIterator<Item = Coord<T>>,
+    tolerance: T,
+    evaluate: impl Fn(Coord<T>, T) -> (T, T),
+) -> (Coord<T>, T) {
+    let size = bounds.width().min(bounds.height());
+    if size == T::zero() {
+        return (bounds.min(), T::zero());
+    }
+    // A tolerance of zero would never finish.
+    let tolerance = tolerance.max(size * T::epsilon());
+    let two = T::from(2).unwrap();
+
+    // Cover the bounds with square cells.
+    let half = size / two;
+    let mut cells = BinaryHeap::new();
+    let mut x = bounds.min().x;
+    while x < bounds.max().x {
+        let mut y = bounds.min().y;
+        while y < bounds.max().y {
+            let center = Coord {
+                x: x + half,
+                y: y + half,
+            };
+            cells.push(Cell::new(center, half, &evaluate));
+            y = y + size;
+        }
+        x = x + size;
+    }
+
+    let mut best = start
+        .into_iter()
+        .map(|center| Cell::new(center, T::zero(), &evaluate))
+        .max_by(|a, b| {
+            a.distance
+                .partial_cmp(&b.distance)
+                .unwrap_or(Ordering::Equal)
+        })
+        .unwrap_or_else(|| Cell::new(bounds.center(), T::zero(), &evaluate));
+
+    while let Some(cell) = cells.pop() {
+        if cell.distance > best.distance {
+            best = Cell::new(cell.center, T::zero(), &evaluate);
+        }
+        // Cells are popped in order of the best they could do, so no others can improve on
+        // the best either.
+        if cell.max_distance - best.distance <= tolerance {
+            break;
+        }
+        let half = cell.half / two;
+        for (dx, dy) in [(-1., -1.), (1., -1.), (-1., 1.), (1., 1.)] {
+            let center = Coord {
+                x: cell.center.x + half * T::from(dx).unwrap(),
+                y: cell.center.y + half * T::from(dy).unwrap(),
+            };
+            cells.push(Cell::new(center, half, &evaluate));
+        }
+    }
+    (best.center, best.distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{polygon, EuclideanDistance};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn square_with_hole() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 5.), (x: 1., y: 5.)]],
+        );
+        // The widest part is the band across the top.
+        let (pole, distance) = polygon.pole_of_inaccessibility(1e-3).unwrap();
+        assert_relative_eq!(distance, 2.5, epsilon = 1e-3);
+        assert_relative_eq!(pole.y(), 7.5, epsilon = 1e-3);
+        assert!(polygon.contains(&pole));
+    }
+
+    #[test]
+    fn within_tolerance() {
+        let polygon: Polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 7., y: 1.),
+            (x: 9., y: 6.),
+            (x: 4., y: 4.),
+            (x: 1., y: 9.),
+        ];
+        let (pole, distance) = polygon.pole_of_inaccessibility(0.5).unwrap();
+        assert_relative_eq!(distance, pole.euclidean_distance(polygon.exterior()));
+        let (_, precise) = polygon.pole_of_inaccessibility(1e-9).unwrap();
+        assert!(precise >= distance && precise - distance <= 0.5);
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            polygon![(x: 10., y: 0.), (x: 16., y: 0.), (x: 16., y: 6.), (x: 10., y: 6.)],
+        ]);
+        let (pole, distance) = multi.pole_of_inaccessibility(1e-6).unwrap();
+        assert_relative_eq!(pole, Point::new(13., 3.), epsilon = 1e-5);
+        assert_relative_eq!(distance, 3., epsilon = 1e-6);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: Polygon = polygon![];
+        assert_eq!(empty.pole_of_inaccessibility(1.), None);
+        let flat = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(
+            flat.pole_of_inaccessibility(1.),
+            Some((Point::new(0., 0.), 0.))
+        );
+    }
+}
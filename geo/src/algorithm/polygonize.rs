@@ -0,0 +1,480 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::utils::lex_cmp;
+use crate::{
+    Area, Contains, Coord, GeoFloat, IsValid, LineString, MultiLineString, MultiPolygon, Polygon,
+};
+
+/// Form the polygons enclosed by a set of lines.
+///
+/// The lines must be correctly noded: they may only meet at their end points. Lines which cross,
+/// or end in the middle of another line, are not split where they meet. Use [`Node`](crate::Node)
+/// to split lines which aren't.
+///
+/// Every face enclosed by the lines becomes a polygon, with holes for any lines nested inside
+/// it. The lines which don't form part of a polygon are reported separately, in the manner of
+/// JTS's [`Polygonizer`](https://github.com/locationtech/jts/blob/master/modules/core/src/main/java/org/locationtech/jts/operation/polygonize/Polygonizer.java):
+///
+/// - **dangles** have an end which isn't connected to any other line,
+/// - **cut edges** are connected at both ends, but have a polygon on neither side, such as a
+///   line joining two separate rings,
+/// - **invalid ring lines** form rings which are not valid, such as rings which cross
+///   themselves because the lines weren't noded. Input lines with a `NaN` or infinite
+///   coordinate are left out of the graph, and reported here as they are.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, MultiLineString, Polygonize};
+///
+/// let lines = MultiLineString::new(vec![
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+///     line_string![(x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)],
+///     // Splits the square into two.
+///     line_string![(x: 5., y: 0.), (x: 5., y: 10.)],
+///     // Sticks out of the square.
+///     line_string![(x: 10., y: 10.), (x: 15., y: 15.)],
+/// ]);
+///
+/// let polygonization = lines.polygonize();
+/// // Since the middle line doesn't end on the square, it isn't noded.
+/// assert_eq!(polygonization.polygons.0.len(), 1);
+/// assert_eq!(polygonization.dangles.0.len(), 2);
+/// ```
+pub trait Polygonize {
+    type Scalar: GeoFloat;
+
+    /// Returns the polygons formed by the lines, and the lines which don't form polygons.
+    fn polygonize(&self) -> Polygonization<Self::Scalar>;
+}
+
+impl<T: GeoFloat> Polygonize for MultiLineString<T> {
+    type Scalar = T;
+
+    fn polygonize(&self) -> Polygonization<T> {
+        polygonize(&self.0)
+    }
+}
+
+impl<T: GeoFloat> Polygonize for [LineString<T>] {
+    type Scalar = T;
+
+    fn polygonize(&self) -> Polygonization<T> {
+        polygonize(self)
+    }
+}
+
+/// The polygons formed by a set of lines, and the lines which don't form polygons.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygonization<T: GeoFloat> {
+    pub polygons: MultiPolygon<T>,
+    /// Lines with an end which isn't connected to any other line.
+    pub dangles: MultiLineString<T>,
+    /// Lines which are connected at both ends, but don't bound a polygon.
+    pub cut_edges: MultiLineString<T>,
+    /// Rings which are not valid, as closed `LineString`s, and input lines with non-finite
+    /// coordinates.
+    pub invalid_ring_lines: MultiLineString<T>,
+}
+
+/// Returns the polygons formed by the `lines`.
+///
+/// This is the same as [`Polygonize`], for any iterator of lines.
+///
+/// ```
+/// use geo::{line_string, polygonize};
+///
+/// let lines = [
+///     line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+///     line_string![(x: 1., y: 1.), (x: 0., y: 0.)],
+/// ];
+/// assert_eq!(polygonize(&lines).polygons.0.len(), 1);
+/// ```
+pub fn polygonize<'a, T: GeoFloat + 'a>(
+    lines: impl IntoIterator<Item = &'a LineString<T>>,
+) -> Polygonization<T> {
+    let (lines, non_finite): (Vec<_>, Vec<_>) = lines
+        .into_iter()
+        .partition(|line| line.coords().all(|c| c.x.is_finite() && c.y.is_finite()));
+    let mut graph = Graph::new(lines);
+    let dangles = graph.remove_dangles();
+    let cut_edges = graph.remove_cut_edges();
+
+    let mut shells = vec![];
+    let mut holes = vec![];
+    let mut invalid_ring_lines: Vec<_> = non_finite.into_iter().cloned().collect();
+    let components = graph.components();
+    for face in graph.faces() {
+        for ring in graph.split_rings(&face) {
+            let component = components[graph.origin(ring[0])];
+            let ring = graph.ring(&ring);
+            let polygon = Polygon::new(ring, vec![]);
+            let area = polygon.signed_area();
+            if area == T::zero() || !polygon.is_valid() {
+                invalid_ring_lines.push(polygon.into_inner().0);
+            } else if area > T::zero() {
+                shells.push((polygon, area, component));
+            } else {
+                holes.push((polygon.into_inner().0, component));
+            }
+        }
+    }
+
+    // Each hole goes in the smallest shell of another component which contains it. Holes which
+    // aren't in any shell are the outer boundaries of the lines.
+    let mut interiors = vec![vec![]; shells.len()];
+    for (hole, component) in holes {
+        let point = hole.0[0];
+        let shell = shells
+            .iter()
+            .enumerate()
+            .filter(|(_, (shell, _, shell_component))| {
+                *shell_component != component && shell.contains(&point)
+            })
+            .min_by(|(_, a), (_, b)| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+        if let Some((idx, _)) = shell {
+            interiors[idx].push(hole);
+        }
+    }
+    let polygons = shells
+        .into_iter()
+        .zip(interiors)
+        .map(|((shell, _, _), interiors)| Polygon::new(shell.into_inner().0, interiors))
+        .collect();
+
+    Polygonization {
+        polygons: MultiPolygon::new(polygons),
+        dangles: MultiLineString::new(dangles),
+        cut_edges: MultiLineString::new(cut_edges),
+        invalid_ring_lines: MultiLineString::new(invalid_ring_lines),
+    }
+}
+
+/// A planar graph with a node at the ends of each line, and an edge along it.
+///
+/// Each edge `e` has two directed edges: `2 * e` along the line, and `2 * e + 1` against it.
+struct Graph<T: GeoFloat> {
+    lines: Vec<LineString<T>>,
+    /// The node at the start and end of each line.
+    ends: Vec<[usize; 2]>,
+    is_removed: Vec<bool>,
+    num_nodes: usize,
+}
+
+impl<T: GeoFloat> Graph<T> {
+    fn new<'a>(lines: impl IntoIterator<Item = &'a LineString<T>>) -> Self
+    where
+        T: 'a,
+    {
+        let mut lines: Vec<LineString<T>> = lines
+            .into_iter()
+            .map(|line| {
+                let mut coords = line.0.clone();
+                coords.dedup();
+                LineString::new(coords)
+            })
+            .filter(|line| line.0.len() > 1)
+            .collect();
+        // The same line may be given twice, in either direction.
+        let key = |line: &LineString<T>| {
+            let mut reversed = line.0.clone();
+            reversed.reverse();
+            if compare_lines(&line.0, &reversed) == Ordering::Greater {
+                reversed
+            } else {
+                line.0.clone()
+            }
+        };
+        lines.sort_by(|a, b| compare_lines(&key(a), &key(b)));
+        lines.dedup_by(|a, b| key(a) == key(b));
+
+        let mut nodes: Vec<_> = lines
+            .iter()
+            .flat_map(|line| [line.0[0], line.0[line.0.len() - 1]])
+            .collect();
+        nodes.sort_by(lex_cmp);
+        nodes.dedup();
+        let node = |coord: &Coord<T>| nodes.binary_search_by(|n| lex_cmp(n, coord)).unwrap();
+        let ends = lines
+            .iter()
+            .map(|line| [node(&line.0[0]), node(&line.0[line.0.len() - 1])])
+            .collect();
+
+        Graph {
+            is_removed: vec![false; lines.len()],
+            lines,
+            ends,
+            num_nodes: nodes.len(),
+        }
+    }
+
+    fn origin(&self, directed: usize) -> usize {
+        self.ends[directed / 2][directed % 2]
+    }
+
+    fn destination(&self, directed: usize) -> usize {
+        self.origin(directed ^ 1)
+    }
+
+    /// The coordinates of the directed edge, in order.
+    fn coords(&self, directed: usize) -> Vec<Coord<T>> {
+        let mut coords = self.lines[directed / 2].0.clone();
+        if directed % 2 == 1 {
+            coords.reverse();
+        }
+        coords
+    }
+
+    /// Repeatedly removes the edges with an end which isn't connected to any other edge.
+    fn remove_dangles(&mut self) -> Vec<LineString<T>> {
+        let mut degree = vec![0; self.num_nodes];
+        for [start, end] in &self.ends {
+            degree[*start] += 1;
+            degree[*end] += 1;
+        }
+        let mut edges = vec![vec![]; self.num_nodes];
+        for (idx, [start, end]) in self.ends.iter().enumerate() {
+            edges[*start].push(idx);
+            if end != start {
+                edges[*end].push(idx);
+            }
+        }
+
+        let mut dangles = vec![];
+        let mut stack: Vec<_> = (0..self.num_nodes).filter(|n| degree[*n] == 1).collect();
+        while let Some(node) = stack.pop() {
+            let Some(&idx) = edges[node].iter().find(|idx| !self.is_removed[**idx]) else {
+                continue;
+            };
+            self.is_removed[idx] = true;
+            dangles.push(self.lines[idx].clone());
+            for end in self.ends[idx] {
+                degree[end] -= 1;
+                if degree[end] == 1 {
+                    stack.push(end);
+                }
+            }
+        }
+        dangles
+    }
+
+    /// Removes the edges which have the same face on both sides.
+    fn remove_cut_edges(&mut self) -> Vec<LineString<T>> {
+        let mut face_of = vec![usize::MAX; 2 * self.lines.len()];
+        for (idx, face) in self.faces().into_iter().enumerate() {
+            for directed in face {
+                face_of[directed] = idx;
+            }
+        }
+        let mut cut_edges = vec![];
+        for idx in 0..self.lines.len() {
+            if !self.is_removed[idx] && face_of[2 * idx] == face_of[2 * idx + 1] {
+                self.is_removed[idx] = true;
+                cut_edges.push(self.lines[idx].clone());
+            }
+        }
+        cut_edges
+    }
+
+    /// The faces of the graph, as cycles of directed edges with the face on their left.
+    ///
+    /// Bounded faces are traversed counter-clockwise, and the outer boundary of each connected
+    /// part of the graph clockwise.
+    fn faces(&self) -> Vec<Vec<usize>> {
+        // The directed edges leaving each node, in counter-clockwise order.
+        let mut outgoing = vec![vec![]; self.num_nodes];
+        for idx in (0..self.lines.len()).filter(|idx| !self.is_removed[*idx]) {
+            for directed in [2 * idx, 2 * idx + 1] {
+                let coords = self.coords(directed);
+                let delta = coords[1] - coords[0];
+                outgoing[self.origin(directed)].push((delta.y.atan2(delta.x), directed));
+            }
+        }
+        let mut next = vec![usize::MAX; 2 * self.lines.len()];
+        for edges in &mut outgoing {
+            edges.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+            // The edge after an incoming edge is the next one clockwise from its reverse, which
+            // turns as far left as possible.
+            for (idx, &(_, directed)) in edges.iter().enumerate() {
+                let prev = edges[(idx + edges.len() - 1) % edges.len()].1;
+                next[directed ^ 1] = prev;
+            }
+        }
+
+        let mut is_visited = vec![false; 2 * self.lines.len()];
+        let mut faces = vec![];
+        for start in 0..2 * self.lines.len() {
+            if self.is_removed[start / 2] || is_visited[start] {
+                continue;
+            }
+            let mut face = vec![];
+            let mut directed = start;
+            while !is_visited[directed] {
+                is_visited[directed] = true;
+                face.push(directed);
+                directed = next[directed];
+            }
+            faces.push(face);
+        }
+        faces
+    }
+
+    /// Splits a face which visits a node more than once into rings which don't.
+    fn split_rings(&self, face: &[usize]) -> Vec<Vec<usize>> {
+        let mut rings = vec![];
+        let mut path = vec![];
+        // The position in the path at which each node on it was reached.
+        let mut positions = HashMap::from([(self.origin(face[0]), 0)]);
+        for &directed in face {
+            path.push(directed);
+            let node = self.destination(directed);
+            match positions.get(&node) {
+                Some(&position) => {
+                    rings.push(path.split_off(position));
+                    positions.retain(|_, p| *p <= position);
+                }
+                None => {
+                    positions.insert(node, path.len());
+                }
+            }
+        }
+        rings
+    }
+
+    /// The closed ring along a cycle of directed edges.
+    fn ring(&self, cycle: &[usize]) -> LineString<T> {
+        let mut coords = vec![];
+        for &directed in cycle {
+            let edge = self.coords(directed);
+            let skip = usize::from(!coords.is_empty());
+            coords.extend(edge.into_iter().skip(skip));
+        }
+        LineString::new(coords)
+    }
+
+    /// The connected component of each node.
+    fn components(&self) -> Vec<usize> {
+        let mut parent: Vec<_> = (0..self.num_nodes).collect();
+        fn find(parent: &mut [usize], mut node: usize) -> usize {
+            while parent[node] != node {
+                parent[node] = parent[parent[node]];
+                node = parent[node];
+            }
+            node
+        }
+        for (idx, [start, end]) in self.ends.iter().enumerate() {
+            if !self.is_removed[idx] {
+                let (a, b) = (find(&mut parent, *start), find(&mut parent, *end));
+                parent[a] = b;
+            }
+        }
+        (0..self.num_nodes).map(|n| find(&mut parent, n)).collect()
+    }
+}
+
+fn compare_lines<T: GeoFloat>(a: &[Coord<T>], b: &[Coord<T>]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| lex_cmp(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn two_squares() {
+        let lines = MultiLineString::new(vec![
+            line_string![(x: 200., y: 200.), (x: 100., y: 200.), (x: 100., y: 100.), (x: 200., y: 100.)],
+            line_string![(x: 200., y: 200.), (x: 200., y: 100.)],
+            line_string![(x: 200., y: 200.), (x: 300., y: 200.), (x: 300., y: 100.), (x: 200., y: 100.)],
+        ]);
+        let result = lines.polygonize();
+        assert_eq!(result.polygons.0.len(), 2);
+        assert_eq!(result.polygons.unsigned_area(), 20000.);
+        assert!(result.dangles.0.is_empty());
+        assert!(result.cut_edges.0.is_empty());
+        assert!(result.invalid_ring_lines.0.is_empty());
+    }
+
+    #[test]
+    fn nested_ring_is_a_hole() {
+        let lines = [
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)],
+            line_string![(x: 2., y: 2.), (x: 2., y: 8.), (x: 8., y: 8.), (x: 8., y: 2.), (x: 2., y: 2.)],
+        ];
+        let result = polygonize(&lines);
+        assert_eq!(result.polygons.0.len(), 2);
+        let outer = &result.polygons.0[0];
+        assert_eq!(outer.interiors().len(), 1);
+        assert_eq!(outer.unsigned_area(), 64.);
+        assert_eq!(result.polygons.0[1].unsigned_area(), 36.);
+    }
+
+    #[test]
+    fn dangles_and_cut_edges() {
+        let lines = [
+            // Two triangles joined by a line.
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.), (x: 0., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 5., y: 0.)],
+            line_string![(x: 5., y: 0.), (x: 6., y: 0.), (x: 5., y: 1.), (x: 5., y: 0.)],
+            // A tail from one of them.
+            line_string![(x: 6., y: 0.), (x: 7., y: 0.), (x: 8., y: 0.)],
+            line_string![(x: 8., y: 0.), (x: 9., y: 1.)],
+        ];
+        let result = polygonize(&lines);
+        assert_eq!(result.polygons.0.len(), 2);
+        assert_eq!(
+            result.cut_edges.0,
+            vec![line_string![(x: 0., y: 0.), (x: 5., y: 0.)]]
+        );
+        assert_eq!(result.dangles.0.len(), 2);
+    }
+
+    #[test]
+    fn touching_rings() {
+        // Two squares which share a corner, so the outer boundary visits it twice.
+        let lines = [
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.), (x: 0., y: 0.)],
+            line_string![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.), (x: 1., y: 1.)],
+        ];
+        let result = polygonize(&lines);
+        assert_eq!(result.polygons.0.len(), 2);
+        assert!(result.polygons.is_valid());
+        assert!(result.invalid_ring_lines.0.is_empty());
+    }
+
+    #[test]
+    fn invalid_ring() {
+        let bow_tie = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 1., y: 0.), (x: 0., y: 1.), (x: 0., y: 0.)];
+        let result = polygonize(std::slice::from_ref(&bow_tie));
+        assert!(result.polygons.0.is_empty());
+        assert_eq!(result.invalid_ring_lines.0.len(), 2);
+        assert!(result.invalid_ring_lines.0.contains(&bow_tie));
+    }
+
+    #[test]
+    fn non_finite_lines() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let nan = line_string![(x: 0., y: 0.), (x: f64::NAN, y: 1.)];
+        let infinite = line_string![(x: 1., y: 1.), (x: 2., y: f64::INFINITY)];
+        let result = polygonize([square.exterior(), &nan, &infinite]);
+        assert_eq!(result.polygons.0.len(), 1);
+        assert!(result.dangles.0.is_empty());
+        assert_eq!(result.invalid_ring_lines.0.len(), 2);
+        assert!(result.invalid_ring_lines.0.contains(&infinite));
+    }
+
+    #[test]
+    fn duplicate_lines() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let mut reversed = square.exterior().clone();
+        reversed.0.reverse();
+        let result = polygonize([square.exterior(), &reversed]);
+        assert_eq!(result.polygons.0.len(), 1);
+    }
+}
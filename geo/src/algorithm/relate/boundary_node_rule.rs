@@ -0,0 +1,75 @@
+/// Decides which endpoints of a geometry's lines lie on its boundary, from the number of line
+/// endpoints at the same coordinate.
+///
+/// The OGC Simple Features specification uses the [`Mod2`](BoundaryNodeRule::Mod2) rule, which
+/// is the default for [`Relate::relate`](crate::Relate::relate). The other rules suit data like
+/// networks, where the endpoints of lines are significant however many of them meet.
+///
+/// Based on [JTS's `BoundaryNodeRule`](https://locationtech.github.io/jts/javadoc/org/locationtech/jts/algorithm/BoundaryNodeRule.html).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, BoundaryNodeRule, Relate};
+///
+/// // A closed line string has no boundary under the Mod-2 rule...
+/// let ring = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+/// let start = point!(x: 0., y: 0.);
+/// assert!(ring.relate(&start).is_contains());
+///
+/// // ...but its endpoint is on the boundary under the end point rule
+/// let matrix = ring.relate_with(&start, BoundaryNodeRule::EndPoint);
+/// assert!(!matrix.is_contains());
+/// assert!(matrix.is_touches());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BoundaryNodeRule {
+    /// An endpoint is on the boundary if an odd number of line endpoints meet at it.
+    #[default]
+    Mod2,
+    /// Every endpoint is on the boundary.
+    EndPoint,
+    /// An endpoint is on the boundary if more than one line endpoint meets at it.
+    MultivalentEndPoint,
+    /// An endpoint is on the boundary if it's the endpoint of exactly one line.
+    MonovalentEndPoint,
+}
+
+impl BoundaryNodeRule {
+    /// Whether a coordinate where `boundary_count` line endpoints meet is on the boundary.
+    pub fn is_in_boundary(&self, boundary_count: usize) -> bool {
+        match self {
+            BoundaryNodeRule::Mod2 => boundary_count % 2 == 1,
+            BoundaryNodeRule::EndPoint => boundary_count > 0,
+            BoundaryNodeRule::MultivalentEndPoint => boundary_count > 1,
+            BoundaryNodeRule::MonovalentEndPoint => boundary_count == 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_in_boundary() {
+        let rules = [
+            BoundaryNodeRule::Mod2,
+            BoundaryNodeRule::EndPoint,
+            BoundaryNodeRule::MultivalentEndPoint,
+            BoundaryNodeRule::MonovalentEndPoint,
+        ];
+        let expected = [
+            // counts of 1, 2 and 3
+            [true, false, true],
+            [true, true, true],
+            [false, true, true],
+            [true, false, false],
+        ];
+        for (rule, expected) in rules.iter().zip(expected) {
+            for (count, expected) in (1..=3).zip(expected) {
+                assert_eq!(rule.is_in_boundary(count), expected, "{rule:?} {count}");
+            }
+        }
+    }
+}
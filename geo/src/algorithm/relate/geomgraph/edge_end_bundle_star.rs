@@ -2,7 +2,7 @@ use super::{
     Dimensions, Direction, EdgeEnd, EdgeEndBundle, EdgeEndKey, GeometryGraph, IntersectionMatrix,
     LabeledEdgeEndBundle,
 };
-use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::coordinate_position::CoordPos;
 use crate::{Coord, GeoFloat, GeometryCow};
 
 /// An ordered list of [`EdgeEndBundle`]s around a [`RelateNodeFactory::Node`].
@@ -63,14 +63,14 @@ impl<F: GeoFloat> LabeledEdgeEndBundleStar<F> {
                     } else {
                         // PERF: In JTS this is memoized, but that gets a little tricky with rust's
                         // borrow checker. Let's wait to see if it's a hotspot.
-                        let geometry = match geom_index {
-                            0 => graph_a.geometry(),
-                            1 => graph_b.geometry(),
+                        let graph = match geom_index {
+                            0 => graph_a,
+                            1 => graph_b,
                             _ => unreachable!("invalid geom_index"),
                         };
                         use crate::HasDimensions;
-                        if geometry.dimensions() == Dimensions::TwoDimensional {
-                            geometry.coordinate_position(&coord)
+                        if graph.geometry().dimensions() == Dimensions::TwoDimensional {
+                            graph.locate(&coord)
                         } else {
                             // if geometry is *not* an area, Coord is always Outside
                             CoordPos::Outside
@@ -193,7 +193,7 @@ where
         let labeled_edges = self
             .edge_map
             .into_values()
-            .map(|edge_end_bundle| edge_end_bundle.into_labeled())
+            .map(|edge_end_bundle| edge_end_bundle.into_labeled(graph_a.boundary_node_rule()))
             .collect();
         LabeledEdgeEndBundleStar::new(labeled_edges, graph_a, graph_b)
     }
@@ -1,13 +1,20 @@
 use super::{
     index::{
-        EdgeSetIntersector, RstarEdgeSetIntersector, SegmentIntersector, SimpleEdgeSetIntersector,
+        build_segment_tree, EdgeSetIntersector, RstarEdgeSetIntersector, Segment,
+        SegmentIntersector, SimpleEdgeSetIntersector,
     },
-    CoordNode, CoordPos, Direction, Edge, Label, LineIntersector, PlanarGraph, TopologyPosition,
+    node_map::{NodeFactory, NodeMap},
+    CoordNode, CoordPos, Edge, Label, LineIntersector, PlanarGraph, TopologyPosition,
 };
 
-use crate::HasDimensions;
-use crate::{Coord, GeoFloat, GeometryCow, Line, LineString, Point, Polygon};
+use crate::algorithm::relate::BoundaryNodeRule;
 
+use crate::intersects::value_in_between;
+use crate::kernels::{Kernel, Orientation};
+use crate::{BoundingRect, CoordinatePosition, HasDimensions, Intersects};
+use crate::{Coord, GeoFloat, GeometryCow, Line, LineString, Point, Polygon, Rect};
+
+use rstar::{RTree, RTreeNum, AABB};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -31,8 +38,15 @@ where
     F: GeoFloat,
 {
     arg_index: usize,
-    parent_geometry: &'a GeometryCow<'a, F>,
+    parent_geometry: GeometryCow<'a, F>,
+    bounding_rect: Option<Rect<F>>,
+    /// The tree of the edges' segments, if it's been cached by [`GeometryGraph::build_tree`]
+    tree: Option<Rc<RTree<Segment<F>>>>,
+    boundary_node_rule: BoundaryNodeRule,
+    /// The number of line endpoints at each potential boundary node
+    boundary_counts: NodeMap<F, BoundaryCount>,
     use_boundary_determination_rule: bool,
+    has_computed_self_nodes: bool,
     planar_graph: PlanarGraph<F>,
 }
 
@@ -69,27 +83,143 @@ impl<'a, F> GeometryGraph<'a, F>
 where
     F: GeoFloat,
 {
-    pub fn new(arg_index: usize, parent_geometry: &'a GeometryCow<F>) -> Self {
+    pub fn new(
+        arg_index: usize,
+        parent_geometry: GeometryCow<'a, F>,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> Self {
         let mut graph = GeometryGraph {
             arg_index,
+            bounding_rect: parent_geometry.bounding_rect(),
             parent_geometry,
+            tree: None,
+            boundary_node_rule,
+            boundary_counts: NodeMap::new(),
             use_boundary_determination_rule: true,
+            has_computed_self_nodes: false,
             planar_graph: PlanarGraph::new(),
         };
-        graph.add_geometry(parent_geometry);
+        let parent_geometry = graph.parent_geometry.clone();
+        graph.add_geometry(&parent_geometry);
         graph
     }
 
+    /// A copy of this graph for use as the geometry at `arg_index` in a relate operation,
+    /// sharing its cached segment tree.
+    ///
+    /// The copy's edges are distinct from this graph's, so relating the copy leaves this graph
+    /// untouched and reusable.
+    pub fn clone_for_arg_index(&self, arg_index: usize) -> Self {
+        GeometryGraph {
+            arg_index,
+            parent_geometry: self.parent_geometry.clone(),
+            bounding_rect: self.bounding_rect,
+            tree: self.tree.clone(),
+            boundary_node_rule: self.boundary_node_rule,
+            boundary_counts: self.boundary_counts.clone(),
+            use_boundary_determination_rule: self.use_boundary_determination_rule,
+            has_computed_self_nodes: self.has_computed_self_nodes,
+            planar_graph: self
+                .planar_graph
+                .clone_for_arg_index(self.arg_index, arg_index),
+        }
+    }
+
+    pub fn arg_index(&self) -> usize {
+        self.arg_index
+    }
+
+    pub fn boundary_node_rule(&self) -> BoundaryNodeRule {
+        self.boundary_node_rule
+    }
+
     pub fn geometry(&self) -> &GeometryCow<F> {
-        self.parent_geometry
+        &self.parent_geometry
+    }
+
+    pub fn bounding_rect(&self) -> Option<Rect<F>> {
+        self.bounding_rect
+    }
+
+    /// Cache the tree of the edges' segments, to be shared with the graph's clones.
+    pub fn build_tree(&mut self) {
+        self.tree = Some(self.get_or_build_tree());
+    }
+
+    pub fn get_or_build_tree(&self) -> Rc<RTree<Segment<F>>> {
+        match &self.tree {
+            Some(tree) => tree.clone(),
+            None => Rc::new(build_segment_tree(self.edges())),
+        }
+    }
+
+    /// The position of `coord` relative to the geometry.
+    ///
+    /// If the geometry is an area and its segment tree is cached, only the segments crossing a
+    /// ray from `coord` are checked.
+    pub fn locate(&self, coord: &Coord<F>) -> CoordPos {
+        let is_area = matches!(
+            self.geometry(),
+            GeometryCow::Polygon(_) | GeometryCow::MultiPolygon(_)
+        );
+        match &self.tree {
+            Some(tree) if is_area => self.locate_in_area(tree, *coord),
+            _ => self.geometry().coordinate_position(coord),
+        }
+    }
+
+    /// Count the crossings of a ray to the right of `coord` with the rings of an area, which is
+    /// odd for an interior point as every hole lies inside its shell.
+    fn locate_in_area(&self, tree: &RTree<Segment<F>>, coord: Coord<F>) -> CoordPos {
+        let Some(bounding_rect) = self.bounding_rect else {
+            return CoordPos::Outside;
+        };
+        if !bounding_rect.intersects(&coord) {
+            return CoordPos::Outside;
+        }
+        let ray = AABB::from_corners(
+            coord,
+            Coord {
+                x: bounding_rect.max().x,
+                y: coord.y,
+            },
+        );
+        let mut crossings = 0;
+        for segment in tree.locate_in_envelope_intersecting(&ray) {
+            let edge = self.edges()[segment.edge_idx].borrow();
+            let start = edge.coords()[segment.segment_idx];
+            let end = edge.coords()[segment.segment_idx + 1];
+            // The same edge crossing rules as `coord_pos_relative_to_ring`
+            let (low, high) = if start.y <= end.y {
+                (start, end)
+            } else {
+                (end, start)
+            };
+            if low.y > coord.y || high.y < coord.y {
+                continue;
+            }
+            let orientation = F::Ker::orient2d(low, high, coord);
+            if orientation == Orientation::Collinear && value_in_between(coord.x, start.x, end.x) {
+                return CoordPos::OnBoundary;
+            }
+            if orientation == Orientation::CounterClockwise && high.y != coord.y {
+                crossings += 1;
+            }
+        }
+        if crossings % 2 == 1 {
+            CoordPos::Inside
+        } else {
+            CoordPos::Outside
+        }
     }
 
     /// Determine whether a component (node or edge) that appears multiple times in elements
     /// of a Multi-Geometry is in the boundary or the interior of the Geometry
-    pub fn determine_boundary(boundary_count: usize) -> CoordPos {
-        // For now, we only support the SFS "Mod-2 Rule"
-        // We could make this configurable if we wanted to support alternative boundary rules.
-        if boundary_count % 2 == 1 {
+    pub fn determine_boundary(
+        boundary_node_rule: BoundaryNodeRule,
+        boundary_count: usize,
+    ) -> CoordPos {
+        if boundary_node_rule.is_in_boundary(boundary_count) {
             CoordPos::OnBoundary
         } else {
             CoordPos::Inside
@@ -107,7 +237,7 @@ where
         Box::new(RstarEdgeSetIntersector::new())
     }
 
-    fn boundary_nodes(&self) -> impl Iterator<Item = &CoordNode<F>> {
+    pub fn boundary_nodes(&self) -> impl Iterator<Item = &CoordNode<F>> {
         self.planar_graph.boundary_nodes(self.arg_index)
     }
 
@@ -273,10 +403,15 @@ where
     /// assumed to be valid).
     ///
     /// `line_intersector` the [`LineIntersector`] to use to determine intersection
-    pub fn compute_self_nodes(
-        &mut self,
-        line_intersector: Box<dyn LineIntersector<F>>,
-    ) -> SegmentIntersector<F> {
+    ///
+    /// The self-nodes are only computed once, so this does nothing for a graph cloned from one
+    /// which has already computed them.
+    pub fn compute_self_nodes(&mut self, line_intersector: Box<dyn LineIntersector<F>>) {
+        if self.has_computed_self_nodes {
+            return;
+        }
+        self.has_computed_self_nodes = true;
+
         let mut segment_intersector = SegmentIntersector::new(line_intersector, true);
 
         let mut edge_set_intersector = Self::create_edge_set_intersector();
@@ -291,22 +426,26 @@ where
         let check_for_self_intersecting_edges = !is_rings;
 
         edge_set_intersector.compute_intersections_within_set(
-            self.edges(),
+            self,
             check_for_self_intersecting_edges,
             &mut segment_intersector,
         );
 
         self.add_self_intersection_nodes();
-
-        segment_intersector
     }
 
+    /// Compute the intersections between the edges of this graph and `other`.
+    ///
+    /// If `is_done_when_proper_interior`, stop at the first proper interior intersection, which
+    /// leaves the edges' intersections incomplete.
     pub fn compute_edge_intersections(
         &self,
-        other: &GeometryGraph<F>,
+        other: &GeometryGraph<'a, F>,
         line_intersector: Box<dyn LineIntersector<F>>,
+        is_done_when_proper_interior: bool,
     ) -> SegmentIntersector<F> {
         let mut segment_intersector = SegmentIntersector::new(line_intersector, false);
+        segment_intersector.set_is_done_when_proper_interior(is_done_when_proper_interior);
         segment_intersector.set_boundary_nodes(
             self.boundary_nodes().cloned().collect(),
             other.boundary_nodes().cloned().collect(),
@@ -314,14 +453,39 @@ where
 
         let mut edge_set_intersector = Self::create_edge_set_intersector();
         edge_set_intersector.compute_intersections_between_sets(
-            self.edges(),
-            other.edges(),
+            self,
+            other,
             &mut segment_intersector,
         );
 
         segment_intersector
     }
 
+    /// Whether an edge of this graph properly crosses an edge of `other`, stopping at the first
+    /// crossing. Unlike [`GeometryGraph::compute_edge_intersections`], no intersections are
+    /// recorded on the edges, so this can be checked before the self-nodes are computed.
+    pub fn has_proper_crossing(
+        &self,
+        other: &GeometryGraph<'a, F>,
+        mut line_intersector: Box<dyn LineIntersector<F>>,
+    ) -> bool {
+        let line = |graph: &GeometryGraph<F>, segment: &Segment<F>| {
+            let edge = graph.edges()[segment.edge_idx].borrow();
+            Line::new(
+                edge.coords()[segment.segment_idx],
+                edge.coords()[segment.segment_idx + 1],
+            )
+        };
+        let tree = self.get_or_build_tree();
+        let other_tree = other.get_or_build_tree();
+        let mut candidates = tree.intersection_candidates_with_other_tree(&other_tree);
+        candidates.any(|(segment, other_segment)| {
+            line_intersector
+                .compute_intersection(line(self, segment), line(other, other_segment))
+                .map_or(false, |intersection| intersection.is_proper())
+        })
+    }
+
     fn insert_point(&mut self, arg_index: usize, coord: Coord<F>, position: CoordPos) {
         let node: &mut CoordNode<F> = self.add_node_with_coordinate(coord);
         node.label_mut().set_on_position(arg_index, position);
@@ -330,24 +494,12 @@ where
     /// Add the boundary points of 1-dim (line) geometries.
     fn insert_boundary_point(&mut self, coord: Coord<F>) {
         let arg_index = self.arg_index;
-        let node: &mut CoordNode<F> = self.add_node_with_coordinate(coord);
+        let boundary_count = self.boundary_counts.insert_node_with_coordinate(coord);
+        *boundary_count += 1;
+        let new_position = Self::determine_boundary(self.boundary_node_rule, *boundary_count);
 
-        let label: &mut Label = node.label_mut();
-
-        // determine the current location for the point (if any)
-        let boundary_count = {
-            #[allow(clippy::bool_to_int_with_if)]
-            let prev_boundary_count =
-                if Some(CoordPos::OnBoundary) == label.position(arg_index, Direction::On) {
-                    1
-                } else {
-                    0
-                };
-            prev_boundary_count + 1
-        };
-
-        let new_position = Self::determine_boundary(boundary_count);
-        label.set_on_position(arg_index, new_position);
+        let node: &mut CoordNode<F> = self.add_node_with_coordinate(coord);
+        node.label_mut().set_on_position(arg_index, new_position);
     }
 
     fn add_self_intersection_nodes(&mut self) {
@@ -393,3 +545,12 @@ where
         }
     }
 }
+
+/// Creates the counters of line endpoints stored in [`GeometryGraph`]
+struct BoundaryCount;
+impl<F: GeoFloat> NodeFactory<F> for BoundaryCount {
+    type Node = usize;
+    fn create_node(_coordinate: Coord<F>) -> usize {
+        0
+    }
+}
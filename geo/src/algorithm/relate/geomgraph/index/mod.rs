@@ -4,6 +4,6 @@ mod segment_intersector;
 mod simple_edge_set_intersector;
 
 pub(crate) use edge_set_intersector::EdgeSetIntersector;
-pub(crate) use rstar_edge_set_intersector::RstarEdgeSetIntersector;
+pub(crate) use rstar_edge_set_intersector::{build_segment_tree, RstarEdgeSetIntersector, Segment};
 pub(crate) use segment_intersector::SegmentIntersector;
 pub(crate) use simple_edge_set_intersector::SimpleEdgeSetIntersector;
@@ -15,6 +15,8 @@ where
     proper_intersection_point: Option<Coord<F>>,
     has_proper_interior_intersection: bool,
     boundary_nodes: Option<[Vec<CoordNode<F>>; 2]>,
+    is_done_when_proper_interior: bool,
+    is_done: bool,
 }
 
 impl<F> SegmentIntersector<F>
@@ -36,8 +38,23 @@ where
             has_proper_interior_intersection: false,
             proper_intersection_point: None,
             boundary_nodes: None,
+            is_done_when_proper_interior: false,
+            is_done: false,
         }
     }
+
+    /// Stop at the first proper interior intersection, for when that alone determines the
+    /// result. Intersections found so far aren't all recorded on their edges, so the edges
+    /// shouldn't be used once the intersector is done.
+    pub fn set_is_done_when_proper_interior(&mut self, is_done_when_proper_interior: bool) {
+        self.is_done_when_proper_interior = is_done_when_proper_interior;
+    }
+
+    /// Whether the intersector has stopped early, so no more intersections need to be added.
+    pub fn is_done(&self) -> bool {
+        self.is_done
+    }
+
     pub fn set_boundary_nodes(
         &mut self,
         boundary_nodes_0: Vec<CoordNode<F>>,
@@ -153,7 +170,10 @@ where
                 self.proper_intersection_point = Some(intersection_coord);
 
                 if !self.is_boundary_point(&intersection_coord, &self.boundary_nodes) {
-                    self.has_proper_interior_intersection = true
+                    self.has_proper_interior_intersection = true;
+                    if self.is_done_when_proper_interior {
+                        self.is_done = true;
+                    }
                 }
             }
         }
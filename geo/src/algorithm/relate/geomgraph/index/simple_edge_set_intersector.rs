@@ -1,4 +1,4 @@
-use super::super::Edge;
+use super::super::{Edge, GeometryGraph};
 use super::{EdgeSetIntersector, SegmentIntersector};
 use crate::GeoFloat;
 
@@ -22,6 +22,9 @@ impl SimpleEdgeSetIntersector {
         let edge1_coords_len = edge1.borrow().coords().len() - 1;
         for i0 in 0..edge0_coords_len {
             for i1 in 0..edge1_coords_len {
+                if segment_intersector.is_done() {
+                    return;
+                }
                 segment_intersector.add_intersections(edge0, i0, edge1, i1);
             }
         }
@@ -31,10 +34,11 @@ impl SimpleEdgeSetIntersector {
 impl<F: GeoFloat> EdgeSetIntersector<F> for SimpleEdgeSetIntersector {
     fn compute_intersections_within_set(
         &mut self,
-        edges: &[Rc<RefCell<Edge<F>>>],
+        graph: &GeometryGraph<F>,
         check_for_self_intersecting_edges: bool,
         segment_intersector: &mut SegmentIntersector<F>,
     ) {
+        let edges = graph.edges();
         for edge0 in edges.iter() {
             for edge1 in edges.iter() {
                 if check_for_self_intersecting_edges || edge0.as_ptr() != edge1.as_ptr() {
@@ -44,14 +48,14 @@ impl<F: GeoFloat> EdgeSetIntersector<F> for SimpleEdgeSetIntersector {
         }
     }
 
-    fn compute_intersections_between_sets(
+    fn compute_intersections_between_sets<'a>(
         &mut self,
-        edges0: &[Rc<RefCell<Edge<F>>>],
-        edges1: &[Rc<RefCell<Edge<F>>>],
+        graph_0: &GeometryGraph<'a, F>,
+        graph_1: &GeometryGraph<'a, F>,
         segment_intersector: &mut SegmentIntersector<F>,
     ) {
-        for edge0 in edges0 {
-            for edge1 in edges1 {
+        for edge0 in graph_0.edges() {
+            for edge1 in graph_1.edges() {
                 self.compute_intersects(edge0, edge1, segment_intersector);
             }
         }
@@ -223,6 +223,126 @@ impl IntersectionMatrix {
             && self.0[CoordPos::Outside][CoordPos::OnBoundary] == Dimensions::Empty
     }
 
+    /// Tests whether this matrix matches `[FT*******]`, `[F**T*****]` or `[F***T****]`.
+    ///
+    /// returns `true` if the two geometries related by this matrix touch: they have at least
+    /// one point in common, but their interiors don't intersect.
+    pub fn is_touches(&self) -> bool {
+        self.0[CoordPos::Inside][CoordPos::Inside] == Dimensions::Empty
+            && (self.0[CoordPos::Inside][CoordPos::OnBoundary] != Dimensions::Empty
+                || self.0[CoordPos::OnBoundary][CoordPos::Inside] != Dimensions::Empty
+                || self.0[CoordPos::OnBoundary][CoordPos::OnBoundary] != Dimensions::Empty)
+    }
+
+    /// Tests whether this matrix matches `[T*T******]` if the first geometry has a lower
+    /// dimension than the second, `[T*****T**]` if it has a higher dimension, or `[0********]`
+    /// if both are lines.
+    ///
+    /// returns `true` if the first geometry crosses the second.
+    pub fn is_crosses(&self) -> bool {
+        let inside_inside = self.0[CoordPos::Inside][CoordPos::Inside];
+        match self.dimensions() {
+            (Dimensions::OneDimensional, Dimensions::OneDimensional) => {
+                inside_inside == Dimensions::ZeroDimensional
+            }
+            (a, b) if a < b && a != Dimensions::Empty => {
+                inside_inside != Dimensions::Empty
+                    && self.0[CoordPos::Inside][CoordPos::Outside] != Dimensions::Empty
+            }
+            (a, b) if a > b && b != Dimensions::Empty => {
+                inside_inside != Dimensions::Empty
+                    && self.0[CoordPos::Outside][CoordPos::Inside] != Dimensions::Empty
+            }
+            _ => false,
+        }
+    }
+
+    /// Tests whether this matrix matches `[T*T***T**]` for two points or two areas, or
+    /// `[1*T***T**]` for two lines.
+    ///
+    /// returns `true` if the two geometries overlap: they have the same dimension, their
+    /// interiors intersect in that dimension, and neither covers the other.
+    pub fn is_overlaps(&self) -> bool {
+        let inside_inside = self.0[CoordPos::Inside][CoordPos::Inside];
+        let interiors_intersect = match self.dimensions() {
+            (Dimensions::ZeroDimensional, Dimensions::ZeroDimensional)
+            | (Dimensions::TwoDimensional, Dimensions::TwoDimensional) => {
+                inside_inside != Dimensions::Empty
+            }
+            (Dimensions::OneDimensional, Dimensions::OneDimensional) => {
+                inside_inside == Dimensions::OneDimensional
+            }
+            _ => false,
+        };
+        interiors_intersect
+            && self.0[CoordPos::Inside][CoordPos::Outside] != Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::Inside] != Dimensions::Empty
+    }
+
+    /// Tests whether this matrix matches `[T*****FF*]`, `[*T****FF*]`, `[***T**FF*]` or
+    /// `[****T*FF*]`.
+    ///
+    /// returns `true` if the first geometry covers the second: no point of the second lies
+    /// outside the first. Unlike [`is_contains`](Self::is_contains), this holds for a geometry
+    /// lying entirely on the boundary of the first.
+    pub fn is_covers(&self) -> bool {
+        let has_common_point = self.0[CoordPos::Inside][CoordPos::Inside] != Dimensions::Empty
+            || self.0[CoordPos::Inside][CoordPos::OnBoundary] != Dimensions::Empty
+            || self.0[CoordPos::OnBoundary][CoordPos::Inside] != Dimensions::Empty
+            || self.0[CoordPos::OnBoundary][CoordPos::OnBoundary] != Dimensions::Empty;
+        has_common_point
+            && self.0[CoordPos::Outside][CoordPos::Inside] == Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::OnBoundary] == Dimensions::Empty
+    }
+
+    /// Tests whether this matrix matches `[T*F**F***]`, `[*TF**F***]`, `[**FT*F***]` or
+    /// `[**F*TF***]`.
+    ///
+    /// returns `true` if the first geometry is covered by the second.
+    pub fn is_covered_by(&self) -> bool {
+        self.transpose().is_covers()
+    }
+
+    /// Tests whether this matrix matches `[T*F**FFF*]`.
+    ///
+    /// returns `true` if the two geometries are topologically equal: they cover the same
+    /// points, regardless of how they are represented.
+    pub fn is_equal_topo(&self) -> bool {
+        self.0[CoordPos::Inside][CoordPos::Inside] != Dimensions::Empty
+            && self.0[CoordPos::Inside][CoordPos::Outside] == Dimensions::Empty
+            && self.0[CoordPos::OnBoundary][CoordPos::Outside] == Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::Inside] == Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::OnBoundary] == Dimensions::Empty
+    }
+
+    /// The matrix relating the second geometry to the first.
+    pub(crate) fn transpose(&self) -> Self {
+        let mut transposed = Self::empty();
+        for a in [CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside] {
+            for b in [CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside] {
+                transposed.0[b][a] = self.0[a][b];
+            }
+        }
+        transposed
+    }
+
+    /// The dimensions of the two geometries, as their interiors are the union of the entries of
+    /// the first row and column.
+    fn dimensions(&self) -> (Dimensions, Dimensions) {
+        let positions = [CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside];
+        let first = positions
+            .iter()
+            .map(|b| self.0[CoordPos::Inside][*b])
+            .max()
+            .unwrap_or(Dimensions::Empty);
+        let second = positions
+            .iter()
+            .map(|a| self.0[*a][CoordPos::Inside])
+            .max()
+            .unwrap_or(Dimensions::Empty);
+        (first, second)
+    }
+
     /// Directly accesses this matrix
     ///
     /// ```
@@ -290,25 +410,49 @@ impl IntersectionMatrix {
     /// assert!(!im.matches("TTT***FFF").expect("valid de-9im spec"));
     /// ```
     pub fn matches(&self, spec: &str) -> Result<bool, InvalidInputError> {
-        if spec.len() != 9 {
-            return Err(InvalidInputError::new(format!(
-                "de-9im specification must be exactly 9 characters. Got {len}",
-                len = spec.len()
-            )));
-        }
+        Ok(self.matches_spec(&dimension_matcher::parse_spec(spec)?))
+    }
 
-        let mut chars = spec.chars();
-        for a in &[CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside] {
-            for b in &[CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside] {
-                let dim_spec = dimension_matcher::DimensionMatcher::try_from(
-                    chars.next().expect("already validated length is 9"),
-                )?;
-                if !dim_spec.matches(self.0[*a][*b]) {
-                    return Ok(false);
+    /// Does the intersection matrix match the parsed de-9im specification?
+    pub(crate) fn matches_spec(&self, spec: &[dimension_matcher::DimensionMatcher; 9]) -> bool {
+        Self::cells()
+            .zip(spec)
+            .all(|((a, b), dim_spec)| dim_spec.matches(self.0[a][b]))
+    }
+
+    /// Treating this matrix's entries as lower bounds, whether the final matrix matches the
+    /// parsed de-9im specification, or `None` if that depends on entries yet to be computed.
+    ///
+    /// An entry can only grow, so an empty entry which must be non-empty, or an entry of a
+    /// particular dimension, is still undecided.
+    pub(crate) fn lower_bound_matches(
+        &self,
+        spec: &[dimension_matcher::DimensionMatcher; 9],
+    ) -> Option<bool> {
+        use dimension_matcher::DimensionMatcher;
+        let mut is_decided = true;
+        for ((a, b), dim_spec) in Self::cells().zip(spec) {
+            let lower_bound = self.0[a][b];
+            match dim_spec {
+                DimensionMatcher::Anything => {}
+                DimensionMatcher::NonEmpty if lower_bound != Dimensions::Empty => {}
+                DimensionMatcher::Exact(dimensions) if lower_bound > *dimensions => {
+                    return Some(false)
                 }
+                DimensionMatcher::Exact(Dimensions::TwoDimensional)
+                    if lower_bound == Dimensions::TwoDimensional => {}
+                DimensionMatcher::NonEmpty | DimensionMatcher::Exact(_) => is_decided = false,
             }
         }
-        Ok(true)
+        is_decided.then_some(true)
+    }
+
+    /// The positions of the cells of the matrix, in the order of a de-9im specification.
+    fn cells() -> impl Iterator<Item = (CoordPos, CoordPos)> {
+        let positions = [CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside];
+        positions
+            .into_iter()
+            .flat_map(move |a| positions.into_iter().map(move |b| (a, b)))
     }
 }
 
@@ -351,6 +495,31 @@ pub(crate) mod dimension_matcher {
         }
     }
 
+    /// Parse a de-9im matching specification, which must be exactly 9 characters long.
+    pub(crate) fn parse_spec(spec: &str) -> Result<[DimensionMatcher; 9], InvalidInputError> {
+        if spec.len() != 9 {
+            return Err(InvalidInputError::new(format!(
+                "de-9im specification must be exactly 9 characters. Got {len}",
+                len = spec.len()
+            )));
+        }
+
+        let mut chars = spec.chars();
+        let mut next =
+            || DimensionMatcher::try_from(chars.next().expect("already validated length is 9"));
+        Ok([
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+        ])
+    }
+
     impl TryFrom<char> for DimensionMatcher {
         type Error = InvalidInputError;
 
@@ -400,4 +569,93 @@ mod tests {
     fn matches_wildcard() {
         assert!(subject().matches("F0011122*").unwrap());
     }
+
+    #[test]
+    fn lower_bound_matches() {
+        use dimension_matcher::parse_spec;
+        let lower_bound = im("FFF0FFFF2");
+        // an entry known to be non-empty
+        assert_eq!(
+            lower_bound.lower_bound_matches(&parse_spec("***T*****").unwrap()),
+            Some(true)
+        );
+        assert_eq!(
+            lower_bound.lower_bound_matches(&parse_spec("***F*****").unwrap()),
+            Some(false)
+        );
+        // entries which could still grow
+        assert_eq!(
+            lower_bound.lower_bound_matches(&parse_spec("***0*****").unwrap()),
+            None
+        );
+        assert_eq!(
+            lower_bound.lower_bound_matches(&parse_spec("T********").unwrap()),
+            None
+        );
+        // two dimensions is the most an entry can have
+        assert_eq!(
+            lower_bound.lower_bound_matches(&parse_spec("********2").unwrap()),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn invalid_spec() {
+        assert!(subject().matches("F0011122").is_err());
+        assert!(subject().matches("22222222X").is_err());
+    }
+
+    fn im(spec: &str) -> IntersectionMatrix {
+        IntersectionMatrix::from_str(spec).unwrap()
+    }
+
+    #[test]
+    fn touches() {
+        // polygons sharing an edge
+        assert!(im("FF2F11212").is_touches());
+        // overlapping polygons
+        assert!(!im("212101212").is_touches());
+        // distinct points
+        assert!(!im("FF0FFF0F2").is_touches());
+    }
+
+    #[test]
+    fn crosses() {
+        // line crossing a polygon
+        assert!(im("101FF0212").is_crosses());
+        // polygon crossed by a line
+        assert!(im("1F20F1102").is_crosses());
+        // lines crossing at a point
+        assert!(im("0F1FF0102").is_crosses());
+        // lines overlapping along a segment
+        assert!(!im("1F1FF0102").is_crosses());
+        // line within a polygon
+        assert!(!im("1FF0FF212").is_crosses());
+    }
+
+    #[test]
+    fn overlaps() {
+        assert!(im("212101212").is_overlaps());
+        assert!(im("1010F0102").is_overlaps());
+        assert!(!im("0F1FF0102").is_overlaps());
+        // line and polygon have different dimensions
+        assert!(!im("101FF0212").is_overlaps());
+    }
+
+    #[test]
+    fn covers() {
+        // polygon and a line along its boundary
+        let matrix = im("F1FF0F212").transpose();
+        assert!(matrix.is_covers());
+        assert!(!matrix.is_contains());
+        assert!(matrix.transpose().is_covered_by());
+        assert!(!matrix.transpose().is_within());
+        assert!(!im("212101212").is_covers());
+    }
+
+    #[test]
+    fn equal_topo() {
+        assert!(im("2FFF1FFF2").is_equal_topo());
+        assert!(!im("2FF11F212").is_equal_topo());
+    }
 }
@@ -67,6 +67,11 @@ impl Label {
         self.geometry_topologies[1].flip();
     }
 
+    /// Exchange the positions for the two geometries.
+    pub fn swap_args(&mut self) {
+        self.geometry_topologies.swap(0, 1);
+    }
+
     pub fn position(&self, geom_index: usize, direction: Direction) -> Option<CoordPos> {
         self.geometry_topologies[geom_index].get(direction)
     }
@@ -33,6 +33,20 @@ where
     }
 }
 
+impl<F, NF> Clone for NodeMap<F, NF>
+where
+    F: GeoFloat,
+    NF: NodeFactory<F>,
+    NF::Node: Clone,
+{
+    fn clone(&self) -> Self {
+        NodeMap {
+            map: self.map.clone(),
+            _node_factory: PhantomData,
+        }
+    }
+}
+
 #[derive(Clone)]
 struct NodeKey<F: GeoFloat>(Coord<F>);
 
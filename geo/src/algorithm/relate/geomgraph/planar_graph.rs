@@ -37,6 +37,28 @@ impl<F: GeoFloat> PlanarGraph<F> {
         }
     }
 
+    /// A deep copy of the graph, with the labels for the geometry at `from_arg_index` moved to
+    /// `to_arg_index`.
+    pub fn clone_for_arg_index(&self, from_arg_index: usize, to_arg_index: usize) -> Self {
+        let mut graph = Self {
+            nodes: self.nodes.clone(),
+            edges: self
+                .edges
+                .iter()
+                .map(|edge| Rc::new(RefCell::new(edge.borrow().clone())))
+                .collect(),
+        };
+        if from_arg_index != to_arg_index {
+            for node in graph.nodes.iter_mut() {
+                node.label_mut().swap_args();
+            }
+            for edge in &graph.edges {
+                edge.borrow_mut().label_mut().swap_args();
+            }
+        }
+        graph
+    }
+
     pub fn is_boundary_node(&self, geom_index: usize, coord: Coord<F>) -> bool {
         self.nodes
             .find(coord)
@@ -1,11 +1,17 @@
+pub use boundary_node_rule::BoundaryNodeRule;
 pub(crate) use edge_end_builder::EdgeEndBuilder;
-pub use geomgraph::intersection_matrix::IntersectionMatrix;
+use geomgraph::GeometryGraph;
+use geomgraph::intersection_matrix::dimension_matcher;
+pub use geomgraph::intersection_matrix::{IntersectionMatrix, InvalidInputError};
+pub use prepared_geometry::PreparedGeometry;
 
 use crate::geometry::*;
-use crate::{GeoFloat, GeometryCow};
+use crate::{Covers, Crosses, EqualsTopo, GeoFloat, GeometryCow, Overlaps, Touches};
 
+mod boundary_node_rule;
 mod edge_end_builder;
 mod geomgraph;
+mod prepared_geometry;
 mod relate_operation;
 
 /// Topologically relate two geometries based on [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) semantics.
@@ -52,14 +58,85 @@ mod relate_operation;
 ///
 /// Note: `Relate` must not be called on geometries containing `NaN` coordinates.
 pub trait Relate<F, T> {
-    fn relate(&self, other: &T) -> IntersectionMatrix;
+    /// Relate the geometries under the OGC [`BoundaryNodeRule::Mod2`] rule.
+    fn relate(&self, other: &T) -> IntersectionMatrix {
+        self.relate_with(other, BoundaryNodeRule::Mod2)
+    }
+
+    /// Relate the geometries, deciding which endpoints of their lines are on their boundaries
+    /// with `boundary_node_rule`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, point, BoundaryNodeRule, MultiLineString, Relate};
+    ///
+    /// // A network of two lines meeting at a junction
+    /// let network = MultiLineString::new(vec![
+    ///     line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+    ///     line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+    /// ]);
+    /// let junction = point!(x: 1., y: 0.);
+    ///
+    /// // Under the Mod-2 rule, the junction is in the network's interior...
+    /// assert!(network.relate(&junction).is_contains());
+    ///
+    /// // ...but every endpoint is on the boundary under the end point rule
+    /// let matrix = network.relate_with(&junction, BoundaryNodeRule::EndPoint);
+    /// assert!(matrix.is_touches());
+    /// ```
+    fn relate_with(&self, other: &T, boundary_node_rule: BoundaryNodeRule) -> IntersectionMatrix;
+
+    /// Whether the [`IntersectionMatrix`] of the geometries matches the de-9im `spec`, as
+    /// [`IntersectionMatrix::matches`] would.
+    ///
+    /// Rather than computing the whole matrix, this stops as soon as the answer is known: when
+    /// the bounding rectangles of the geometries are disjoint, or at the first proper crossing of
+    /// their edges if that decides the answer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, polygon, Relate};
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    /// let crossing = line_string![(x: -5., y: 5.), (x: 15., y: 5.)];
+    ///
+    /// // intersects
+    /// assert!(polygon.relate_matches(&crossing, "T********").unwrap());
+    /// // contains
+    /// assert!(!polygon.relate_matches(&crossing, "T*****FF*").unwrap());
+    /// ```
+    fn relate_matches(&self, other: &T, spec: &str) -> Result<bool, InvalidInputError> {
+        self.relate(other).matches(spec)
+    }
 }
 
 impl<F: GeoFloat> Relate<F, GeometryCow<'_, F>> for GeometryCow<'_, F> {
-    fn relate(&self, other: &GeometryCow<F>) -> IntersectionMatrix {
-        let mut relate_computer = relate_operation::RelateOperation::new(self, other);
+    fn relate_with(
+        &self,
+        other: &GeometryCow<F>,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> IntersectionMatrix {
+        let mut relate_computer = relate_operation::RelateOperation::new(
+            GeometryGraph::new(0, self.clone(), boundary_node_rule),
+            GeometryGraph::new(1, other.clone(), boundary_node_rule),
+        );
         relate_computer.compute_intersection_matrix()
     }
+
+    fn relate_matches(
+        &self,
+        other: &GeometryCow<F>,
+        spec: &str,
+    ) -> Result<bool, InvalidInputError> {
+        let spec = dimension_matcher::parse_spec(spec)?;
+        let mut relate_computer = relate_operation::RelateOperation::new(
+            GeometryGraph::new(0, self.clone(), BoundaryNodeRule::Mod2),
+            GeometryGraph::new(1, other.clone(), BoundaryNodeRule::Mod2),
+        );
+        Ok(relate_computer.compute_matches(&spec))
+    }
 }
 
 macro_rules! relate_impl {
@@ -69,8 +146,42 @@ macro_rules! relate_impl {
     ($(($k:ty, $t:ty),)*) => {
         $(
             impl<F: GeoFloat> Relate<F, $t> for $k {
-                fn relate(&self, other: &$t) -> IntersectionMatrix {
-                    GeometryCow::from(self).relate(&GeometryCow::from(other))
+                fn relate_with(&self, other: &$t, boundary_node_rule: BoundaryNodeRule) -> IntersectionMatrix {
+                    GeometryCow::from(self).relate_with(&GeometryCow::from(other), boundary_node_rule)
+                }
+
+                fn relate_matches(&self, other: &$t, spec: &str) -> Result<bool, InvalidInputError> {
+                    GeometryCow::from(self).relate_matches(&GeometryCow::from(other), spec)
+                }
+            }
+
+            impl<F: GeoFloat> Touches<$t> for $k {
+                fn touches(&self, rhs: &$t) -> bool {
+                    self.relate(rhs).is_touches()
+                }
+            }
+
+            impl<F: GeoFloat> Crosses<$t> for $k {
+                fn crosses(&self, rhs: &$t) -> bool {
+                    self.relate(rhs).is_crosses()
+                }
+            }
+
+            impl<F: GeoFloat> Overlaps<$t> for $k {
+                fn overlaps(&self, rhs: &$t) -> bool {
+                    self.relate(rhs).is_overlaps()
+                }
+            }
+
+            impl<F: GeoFloat> Covers<$t> for $k {
+                fn covers(&self, rhs: &$t) -> bool {
+                    self.relate(rhs).is_covers()
+                }
+            }
+
+            impl<F: GeoFloat> EqualsTopo<$t> for $k {
+                fn equals_topo(&self, rhs: &$t) -> bool {
+                    self.relate(rhs).is_equal_topo()
                 }
             }
         )*
@@ -109,7 +220,7 @@ macro_rules! cartesian_pairs_helper {
     };
 }
 
-// Implement Relate for every combination of Geometry. Alternatively we could do something like
+// Implement Relate, and the named predicates derived from it, for every combination of Geometry. Alternatively we could do something like
 // `impl Relate<Into<GeometryCow>> for Into<GeometryCow> { }`
 // but I don't know that we want to make GeometryCow public (yet?).
 cartesian_pairs!(relate_impl, [Point<F>, Line<F>, LineString<F>, Polygon<F>, MultiPoint<F>, MultiLineString<F>, MultiPolygon<F>, Rect<F>, Triangle<F>, GeometryCollection<F>]);
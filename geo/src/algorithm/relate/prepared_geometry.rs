@@ -0,0 +1,548 @@
+use super::geomgraph::{GeometryGraph, RobustLineIntersector};
+use super::relate_operation::RelateOperation;
+use super::{dimension_matcher, BoundaryNodeRule, IntersectionMatrix, InvalidInputError, Relate};
+use crate::coordinate_position::CoordPos;
+use crate::geometry::*;
+use crate::{BoundingRect, Contains, CoordsIter, Covers, GeoFloat, GeometryCow, Intersects};
+
+use std::borrow::Cow;
+
+/// A geometry which has been prepared for many [`Relate`] queries against other geometries.
+///
+/// Relating two geometries builds a topology graph of each, finds the self-intersections of
+/// each graph, and indexes their segments. A `PreparedGeometry` does this work once for its
+/// geometry, and reuses the graph, its segment index and a point locator for every query.
+///
+/// It offers [`Relate`], [`Contains`], [`Intersects`] and [`Covers`] against every geometry type
+/// and other prepared geometries. Testing points against a polygon or multi-polygon only uses
+/// the segment index, so doesn't build a graph at all.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, point, polygon, Contains, Covers, Intersects, PreparedGeometry, Relate};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let prepared = PreparedGeometry::from(&polygon);
+///
+/// assert!(prepared.contains(&point!(x: 5., y: 5.)));
+/// assert!(!prepared.contains(&point!(x: 0., y: 5.)));
+/// assert!(prepared.covers(&point!(x: 0., y: 5.)));
+/// assert!(!prepared.intersects(&coord! { x: 11., y: 5. }));
+///
+/// let other = polygon![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)];
+/// assert_eq!(prepared.relate(&other), polygon.relate(&other));
+/// assert!(prepared.relate(&other).is_overlaps());
+/// ```
+pub struct PreparedGeometry<'a, F: GeoFloat = f64> {
+    geometry_graph: GeometryGraph<'a, F>,
+}
+
+impl<'a, F: GeoFloat> PreparedGeometry<'a, F> {
+    fn new(geometry: GeometryCow<'a, F>) -> Self {
+        let mut geometry_graph = GeometryGraph::new(0, geometry, BoundaryNodeRule::Mod2);
+        geometry_graph.build_tree();
+        geometry_graph.compute_self_nodes(Box::new(RobustLineIntersector::new()));
+        Self { geometry_graph }
+    }
+
+    fn bounding_rect(&self) -> Option<Rect<F>> {
+        self.geometry_graph.bounding_rect()
+    }
+
+    /// The graph of this geometry for use at `arg_index` in a relate operation. The graph is only
+    /// built afresh if the rule differs from the prepared graph's.
+    fn graph(
+        &self,
+        arg_index: usize,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> GeometryGraph<'_, F> {
+        if boundary_node_rule == self.geometry_graph.boundary_node_rule() {
+            self.geometry_graph.clone_for_arg_index(arg_index)
+        } else {
+            GeometryGraph::new(
+                arg_index,
+                self.geometry_graph.geometry().clone(),
+                boundary_node_rule,
+            )
+        }
+    }
+
+    /// Relate this geometry, as the first argument, to the geometry of `graph`.
+    fn relate_graph(&self, graph: GeometryGraph<F>) -> IntersectionMatrix {
+        RelateOperation::new(self.graph(0, graph.boundary_node_rule()), graph)
+            .compute_intersection_matrix()
+    }
+
+    /// Whether the bounding rectangles of the two geometries intersect.
+    fn may_intersect(&self, other: Option<Rect<F>>) -> bool {
+        match (self.bounding_rect(), other) {
+            (Some(rect), Some(other)) => rect.intersects(&other),
+            _ => false,
+        }
+    }
+
+    /// Whether this geometry's bounding rectangle covers the other's.
+    fn may_cover(&self, other: Option<Rect<F>>) -> bool {
+        match (self.bounding_rect(), other) {
+            (Some(rect), Some(other)) => {
+                rect.min().x <= other.min().x
+                    && rect.min().y <= other.min().y
+                    && other.max().x <= rect.max().x
+                    && other.max().y <= rect.max().y
+            }
+            _ => false,
+        }
+    }
+
+    /// The positions of `coords` relative to this geometry, if they can be found without
+    /// relating them.
+    ///
+    /// Points in a collection might lie in several of its members, so need the topology graph.
+    fn locate<'b>(
+        &'b self,
+        coords: impl IntoIterator<Item = Coord<F>> + 'b,
+    ) -> Option<impl Iterator<Item = CoordPos> + 'b> {
+        if matches!(
+            self.geometry_graph.geometry(),
+            GeometryCow::GeometryCollection(_)
+        ) {
+            return None;
+        }
+        Some(
+            coords
+                .into_iter()
+                .map(|coord| self.geometry_graph.locate(&coord)),
+        )
+    }
+}
+
+/// Whether the relation of the geometries of the graphs matches the de-9im `spec`.
+fn graphs_match<F: GeoFloat>(
+    graph_a: GeometryGraph<F>,
+    graph_b: GeometryGraph<F>,
+    spec: &str,
+) -> Result<bool, InvalidInputError> {
+    let spec = dimension_matcher::parse_spec(spec)?;
+    Ok(RelateOperation::new(graph_a, graph_b).compute_matches(&spec))
+}
+
+impl<F: GeoFloat> Relate<F, PreparedGeometry<'_, F>> for PreparedGeometry<'_, F> {
+    fn relate_with(
+        &self,
+        other: &PreparedGeometry<F>,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> IntersectionMatrix {
+        self.relate_graph(other.graph(1, boundary_node_rule))
+    }
+
+    fn relate_matches(
+        &self,
+        other: &PreparedGeometry<F>,
+        spec: &str,
+    ) -> Result<bool, InvalidInputError> {
+        graphs_match(
+            self.graph(0, BoundaryNodeRule::Mod2),
+            other.graph(1, BoundaryNodeRule::Mod2),
+            spec,
+        )
+    }
+}
+
+impl<F: GeoFloat> Contains<PreparedGeometry<'_, F>> for PreparedGeometry<'_, F> {
+    fn contains(&self, other: &PreparedGeometry<F>) -> bool {
+        self.may_cover(other.bounding_rect()) && self.relate(other).is_contains()
+    }
+}
+
+impl<F: GeoFloat> Covers<PreparedGeometry<'_, F>> for PreparedGeometry<'_, F> {
+    fn covers(&self, other: &PreparedGeometry<F>) -> bool {
+        self.may_cover(other.bounding_rect()) && self.relate(other).is_covers()
+    }
+}
+
+impl<F: GeoFloat> Intersects<PreparedGeometry<'_, F>> for PreparedGeometry<'_, F> {
+    fn intersects(&self, other: &PreparedGeometry<F>) -> bool {
+        self.may_intersect(other.bounding_rect()) && self.relate(other).is_intersects()
+    }
+}
+
+impl<F: GeoFloat> Contains<Coord<F>> for PreparedGeometry<'_, F> {
+    fn contains(&self, coord: &Coord<F>) -> bool {
+        self.contains(&Point::from(*coord))
+    }
+}
+
+impl<F: GeoFloat> Covers<Coord<F>> for PreparedGeometry<'_, F> {
+    fn covers(&self, coord: &Coord<F>) -> bool {
+        self.covers(&Point::from(*coord))
+    }
+}
+
+impl<F: GeoFloat> Intersects<Coord<F>> for PreparedGeometry<'_, F> {
+    fn intersects(&self, coord: &Coord<F>) -> bool {
+        self.intersects(&Point::from(*coord))
+    }
+}
+
+/// Implement the predicates for points from their positions, falling back to relating them.
+macro_rules! impl_prepared_points {
+    ($type:ident) => {
+        impl<F: GeoFloat> Contains<$type<F>> for PreparedGeometry<'_, F> {
+            fn contains(&self, points: &$type<F>) -> bool {
+                if !self.may_cover(points.bounding_rect().into()) {
+                    return false;
+                }
+                match self.locate(points.coords_iter()) {
+                    Some(mut positions) => {
+                        let mut has_inside = false;
+                        positions.all(|position| {
+                            has_inside |= position == CoordPos::Inside;
+                            position != CoordPos::Outside
+                        }) && has_inside
+                    }
+                    None => self.relate(points).is_contains(),
+                }
+            }
+        }
+
+        impl<F: GeoFloat> Covers<$type<F>> for PreparedGeometry<'_, F> {
+            fn covers(&self, points: &$type<F>) -> bool {
+                if !self.may_cover(points.bounding_rect().into()) {
+                    return false;
+                }
+                match self.locate(points.coords_iter()) {
+                    Some(mut positions) => positions.all(|position| position != CoordPos::Outside),
+                    None => self.relate(points).is_covers(),
+                }
+            }
+        }
+
+        impl<F: GeoFloat> Intersects<$type<F>> for PreparedGeometry<'_, F> {
+            fn intersects(&self, points: &$type<F>) -> bool {
+                if !self.may_intersect(points.bounding_rect().into()) {
+                    return false;
+                }
+                match self.locate(points.coords_iter()) {
+                    Some(mut positions) => positions.any(|position| position != CoordPos::Outside),
+                    None => self.relate(points).is_intersects(),
+                }
+            }
+        }
+    };
+}
+
+/// Implement the predicates by relating the geometries, once their bounding rectangles show
+/// they might hold.
+macro_rules! impl_prepared_relate {
+    ($type:ident) => {
+        impl<F: GeoFloat> Contains<$type<F>> for PreparedGeometry<'_, F> {
+            fn contains(&self, other: &$type<F>) -> bool {
+                self.may_cover(other.bounding_rect().into()) && self.relate(other).is_contains()
+            }
+        }
+
+        impl<F: GeoFloat> Covers<$type<F>> for PreparedGeometry<'_, F> {
+            fn covers(&self, other: &$type<F>) -> bool {
+                self.may_cover(other.bounding_rect().into()) && self.relate(other).is_covers()
+            }
+        }
+
+        impl<F: GeoFloat> Intersects<$type<F>> for PreparedGeometry<'_, F> {
+            fn intersects(&self, other: &$type<F>) -> bool {
+                self.may_intersect(other.bounding_rect().into())
+                    && self.relate(other).is_intersects()
+            }
+        }
+    };
+}
+
+/// Implement `Relate` both ways between a geometry type and a prepared geometry, and the
+/// conversions from the geometry type.
+macro_rules! impl_prepared {
+    ($type:ident) => {
+        impl<F: GeoFloat> Relate<F, $type<F>> for PreparedGeometry<'_, F> {
+            fn relate_with(
+                &self,
+                other: &$type<F>,
+                boundary_node_rule: BoundaryNodeRule,
+            ) -> IntersectionMatrix {
+                self.relate_graph(GeometryGraph::new(
+                    1,
+                    GeometryCow::from(other),
+                    boundary_node_rule,
+                ))
+            }
+
+            fn relate_matches(
+                &self,
+                other: &$type<F>,
+                spec: &str,
+            ) -> Result<bool, InvalidInputError> {
+                graphs_match(
+                    self.graph(0, BoundaryNodeRule::Mod2),
+                    GeometryGraph::new(1, GeometryCow::from(other), BoundaryNodeRule::Mod2),
+                    spec,
+                )
+            }
+        }
+
+        impl<F: GeoFloat> Relate<F, PreparedGeometry<'_, F>> for $type<F> {
+            fn relate_with(
+                &self,
+                other: &PreparedGeometry<F>,
+                boundary_node_rule: BoundaryNodeRule,
+            ) -> IntersectionMatrix {
+                RelateOperation::new(
+                    GeometryGraph::new(0, GeometryCow::from(self), boundary_node_rule),
+                    other.graph(1, boundary_node_rule),
+                )
+                .compute_intersection_matrix()
+            }
+
+            fn relate_matches(
+                &self,
+                other: &PreparedGeometry<F>,
+                spec: &str,
+            ) -> Result<bool, InvalidInputError> {
+                graphs_match(
+                    GeometryGraph::new(0, GeometryCow::from(self), BoundaryNodeRule::Mod2),
+                    other.graph(1, BoundaryNodeRule::Mod2),
+                    spec,
+                )
+            }
+        }
+
+        impl<'a, F: GeoFloat> From<&'a $type<F>> for PreparedGeometry<'a, F> {
+            fn from(geometry: &'a $type<F>) -> Self {
+                PreparedGeometry::new(GeometryCow::from(geometry))
+            }
+        }
+
+        impl<F: GeoFloat> From<$type<F>> for PreparedGeometry<'static, F> {
+            fn from(geometry: $type<F>) -> Self {
+                PreparedGeometry::new(GeometryCow::$type(Cow::Owned(geometry)))
+            }
+        }
+    };
+}
+
+impl_prepared_points!(Point);
+impl_prepared_points!(MultiPoint);
+impl_prepared_relate!(Line);
+impl_prepared_relate!(LineString);
+impl_prepared_relate!(Polygon);
+impl_prepared_relate!(MultiLineString);
+impl_prepared_relate!(MultiPolygon);
+impl_prepared_relate!(Rect);
+impl_prepared_relate!(Triangle);
+impl_prepared_relate!(GeometryCollection);
+impl_prepared_relate!(Geometry);
+
+impl_prepared!(Point);
+impl_prepared!(Line);
+impl_prepared!(LineString);
+impl_prepared!(Polygon);
+impl_prepared!(MultiPoint);
+impl_prepared!(MultiLineString);
+impl_prepared!(MultiPolygon);
+impl_prepared!(Rect);
+impl_prepared!(Triangle);
+impl_prepared!(GeometryCollection);
+
+impl<F: GeoFloat> Relate<F, Geometry<F>> for PreparedGeometry<'_, F> {
+    fn relate_with(
+        &self,
+        other: &Geometry<F>,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> IntersectionMatrix {
+        self.relate_graph(GeometryGraph::new(
+            1,
+            GeometryCow::from(other),
+            boundary_node_rule,
+        ))
+    }
+
+    fn relate_matches(&self, other: &Geometry<F>, spec: &str) -> Result<bool, InvalidInputError> {
+        graphs_match(
+            self.graph(0, BoundaryNodeRule::Mod2),
+            GeometryGraph::new(1, GeometryCow::from(other), BoundaryNodeRule::Mod2),
+            spec,
+        )
+    }
+}
+
+impl<F: GeoFloat> Relate<F, PreparedGeometry<'_, F>> for Geometry<F> {
+    fn relate_with(
+        &self,
+        other: &PreparedGeometry<F>,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> IntersectionMatrix {
+        RelateOperation::new(
+            GeometryGraph::new(0, GeometryCow::from(self), boundary_node_rule),
+            other.graph(1, boundary_node_rule),
+        )
+        .compute_intersection_matrix()
+    }
+
+    fn relate_matches(
+        &self,
+        other: &PreparedGeometry<F>,
+        spec: &str,
+    ) -> Result<bool, InvalidInputError> {
+        graphs_match(
+            GeometryGraph::new(0, GeometryCow::from(self), BoundaryNodeRule::Mod2),
+            other.graph(1, BoundaryNodeRule::Mod2),
+            spec,
+        )
+    }
+}
+
+impl<'a, F: GeoFloat> From<&'a Geometry<F>> for PreparedGeometry<'a, F> {
+    fn from(geometry: &'a Geometry<F>) -> Self {
+        PreparedGeometry::new(GeometryCow::from(geometry))
+    }
+}
+
+impl<F: GeoFloat> From<Geometry<F>> for PreparedGeometry<'static, F> {
+    fn from(geometry: Geometry<F>) -> Self {
+        let geometry = match geometry {
+            Geometry::Point(g) => GeometryCow::Point(Cow::Owned(g)),
+            Geometry::Line(g) => GeometryCow::Line(Cow::Owned(g)),
+            Geometry::LineString(g) => GeometryCow::LineString(Cow::Owned(g)),
+            Geometry::Polygon(g) => GeometryCow::Polygon(Cow::Owned(g)),
+            Geometry::MultiPoint(g) => GeometryCow::MultiPoint(Cow::Owned(g)),
+            Geometry::MultiLineString(g) => GeometryCow::MultiLineString(Cow::Owned(g)),
+            Geometry::MultiPolygon(g) => GeometryCow::MultiPolygon(Cow::Owned(g)),
+            Geometry::GeometryCollection(g) => GeometryCow::GeometryCollection(Cow::Owned(g)),
+            Geometry::Rect(g) => GeometryCow::Rect(Cow::Owned(g)),
+            Geometry::Triangle(g) => GeometryCow::Triangle(Cow::Owned(g)),
+        };
+        PreparedGeometry::new(geometry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, point, polygon, CoveredBy, Within};
+
+    fn square_with_hole() -> Polygon {
+        polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        )
+    }
+
+    #[test]
+    fn relate_matches_unprepared() {
+        let polygon = Geometry::from(square_with_hole());
+        let others: Vec<Geometry> = vec![
+            point!(x: 5., y: 5.).into(),
+            point!(x: 2., y: 2.).into(),
+            line_string![(x: -1., y: 5.), (x: 11., y: 5.)].into(),
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)].into(),
+            polygon![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)].into(),
+            polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)].into(),
+            polygon![(x: 20., y: 20.), (x: 30., y: 20.), (x: 30., y: 30.)].into(),
+            polygon.clone(),
+        ];
+        let prepared = PreparedGeometry::from(&polygon);
+        // query twice, to check the prepared graph isn't changed by relating it
+        for _ in 0..2 {
+            for other in &others {
+                assert_eq!(prepared.relate(other), polygon.relate(other));
+                assert_eq!(other.relate(&prepared), other.relate(&polygon));
+
+                let prepared_other = PreparedGeometry::from(other);
+                assert_eq!(prepared.relate(&prepared_other), polygon.relate(other));
+                assert_eq!(
+                    prepared.contains(&prepared_other),
+                    polygon.relate(other).is_contains()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn points_in_polygon_with_hole() {
+        let prepared = PreparedGeometry::from(square_with_hole());
+        let inside = point!(x: 2., y: 5.);
+        let in_hole = point!(x: 5., y: 5.);
+        let on_hole = point!(x: 4., y: 5.);
+        let vertex = point!(x: 10., y: 10.);
+        let outside = point!(x: 11., y: 5.);
+
+        assert!(prepared.contains(&inside));
+        assert!(!prepared.intersects(&in_hole));
+        assert!(!prepared.contains(&on_hole));
+        assert!(prepared.covers(&on_hole));
+        assert!(prepared.covers(&vertex));
+        assert!(!prepared.intersects(&outside));
+        assert!(inside.is_within(&prepared));
+        assert!(vertex.is_covered_by(&prepared));
+
+        let points = MultiPoint::new(vec![inside, on_hole]);
+        assert!(prepared.contains(&points));
+        let points = MultiPoint::new(vec![on_hole, vertex]);
+        assert!(!prepared.contains(&points));
+        assert!(prepared.covers(&points));
+        let points = MultiPoint::new(vec![in_hole, outside]);
+        assert!(!prepared.intersects(&points));
+        assert!(!prepared.covers(&MultiPoint::<f64>::new(vec![])));
+    }
+
+    #[test]
+    fn points_on_ray_through_vertices() {
+        // rays to the right of these points pass through the vertices of the zigzag
+        let polygon = polygon![
+            (x: 0., y: 0.), (x: 4., y: 0.), (x: 6., y: 2.), (x: 4., y: 4.),
+            (x: 6., y: 6.), (x: 0., y: 6.),
+        ];
+        let prepared = PreparedGeometry::from(&polygon);
+        for y in 0..=6 {
+            for x in -1..=7 {
+                let point = point!(x: x as f64, y: y as f64);
+                assert_eq!(
+                    prepared.covers(&point),
+                    polygon.relate(&point).is_covers(),
+                    "{point:?}"
+                );
+                assert_eq!(prepared.contains(&point), polygon.contains(&point));
+            }
+        }
+    }
+
+    #[test]
+    fn line_string() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let prepared = PreparedGeometry::from(&line_string);
+        assert!(prepared.contains(&point!(x: 5., y: 0.)));
+        assert!(!prepared.contains(&point!(x: 0., y: 0.)));
+        assert!(prepared.covers(&point!(x: 0., y: 0.)));
+        assert!(prepared.covers(&line_string![(x: 5., y: 0.), (x: 10., y: 0.), (x: 10., y: 5.)]));
+        assert!(prepared.intersects(&line_string![(x: 5., y: -5.), (x: 5., y: 5.)]));
+        assert!(!prepared.intersects(&line_string![(x: 5., y: 1.), (x: 9., y: 5.)]));
+    }
+
+    #[test]
+    fn relate_with_boundary_node_rule() {
+        let ring = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 0.)];
+        let start = point!(x: 0., y: 0.);
+        let prepared = PreparedGeometry::from(&ring);
+        for boundary_node_rule in [BoundaryNodeRule::Mod2, BoundaryNodeRule::EndPoint] {
+            assert_eq!(
+                prepared.relate_with(&start, boundary_node_rule),
+                ring.relate_with(&start, boundary_node_rule)
+            );
+            assert_eq!(
+                start.relate_with(&prepared, boundary_node_rule),
+                start.relate_with(&ring, boundary_node_rule)
+            );
+        }
+        assert!(prepared.relate(&start).is_contains());
+        assert!(prepared
+            .relate_with(&start, BoundaryNodeRule::EndPoint)
+            .is_touches());
+    }
+}
@@ -1,4 +1,5 @@
-use super::{EdgeEndBuilder, IntersectionMatrix};
+use super::geomgraph::intersection_matrix::dimension_matcher::DimensionMatcher;
+use super::{BoundaryNodeRule, EdgeEndBuilder, IntersectionMatrix};
 use crate::dimensions::{Dimensions, HasDimensions};
 use crate::relate::geomgraph::{
     index::SegmentIntersector,
@@ -6,8 +7,7 @@ use crate::relate::geomgraph::{
     CoordNode, CoordPos, Edge, EdgeEnd, EdgeEndBundleStar, GeometryGraph, LabeledEdgeEndBundleStar,
     RobustLineIntersector,
 };
-use crate::CoordinatePosition;
-use crate::{Coord, GeoFloat, GeometryCow};
+use crate::{Coord, GeoFloat};
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -48,12 +48,14 @@ where
     F: GeoFloat,
 {
     pub(crate) fn new(
-        geom_a: &'a GeometryCow<'a, F>,
-        geom_b: &'a GeometryCow<'a, F>,
+        graph_a: GeometryGraph<'a, F>,
+        graph_b: GeometryGraph<'a, F>,
     ) -> RelateOperation<'a, F> {
+        debug_assert_eq!(graph_a.arg_index(), 0);
+        debug_assert_eq!(graph_b.arg_index(), 1);
         Self {
-            graph_a: GeometryGraph::new(0, geom_a),
-            graph_b: GeometryGraph::new(1, geom_b),
+            graph_a,
+            graph_b,
             nodes: NodeMap::new(),
             isolated_edges: vec![],
             line_intersector: RobustLineIntersector::new(),
@@ -61,6 +63,92 @@ where
     }
 
     pub(crate) fn compute_intersection_matrix(&mut self) -> IntersectionMatrix {
+        let mut intersection_matrix = Self::exterior_intersection_matrix();
+
+        if !self.bounding_rects_intersect() {
+            // since Geometries don't overlap, we can skip most of the work
+            self.compute_disjoint_intersection_matrix(&mut intersection_matrix);
+            return intersection_matrix;
+        }
+
+        self.compute_self_nodes();
+
+        // compute intersections between edges of the two input geometries
+        let segment_intersector = self.graph_a.compute_edge_intersections(
+            &self.graph_b,
+            Box::new(self.line_intersector.clone()),
+            false,
+        );
+
+        self.complete_intersection_matrix(&segment_intersector, &mut intersection_matrix);
+        intersection_matrix
+    }
+
+    /// Whether the [`IntersectionMatrix`] matches the parsed de-9im `spec`, stopping as soon as
+    /// the answer is known.
+    ///
+    /// The answer may be known from the dimensions of the geometries when their bounding
+    /// rectangles are disjoint, or from the first proper crossing of their edges.
+    pub(crate) fn compute_matches(&mut self, spec: &[DimensionMatcher; 9]) -> bool {
+        let mut intersection_matrix = Self::exterior_intersection_matrix();
+        if let Some(matches) = intersection_matrix.lower_bound_matches(spec) {
+            return matches;
+        }
+
+        if !self.bounding_rects_intersect() {
+            self.compute_disjoint_intersection_matrix(&mut intersection_matrix);
+            return intersection_matrix.matches_spec(spec);
+        }
+
+        // Points have no edges to cross.
+        let can_cross = self.graph_a.geometry().dimensions() >= Dimensions::OneDimensional
+            && self.graph_b.geometry().dimensions() >= Dimensions::OneDimensional;
+
+        // If any proper crossing of the geometries' edges would decide the answer, look for one
+        // before the more expensive self-noding.
+        if can_cross {
+            let mut crossed_intersection_matrix = intersection_matrix.clone();
+            self.compute_proper_intersection_im(true, false, &mut crossed_intersection_matrix);
+            if let Some(matches) = crossed_intersection_matrix.lower_bound_matches(spec) {
+                // cache the trees, to be reused if there's no crossing
+                self.graph_a.build_tree();
+                self.graph_b.build_tree();
+                if self
+                    .graph_a
+                    .has_proper_crossing(&self.graph_b, Box::new(self.line_intersector.clone()))
+                {
+                    return matches;
+                }
+            }
+        }
+
+        self.compute_self_nodes();
+
+        // If a proper crossing at a point in the interior of both geometries would decide the
+        // answer, stop at the first one.
+        let crossing_matches = if can_cross {
+            let mut crossed_intersection_matrix = intersection_matrix.clone();
+            self.compute_proper_intersection_im(true, true, &mut crossed_intersection_matrix);
+            crossed_intersection_matrix.lower_bound_matches(spec)
+        } else {
+            None
+        };
+
+        let segment_intersector = self.graph_a.compute_edge_intersections(
+            &self.graph_b,
+            Box::new(self.line_intersector.clone()),
+            crossing_matches.is_some(),
+        );
+        if segment_intersector.is_done() {
+            return crossing_matches.expect("only done early when a crossing decides the match");
+        }
+
+        self.complete_intersection_matrix(&segment_intersector, &mut intersection_matrix);
+        intersection_matrix.matches_spec(spec)
+    }
+
+    /// The matrix of geometries which haven't been compared yet.
+    fn exterior_intersection_matrix() -> IntersectionMatrix {
         let mut intersection_matrix = IntersectionMatrix::empty();
         // since Geometries are finite and embedded in a 2-D space,
         // the `(Outside, Outside)` element must always be 2-D
@@ -69,34 +157,35 @@ where
             CoordPos::Outside,
             Dimensions::TwoDimensional,
         );
+        intersection_matrix
+    }
 
-        use crate::BoundingRect;
+    fn bounding_rects_intersect(&self) -> bool {
         use crate::Intersects;
-        match (
-            self.graph_a.geometry().bounding_rect(),
-            self.graph_b.geometry().bounding_rect(),
-        ) {
-            (Some(bounding_rect_a), Some(bounding_rect_b))
-                if bounding_rect_a.intersects(&bounding_rect_b) => {}
-            _ => {
-                // since Geometries don't overlap, we can skip most of the work
-                self.compute_disjoint_intersection_matrix(&mut intersection_matrix);
-                return intersection_matrix;
+        match (self.graph_a.bounding_rect(), self.graph_b.bounding_rect()) {
+            (Some(bounding_rect_a), Some(bounding_rect_b)) => {
+                bounding_rect_a.intersects(&bounding_rect_b)
             }
+            _ => false,
         }
+    }
 
-        // Since changes to topology are inspected at nodes, we must crate a node for each
-        // intersection.
+    /// Since changes to topology are inspected at nodes, we must crate a node for each
+    /// intersection.
+    fn compute_self_nodes(&mut self) {
         self.graph_a
             .compute_self_nodes(Box::new(self.line_intersector.clone()));
         self.graph_b
             .compute_self_nodes(Box::new(self.line_intersector.clone()));
+    }
 
-        // compute intersections between edges of the two input geometries
-        let segment_intersector = self
-            .graph_a
-            .compute_edge_intersections(&self.graph_b, Box::new(self.line_intersector.clone()));
-
+    /// Compute the rest of the matrix from the intersections between the edges of the two
+    /// geometries.
+    fn complete_intersection_matrix(
+        &mut self,
+        segment_intersector: &SegmentIntersector<F>,
+        intersection_matrix: &mut IntersectionMatrix,
+    ) {
         self.compute_intersection_nodes(0);
         self.compute_intersection_nodes(1);
         // Copy the labelling for the nodes in the parent Geometries.  These override any labels
@@ -106,7 +195,11 @@ where
         // complete the labelling for any nodes which only have a label for a single geometry
         self.label_isolated_nodes();
         // If a proper intersection was found, we can set a lower bound on the IM.
-        self.compute_proper_intersection_im(&segment_intersector, &mut intersection_matrix);
+        self.compute_proper_intersection_im(
+            segment_intersector.has_proper_intersection(),
+            segment_intersector.has_proper_interior_intersection(),
+            intersection_matrix,
+        );
         // Now process improper intersections
         // (eg where one or other of the geometries has a vertex at the intersection point)
         // We need to compute the edge graph at all nodes to determine the IM.
@@ -138,11 +231,9 @@ where
 
         debug!(
             "before update_intersection_matrix: {:?}",
-            &intersection_matrix
+            intersection_matrix
         );
-        self.update_intersection_matrix(labeled_node_edges, &mut intersection_matrix);
-
-        intersection_matrix
+        self.update_intersection_matrix(labeled_node_edges, intersection_matrix);
     }
 
     fn insert_edge_ends(&mut self, edge_ends: Vec<EdgeEnd<F>>) {
@@ -155,17 +246,15 @@ where
     }
 
     fn compute_proper_intersection_im(
-        &mut self,
-        segment_intersector: &SegmentIntersector<F>,
+        &self,
+        has_proper: bool,
+        has_proper_interior: bool,
         intersection_matrix: &mut IntersectionMatrix,
     ) {
         // If a proper intersection is found, we can set a lower bound on the IM.
         let dim_a = self.graph_a.geometry().dimensions();
         let dim_b = self.graph_b.geometry().dimensions();
 
-        let has_proper = segment_intersector.has_proper_intersection();
-        let has_proper_interior = segment_intersector.has_proper_interior_intersection();
-
         debug_assert!(
             (dim_a != Dimensions::ZeroDimensional && dim_b != Dimensions::ZeroDimensional)
                 || (!has_proper && !has_proper_interior)
@@ -302,7 +391,7 @@ where
             if dimensions != Dimensions::Empty {
                 intersection_matrix.set(CoordPos::Inside, CoordPos::Outside, dimensions);
 
-                let boundary_dimensions = geometry_a.boundary_dimensions();
+                let boundary_dimensions = Self::boundary_dimensions(&self.graph_a);
                 if boundary_dimensions != Dimensions::Empty {
                     intersection_matrix.set(
                         CoordPos::OnBoundary,
@@ -319,7 +408,7 @@ where
             if dimensions != Dimensions::Empty {
                 intersection_matrix.set(CoordPos::Outside, CoordPos::Inside, dimensions);
 
-                let boundary_dimensions = geometry_b.boundary_dimensions();
+                let boundary_dimensions = Self::boundary_dimensions(&self.graph_b);
                 if boundary_dimensions != Dimensions::Empty {
                     intersection_matrix.set(
                         CoordPos::Outside,
@@ -331,6 +420,22 @@ where
         }
     }
 
+    /// The dimensions of the boundary of a graph's geometry under its [`BoundaryNodeRule`].
+    fn boundary_dimensions(graph: &GeometryGraph<F>) -> Dimensions {
+        let geometry = graph.geometry();
+        match geometry.dimensions() {
+            // Under other rules, lines have a boundary wherever the rule puts their endpoints
+            Dimensions::OneDimensional if graph.boundary_node_rule() != BoundaryNodeRule::Mod2 => {
+                if graph.boundary_nodes().next().is_some() {
+                    Dimensions::ZeroDimensional
+                } else {
+                    Dimensions::Empty
+                }
+            }
+            _ => geometry.boundary_dimensions(),
+        }
+    }
+
     fn update_intersection_matrix(
         &self,
         labeled_node_edges: Vec<(CoordNode<F>, LabeledEdgeEndBundleStar<F>)>,
@@ -373,7 +478,7 @@ where
         for edge in this_graph.edges() {
             let mut mut_edge = edge.borrow_mut();
             if mut_edge.is_isolated() {
-                Self::label_isolated_edge(&mut mut_edge, target_index, target_graph.geometry());
+                Self::label_isolated_edge(&mut mut_edge, target_index, target_graph);
                 self.isolated_edges.push(edge.clone());
             }
         }
@@ -382,13 +487,13 @@ where
     /// Label an isolated edge of a graph with its relationship to the target geometry.
     /// If the target has dim 2 or 1, the edge can either be in the interior or the exterior.
     /// If the target has dim 0, the edge must be in the exterior
-    fn label_isolated_edge(edge: &mut Edge<F>, target_index: usize, target: &GeometryCow<F>) {
-        if target.dimensions() > Dimensions::ZeroDimensional {
+    fn label_isolated_edge(edge: &mut Edge<F>, target_index: usize, target: &GeometryGraph<F>) {
+        if target.geometry().dimensions() > Dimensions::ZeroDimensional {
             // An isolated edge doesn't cross any boundary, so it's either wholly inside, or wholly
             // outside of the geometry. As such, we can use any point from the edge to infer the
             // position of the edge as a whole.
             let coord = edge.coords().first().expect("can't create empty edge");
-            let position = target.coordinate_position(coord);
+            let position = target.locate(coord);
             edge.label_mut().set_all_positions(target_index, position);
         } else {
             edge.label_mut()
@@ -403,28 +508,24 @@ where
     /// complete the labelling we need to check for nodes that lie in the interior of edges, and in
     /// the interior of areas.
     fn label_isolated_nodes(&mut self) {
-        let geometry_a = self.graph_a.geometry();
-        let geometry_b = self.graph_b.geometry();
+        let graph_a = &self.graph_a;
+        let graph_b = &self.graph_b;
         for (node, _edges) in self.nodes.iter_mut() {
             let label = node.label();
             // isolated nodes should always have at least one geometry in their label
             debug_assert!(label.geometry_count() > 0, "node with empty label found");
             if node.is_isolated() {
                 if label.is_empty(0) {
-                    Self::label_isolated_node(node, 0, geometry_a)
+                    Self::label_isolated_node(node, 0, graph_a)
                 } else {
-                    Self::label_isolated_node(node, 1, geometry_b)
+                    Self::label_isolated_node(node, 1, graph_b)
                 }
             }
         }
     }
 
-    fn label_isolated_node(
-        node: &mut CoordNode<F>,
-        target_index: usize,
-        geometry: &GeometryCow<F>,
-    ) {
-        let position = geometry.coordinate_position(node.coordinate());
+    fn label_isolated_node(node: &mut CoordNode<F>, target_index: usize, graph: &GeometryGraph<F>) {
+        let position = graph.locate(node.coordinate());
         node.label_mut().set_all_positions(target_index, position);
     }
 }
@@ -432,7 +533,9 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use geo_types::{polygon, Geometry};
+    use crate::relate::geomgraph::intersection_matrix::dimension_matcher::parse_spec;
+    use crate::GeometryCow;
+    use geo_types::{line_string, point, polygon, Geometry, MultiLineString};
     use std::str::FromStr;
 
     #[test]
@@ -455,9 +558,9 @@ mod test {
         ]
         .into();
 
-        let gc1 = GeometryCow::from(&square_a);
-        let gc2 = GeometryCow::from(&square_b);
-        let mut relate_computer = RelateOperation::new(&gc1, &gc2);
+        let graph_a = GeometryGraph::new(0, GeometryCow::from(&square_a), BoundaryNodeRule::Mod2);
+        let graph_b = GeometryGraph::new(1, GeometryCow::from(&square_b), BoundaryNodeRule::Mod2);
+        let mut relate_computer = RelateOperation::new(graph_a, graph_b);
         let intersection_matrix = relate_computer.compute_intersection_matrix();
         assert_eq!(
             intersection_matrix,
@@ -485,9 +588,9 @@ mod test {
         ]
         .into();
 
-        let gca = GeometryCow::from(&square_a);
-        let gcb = GeometryCow::from(&square_b);
-        let mut relate_computer = RelateOperation::new(&gca, &gcb);
+        let graph_a = GeometryGraph::new(0, GeometryCow::from(&square_a), BoundaryNodeRule::Mod2);
+        let graph_b = GeometryGraph::new(1, GeometryCow::from(&square_b), BoundaryNodeRule::Mod2);
+        let mut relate_computer = RelateOperation::new(graph_a, graph_b);
         let intersection_matrix = relate_computer.compute_intersection_matrix();
         assert_eq!(
             intersection_matrix,
@@ -515,13 +618,104 @@ mod test {
         ]
         .into();
 
-        let gca = &GeometryCow::from(&square_a);
-        let gcb = &GeometryCow::from(&square_b);
-        let mut relate_computer = RelateOperation::new(gca, gcb);
+        let graph_a = GeometryGraph::new(0, GeometryCow::from(&square_a), BoundaryNodeRule::Mod2);
+        let graph_b = GeometryGraph::new(1, GeometryCow::from(&square_b), BoundaryNodeRule::Mod2);
+        let mut relate_computer = RelateOperation::new(graph_a, graph_b);
         let intersection_matrix = relate_computer.compute_intersection_matrix();
         assert_eq!(
             intersection_matrix,
             IntersectionMatrix::from_str("212101212").unwrap()
         );
     }
+
+    #[test]
+    fn test_boundary_node_rules() {
+        let relate = |a: &Geometry, b: &Geometry, boundary_node_rule| {
+            RelateOperation::new(
+                GeometryGraph::new(0, GeometryCow::from(a), boundary_node_rule),
+                GeometryGraph::new(1, GeometryCow::from(b), boundary_node_rule),
+            )
+            .compute_intersection_matrix()
+        };
+
+        // three lines meeting at the origin
+        let star: Geometry = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 0., y: 1.)],
+            line_string![(x: 0., y: 0.), (x: -1., y: 0.)],
+        ])
+        .into();
+        let center: Geometry = point!(x: 0., y: 0.).into();
+        let far: Geometry = point!(x: 5., y: 5.).into();
+
+        for (boundary_node_rule, expected) in [
+            (BoundaryNodeRule::Mod2, "FF10F0FF2"),
+            (BoundaryNodeRule::EndPoint, "FF10F0FF2"),
+            (BoundaryNodeRule::MultivalentEndPoint, "FF10FFFF2"),
+            (BoundaryNodeRule::MonovalentEndPoint, "0F1FF0FF2"),
+        ] {
+            assert_eq!(
+                relate(&star, &center, boundary_node_rule),
+                IntersectionMatrix::from_str(expected).unwrap(),
+                "{boundary_node_rule:?}"
+            );
+        }
+
+        // a closed line only has a boundary under the end point rules
+        let ring: Geometry =
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)].into();
+        assert_eq!(
+            relate(&ring, &far, BoundaryNodeRule::Mod2),
+            IntersectionMatrix::from_str("FF1FFF0F2").unwrap()
+        );
+        assert_eq!(
+            relate(&ring, &far, BoundaryNodeRule::EndPoint),
+            IntersectionMatrix::from_str("FF1FF00F2").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_matches_agrees_with_intersection_matrix() {
+        let geometries: Vec<Geometry> = vec![
+            polygon![(x: 0., y: 0.), (x: 20., y: 0.), (x: 20., y: 20.), (x: 0., y: 20.)].into(),
+            polygon![(x: 5., y: 5.), (x: 30., y: 5.), (x: 30., y: 30.), (x: 5., y: 30.)].into(),
+            polygon![(x: 5., y: 5.), (x: 10., y: 5.), (x: 10., y: 10.), (x: 5., y: 10.)].into(),
+            polygon![(x: 50., y: 50.), (x: 60., y: 50.), (x: 60., y: 60.)].into(),
+            line_string![(x: -5., y: 10.), (x: 25., y: 10.)].into(),
+            line_string![(x: 10., y: -5.), (x: 10., y: 25.)].into(),
+            line_string![(x: 0., y: 0.), (x: 20., y: 0.)].into(),
+            point!(x: 10., y: 10.).into(),
+            point!(x: 0., y: 10.).into(),
+        ];
+        let specs = [
+            "T********",
+            "FF*FF****",
+            "T*F**F***",
+            "T*****FF*",
+            "F***T****",
+            "T*T******",
+            "0********",
+            "212101212",
+            "T*T***T**",
+            "********F",
+        ];
+        for a in &geometries {
+            for b in &geometries {
+                let new_relate_operation = || {
+                    RelateOperation::new(
+                        GeometryGraph::new(0, GeometryCow::from(a), BoundaryNodeRule::Mod2),
+                        GeometryGraph::new(1, GeometryCow::from(b), BoundaryNodeRule::Mod2),
+                    )
+                };
+                let intersection_matrix = new_relate_operation().compute_intersection_matrix();
+                for spec in specs {
+                    assert_eq!(
+                        new_relate_operation().compute_matches(&parse_spec(spec).unwrap()),
+                        intersection_matrix.matches(spec).unwrap(),
+                        "{spec} {a:?} {b:?}"
+                    );
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,25 @@
+/// Tests if two geometries touch: they have at least one point in common, but their interiors
+/// don't intersect.
+///
+/// In other words, the [DE-9IM] intersection matrix for (Self, Rhs) is `[FT*******]`,
+/// `[F**T*****]` or `[F***T****]`. Two points never touch, as points have no boundary.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, polygon};
+/// use geo::algorithm::Touches;
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let neighbour = polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2., y: 2.)];
+/// assert!(polygon.touches(&neighbour));
+///
+/// let line_string = line_string![(x: 1., y: 1.), (x: 3., y: 3.)];
+/// assert!(!polygon.touches(&line_string));
+/// assert!(point!(x: 1., y: 1.).touches(&line_string));
+/// ```
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+pub trait Touches<Rhs = Self> {
+    fn touches(&self, rhs: &Rhs) -> bool;
+}
@@ -0,0 +1,996 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+use crate::kernels::{Kernel, Orientation};
+use crate::triangulate_delaunay::{circumcenter, next_halfedge, prev_halfedge};
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoFloat, LineString, MultiPolygon, Polygon, Triangle, TriangulateDelaunay};
+
+/// Triangulate polygons, keeping the edges of their rings, such that each triangle's
+/// circumcircle contains no point visible from inside the triangle.
+///
+/// This is the constrained Delaunay triangulation of the rings. The points are first
+/// triangulated with [`TriangulateDelaunay`], then each edge of the rings is added by flipping
+/// the edges crossing it, and the Delaunay condition is restored around it. Only the triangles
+/// inside the polygons are returned, so holes are left empty. Unlike ear-cutting, this avoids
+/// slivers wherever the polygon allows.
+///
+/// The triangles can also be [refined](Self::refined_triangles) by adding Steiner points, so
+/// that no triangle has an angle smaller than a given minimum.
+///
+/// The polygons should be [valid](crate::IsValid); edges which cross other edges are left out.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, TriangulateConstrained};
+///
+/// let polygon = polygon!(
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+/// );
+///
+/// let triangles = polygon.constrained_triangles();
+/// assert_eq!(triangles.len(), 8);
+/// assert_eq!(triangles.iter().map(|t| t.unsigned_area()).sum::<f64>(), 96.);
+/// ```
+pub trait TriangulateConstrained {
+    type Scalar: GeoFloat;
+
+    /// Returns the triangles of the constrained Delaunay triangulation, with their vertices in
+    /// counter-clockwise order.
+    fn constrained_triangles(&self) -> Vec<Triangle<Self::Scalar>>;
+
+    /// Returns the triangles of the constrained Delaunay triangulation, with Steiner points
+    /// added using Ruppert's algorithm until no triangle has an angle smaller than the minimum
+    /// of `refinement`.
+    ///
+    /// Angles of the polygons which are already smaller than the minimum are kept, as are
+    /// the triangles between two of their edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, Area, Refinement, TriangulateConstrained};
+    ///
+    /// let sliver = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 1.), (x: 0., y: 1.)];
+    /// let triangles = sliver.refined_triangles(&Refinement::new().min_angle(25.));
+    /// assert!(triangles.len() > 2);
+    /// assert_eq!(triangles.iter().map(|t| t.unsigned_area()).sum::<f64>(), 10.);
+    /// ```
+    fn refined_triangles(
+        &self,
+        refinement: &Refinement<Self::Scalar>,
+    ) -> Vec<Triangle<Self::Scalar>>;
+}
+
+impl<T: GeoFloat> TriangulateConstrained for Polygon<T> {
+    type Scalar = T;
+
+    fn constrained_triangles(&self) -> Vec<Triangle<T>> {
+        triangulate(rings(self), None)
+    }
+
+    fn refined_triangles(&self, refinement: &Refinement<T>) -> Vec<Triangle<T>> {
+        triangulate(rings(self), Some(refinement))
+    }
+}
+
+impl<T: GeoFloat> TriangulateConstrained for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn constrained_triangles(&self) -> Vec<Triangle<T>> {
+        triangulate(self.iter().flat_map(rings), None)
+    }
+
+    fn refined_triangles(&self, refinement: &Refinement<T>) -> Vec<Triangle<T>> {
+        triangulate(self.iter().flat_map(rings), Some(refinement))
+    }
+}
+
+/// The quality required of a [refined triangulation](TriangulateConstrained::refined_triangles).
+///
+/// Ruppert's algorithm is guaranteed to finish for minimum angles up to about `20.7°`, if the
+/// polygons have no angles smaller than `60°`. Larger minimum angles usually work in practice,
+/// up to about `33°`, but to be sure of finishing the number of Steiner points is limited.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Refinement;
+///
+/// let refinement = Refinement::new().min_angle(30.).max_steiner_points(1000);
+/// assert_eq!(refinement.get_min_angle(), 30.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Refinement<T: GeoFloat> {
+    min_angle: T,
+    max_steiner_points: usize,
+}
+
+impl<T: GeoFloat> Refinement<T> {
+    /// A minimum angle of `20°`, with at most `100_000` Steiner points.
+    pub fn new() -> Self {
+        Self {
+            min_angle: T::from(20).unwrap(),
+            max_steiner_points: 100_000,
+        }
+    }
+
+    /// Set the smallest angle allowed in any triangle, in degrees. Values are clamped to the
+    /// range `0°` to `60°`.
+    pub fn min_angle(mut self, min_angle: T) -> Self {
+        self.min_angle = min_angle.max(T::zero()).min(T::from(60).unwrap());
+        self
+    }
+
+    /// Set the largest number of Steiner points which may be added.
+    pub fn max_steiner_points(mut self, max_steiner_points: usize) -> Self {
+        self.max_steiner_points = max_steiner_points;
+        self
+    }
+
+    pub fn get_min_angle(&self) -> T {
+        self.min_angle
+    }
+
+    pub fn get_max_steiner_points(&self) -> usize {
+        self.max_steiner_points
+    }
+}
+
+impl<T: GeoFloat> Default for Refinement<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn rings<T: GeoFloat>(polygon: &Polygon<T>) -> impl Iterator<Item = &LineString<T>> {
+    std::iter::once(polygon.exterior()).chain(polygon.interiors())
+}
+
+fn triangulate<'a, T: GeoFloat + 'a>(
+    rings: impl Iterator<Item = &'a LineString<T>>,
+    refinement: Option<&Refinement<T>>,
+) -> Vec<Triangle<T>> {
+    let rings: Vec<_> = rings.collect();
+    let mut coords: Vec<_> = rings
+        .iter()
+        .flat_map(|ring| ring.0.iter().copied())
+        .collect();
+    coords.sort_by(lex_cmp);
+    coords.dedup();
+    let index = |c: &Coord<T>| coords.binary_search_by(|p| lex_cmp(p, c)).unwrap();
+
+    let Some(mut mesh) = Mesh::new(&coords) else {
+        return vec![];
+    };
+    for ring in rings {
+        for line in ring.lines() {
+            let (a, b) = (index(&line.start), index(&line.end));
+            if a != b {
+                mesh.insert_segment(a, b);
+            }
+        }
+    }
+    mesh.find_inside();
+    if let Some(refinement) = refinement {
+        mesh.refine(refinement);
+    }
+
+    (0..mesh.inside.len())
+        .filter(|&t| mesh.inside[t])
+        .map(|t| {
+            let [a, b, c] = mesh.vertices(t);
+            Triangle::new(mesh.points[a], mesh.points[b], mesh.points[c])
+        })
+        .collect()
+}
+
+/// Where a point was found in the triangulation.
+enum Location {
+    Triangle(usize),
+    /// On the edge of a half-edge.
+    Edge(usize),
+    Vertex,
+    /// Hidden from the starting triangle by a constrained half-edge.
+    Blocked(usize),
+}
+
+/// A triangulation which can be modified, as a half-edge structure like
+/// [`DelaunayTriangulation`](crate::triangulate_delaunay::DelaunayTriangulation).
+struct Mesh<T: GeoFloat> {
+    points: Vec<Coord<T>>,
+    triangles: Vec<usize>,
+    halfedges: Vec<Option<usize>>,
+    /// Whether each half-edge is part of a ring.
+    constrained: Vec<bool>,
+    /// Whether each triangle is inside the polygons.
+    inside: Vec<bool>,
+    /// A half-edge starting at each point.
+    outgoing: Vec<usize>,
+    /// The number of points which were triangulated, before any Steiner points.
+    input_points: usize,
+    /// The ends of the segment each Steiner point was added to, if it was.
+    segments: Vec<Option<[usize; 2]>>,
+}
+
+impl<T: GeoFloat> Mesh<T> {
+    fn new(points: &[Coord<T>]) -> Option<Self> {
+        let triangulation = points.delaunay_triangulation();
+        if triangulation.is_empty() {
+            return None;
+        }
+        let mut outgoing = vec![0; points.len()];
+        for (e, &i) in triangulation.triangles.iter().enumerate() {
+            outgoing[i] = e;
+        }
+        Some(Mesh {
+            constrained: vec![false; triangulation.triangles.len()],
+            inside: vec![false; triangulation.len()],
+            points: triangulation.points,
+            triangles: triangulation.triangles,
+            halfedges: triangulation.halfedges,
+            outgoing,
+            input_points: points.len(),
+            segments: vec![None; points.len()],
+        })
+    }
+
+    fn vertices(&self, t: usize) -> [usize; 3] {
+        [
+            self.triangles[3 * t],
+            self.triangles[3 * t + 1],
+            self.triangles[3 * t + 2],
+        ]
+    }
+
+    fn orient(&self, a: usize, b: usize, c: usize) -> Orientation {
+        T::Ker::orient2d(self.points[a], self.points[b], self.points[c])
+    }
+
+    /// Whether `d` is strictly inside the circumcircle of the triangle of half-edge `e`.
+    fn in_circumcircle(&self, e: usize, d: Coord<T>) -> bool {
+        let [a, b, c] = self.vertices(e / 3).map(|i| self.points[i]);
+        T::Ker::incircle(a, b, c, d) == Orientation::CounterClockwise
+    }
+
+    /// The half-edges starting at point `a`.
+    fn outgoing(&self, a: usize) -> Vec<usize> {
+        let start = self.outgoing[a];
+        let mut edges = vec![];
+        let mut e = start;
+        loop {
+            edges.push(e);
+            match self.halfedges[prev_halfedge(e)] {
+                Some(o) if o == start => return edges,
+                Some(o) => e = o,
+                None => break,
+            }
+        }
+        // The point is on the hull, so go the other way around it too.
+        let mut e = start;
+        while let Some(o) = self.halfedges[e] {
+            e = next_halfedge(o);
+            edges.push(e);
+        }
+        edges
+    }
+
+    /// The half-edge from `a` to `b`, if there is one.
+    fn find_edge(&self, a: usize, b: usize) -> Option<usize> {
+        self.outgoing(a)
+            .into_iter()
+            .find(|&e| self.triangles[next_halfedge(e)] == b)
+    }
+
+    fn set_constrained(&mut self, e: usize) {
+        self.constrained[e] = true;
+        if let Some(o) = self.halfedges[e] {
+            self.constrained[o] = true;
+        }
+    }
+
+    /// Sets the points of triangle `t`, adding it if it's new.
+    fn set_triangle(&mut self, t: usize, vertices: [usize; 3], inside: bool) {
+        if 3 * t == self.triangles.len() {
+            self.triangles.extend(vertices);
+            self.halfedges.extend([None; 3]);
+            self.constrained.extend([false; 3]);
+            self.inside.push(inside);
+        } else {
+            self.triangles[3 * t..3 * t + 3].copy_from_slice(&vertices);
+            self.inside[t] = inside;
+        }
+        for (k, v) in vertices.into_iter().enumerate() {
+            self.outgoing[v] = 3 * t + k;
+        }
+    }
+
+    /// Joins half-edge `e` to its opposite `o`, which is constrained if `constrained`.
+    fn link(&mut self, e: usize, o: Option<usize>, constrained: bool) {
+        self.halfedges[e] = o;
+        self.constrained[e] = constrained;
+        if let Some(o) = o {
+            self.halfedges[o] = Some(e);
+            self.constrained[o] = constrained;
+        }
+    }
+
+    /// The opposite and constraint of half-edge `e`.
+    fn outside(&self, e: usize) -> (Option<usize>, bool) {
+        (self.halfedges[e], self.constrained[e])
+    }
+
+    /// Replaces the edge of half-edge `e`, the diagonal of the quadrilateral formed by its
+    /// triangle and the opposite one, with the other diagonal.
+    ///
+    /// If `e` goes from `a` to `b` in triangle `(a, b, c)`, and the opposite triangle is
+    /// `(b, a, d)`, the new triangles are `(c, a, d)` and `(d, b, c)`, in the same places.
+    fn flip(&mut self, e: usize) {
+        let o = self.halfedges[e].unwrap();
+        let (a, b) = (self.triangles[e], self.triangles[o]);
+        let (c, d) = (
+            self.triangles[prev_halfedge(e)],
+            self.triangles[prev_halfedge(o)],
+        );
+        let (ca, bc) = (
+            self.outside(prev_halfedge(e)),
+            self.outside(next_halfedge(e)),
+        );
+        let (ad, db) = (
+            self.outside(next_halfedge(o)),
+            self.outside(prev_halfedge(o)),
+        );
+        let inside = self.inside[e / 3];
+
+        let (t0, t1) = (e / 3, o / 3);
+        self.set_triangle(t0, [c, a, d], inside);
+        self.set_triangle(t1, [d, b, c], inside);
+        self.link(3 * t0, ca.0, ca.1);
+        self.link(3 * t0 + 1, ad.0, ad.1);
+        self.link(3 * t0 + 2, Some(3 * t1 + 2), false);
+        self.link(3 * t1, db.0, db.1);
+        self.link(3 * t1 + 1, bc.0, bc.1);
+    }
+
+    /// Flips edges until the triangles across each of the half-edges `edges` satisfy the
+    /// Delaunay condition.
+    fn legalize(&mut self, mut edges: Vec<usize>) {
+        while let Some(e) = edges.pop() {
+            let Some(o) = self.halfedges[e] else {
+                continue;
+            };
+            let d = self.points[self.triangles[prev_halfedge(o)]];
+            if self.constrained[e] || !self.in_circumcircle(e, d) {
+                continue;
+            }
+            self.flip(e);
+            edges.push(3 * (e / 3) + 1);
+            edges.push(3 * (o / 3));
+        }
+    }
+
+    /// Adds point `p` inside triangle `t`.
+    fn insert_in_triangle(&mut self, t: usize, p: usize) {
+        let [a, b, c] = self.vertices(t);
+        let (ab, bc, ca) = (
+            self.outside(3 * t),
+            self.outside(3 * t + 1),
+            self.outside(3 * t + 2),
+        );
+        let inside = self.inside[t];
+        let (t1, t2) = (self.inside.len(), self.inside.len() + 1);
+
+        self.set_triangle(t, [a, b, p], inside);
+        self.set_triangle(t1, [b, c, p], inside);
+        self.set_triangle(t2, [c, a, p], inside);
+        self.link(3 * t, ab.0, ab.1);
+        self.link(3 * t + 1, Some(3 * t1 + 2), false);
+        self.link(3 * t + 2, Some(3 * t2 + 1), false);
+        self.link(3 * t1, bc.0, bc.1);
+        self.link(3 * t1 + 1, Some(3 * t2 + 2), false);
+        self.link(3 * t2, ca.0, ca.1);
+        self.legalize(vec![3 * t, 3 * t1, 3 * t2]);
+    }
+
+    /// Adds point `p` on the edge of half-edge `e`, splitting the triangles on both sides.
+    fn insert_on_edge(&mut self, e: usize, p: usize) {
+        let o = self.halfedges[e];
+        let (a, b, c) = (
+            self.triangles[e],
+            self.triangles[next_halfedge(e)],
+            self.triangles[prev_halfedge(e)],
+        );
+        let (bc, ca) = (
+            self.outside(next_halfedge(e)),
+            self.outside(prev_halfedge(e)),
+        );
+        let constrained = self.constrained[e];
+        let t = e / 3;
+        let inside = self.inside[t];
+        let t1 = self.inside.len();
+
+        self.set_triangle(t, [c, a, p], inside);
+        self.set_triangle(t1, [b, c, p], inside);
+        self.link(3 * t, ca.0, ca.1);
+        self.link(3 * t + 2, Some(3 * t1 + 1), false);
+        self.link(3 * t1, bc.0, bc.1);
+        let mut edges = vec![3 * t, 3 * t1];
+
+        match o {
+            Some(o) => {
+                let d = self.triangles[prev_halfedge(o)];
+                let (ad, db) = (
+                    self.outside(next_halfedge(o)),
+                    self.outside(prev_halfedge(o)),
+                );
+                let u = o / 3;
+                let inside = self.inside[u];
+                let u1 = self.inside.len();
+
+                self.set_triangle(u, [d, b, p], inside);
+                self.set_triangle(u1, [a, d, p], inside);
+                self.link(3 * u, db.0, db.1);
+                self.link(3 * u + 1, Some(3 * t1 + 2), constrained);
+                self.link(3 * u + 2, Some(3 * u1 + 1), false);
+                self.link(3 * u1, ad.0, ad.1);
+                self.link(3 * u1 + 2, Some(3 * t + 1), constrained);
+                edges.extend([3 * u, 3 * u1]);
+            }
+            None => {
+                self.link(3 * t + 1, None, constrained);
+                self.link(3 * t1 + 2, None, constrained);
+            }
+        }
+        self.legalize(edges);
+    }
+
+    /// Adds the edge from `a` to `b`, and constrains it to stay in the triangulation.
+    fn insert_segment(&mut self, mut a: usize, b: usize) {
+        'segment: while a != b {
+            if let Some(e) = self.find_edge(a, b).or_else(|| self.find_edge(b, a)) {
+                self.set_constrained(e);
+                return;
+            }
+
+            // Find the first edge crossed by the segment, or an edge along it.
+            let mut first = None;
+            for e in self.outgoing(a) {
+                let (x, y) = (
+                    self.triangles[next_halfedge(e)],
+                    self.triangles[prev_halfedge(e)],
+                );
+                // The edge from `a` to `y` is only an outgoing half-edge if it isn't on the hull.
+                for (along, next) in [(e, x), (prev_halfedge(e), y)] {
+                    let (an, ab) = (
+                        self.points[next] - self.points[a],
+                        self.points[b] - self.points[a],
+                    );
+                    if self.orient(a, b, next) == Orientation::Collinear
+                        && an.x * ab.x + an.y * ab.y > T::zero()
+                    {
+                        self.set_constrained(along);
+                        a = next;
+                        continue 'segment;
+                    }
+                }
+                if self.orient(a, x, b) == Orientation::CounterClockwise
+                    && self.orient(a, y, b) == Orientation::Clockwise
+                {
+                    first = Some(next_halfedge(e));
+                    break;
+                }
+            }
+            let Some(mut h) = first else {
+                return;
+            };
+
+            // Collect the crossed edges, up to the end of the segment or a point on it.
+            let mut crossing = vec![];
+            let end = loop {
+                let (Some(o), false) = (self.halfedges[h], self.constrained[h]) else {
+                    // The segment crosses another segment.
+                    return;
+                };
+                let u = self.triangles[h];
+                crossing.push((u, self.triangles[o]));
+                let z = self.triangles[prev_halfedge(o)];
+                let side = self.orient(a, b, z);
+                if z == b || side == Orientation::Collinear {
+                    break z;
+                }
+                h = if self.orient(a, b, u) == side {
+                    prev_halfedge(o)
+                } else {
+                    next_halfedge(o)
+                };
+            };
+
+            let new_edges = self.remove_crossing(a, end, crossing);
+            let e = self.find_edge(a, end).or_else(|| self.find_edge(end, a));
+            self.set_constrained(e.unwrap());
+            self.restore_delaunay(a, end, new_edges);
+            a = end;
+        }
+    }
+
+    /// Flips the edges `crossing` the segment from `a` to `b` until none cross it, returning
+    /// the new edges.
+    fn remove_crossing(
+        &mut self,
+        a: usize,
+        b: usize,
+        crossing: Vec<(usize, usize)>,
+    ) -> Vec<(usize, usize)> {
+        let mut crossing = VecDeque::from(crossing);
+        let mut new_edges = vec![];
+        while let Some((u, v)) = crossing.pop_front() {
+            let e = self.find_edge(u, v).unwrap();
+            let o = self.halfedges[e].unwrap();
+            let (c, d) = (
+                self.triangles[prev_halfedge(e)],
+                self.triangles[prev_halfedge(o)],
+            );
+            // The edge can only be flipped if its quadrilateral is convex.
+            let (side_u, side_v) = (self.orient(c, d, u), self.orient(c, d, v));
+            if side_u == Orientation::Collinear
+                || side_v == Orientation::Collinear
+                || side_u == side_v
+            {
+                crossing.push_back((u, v));
+                continue;
+            }
+            self.flip(e);
+            let (side_c, side_d) = (self.orient(a, b, c), self.orient(a, b, d));
+            if side_c != Orientation::Collinear
+                && side_d != Orientation::Collinear
+                && side_c != side_d
+            {
+                crossing.push_back((c, d));
+            } else {
+                new_edges.push((c, d));
+            }
+        }
+        new_edges
+    }
+
+    /// Flips the `new_edges` made while adding the segment from `a` to `b`, until they
+    /// satisfy the Delaunay condition.
+    fn restore_delaunay(&mut self, a: usize, b: usize, mut new_edges: Vec<(usize, usize)>) {
+        loop {
+            let mut flipped = false;
+            for edge in new_edges.iter_mut() {
+                let (u, v) = *edge;
+                if (u, v) == (a, b) || (u, v) == (b, a) {
+                    continue;
+                }
+                let e = self.find_edge(u, v).unwrap();
+                let Some(o) = self.halfedges[e] else {
+                    continue;
+                };
+                let c = self.triangles[prev_halfedge(e)];
+                let d = self.triangles[prev_halfedge(o)];
+                if !self.constrained[e] && self.in_circumcircle(e, self.points[d]) {
+                    self.flip(e);
+                    *edge = (c, d);
+                    flipped = true;
+                }
+            }
+            if !flipped {
+                return;
+            }
+        }
+    }
+
+    /// Marks the triangles inside the polygons, as those separated from the hull by an odd
+    /// number of constrained edges.
+    fn find_inside(&mut self) {
+        let mut visited = vec![false; self.inside.len()];
+        let mut stack = vec![];
+        for e in 0..self.triangles.len() {
+            if self.halfedges[e].is_none() && !visited[e / 3] {
+                visited[e / 3] = true;
+                self.inside[e / 3] = self.constrained[e];
+                stack.push(e / 3);
+            }
+        }
+        while let Some(t) = stack.pop() {
+            for e in 3 * t..3 * t + 3 {
+                let Some(o) = self.halfedges[e] else {
+                    continue;
+                };
+                if !visited[o / 3] {
+                    visited[o / 3] = true;
+                    self.inside[o / 3] = self.inside[t] != self.constrained[e];
+                    stack.push(o / 3);
+                }
+            }
+        }
+    }
+
+    /// Adds Steiner points with Ruppert's algorithm, until no triangle inside the polygons has
+    /// an angle smaller than the minimum.
+    fn refine(&mut self, refinement: &Refinement<T>) {
+        let sin = refinement.min_angle.to_radians().sin();
+        let min_ratio = sin * sin * T::from(4).unwrap();
+
+        let mut segments: Vec<_> = (0..self.triangles.len())
+            .filter(|&e| self.constrained[e] && self.inside[e / 3])
+            .map(|e| (self.triangles[e], self.triangles[next_halfedge(e)]))
+            .collect();
+        let mut bad: VecDeque<_> = (0..self.inside.len())
+            .filter(|&t| self.is_bad(t, min_ratio))
+            .map(|t| self.vertices(t))
+            .collect();
+
+        let mut added = 0;
+        while added < refinement.max_steiner_points {
+            // Split encroached segments first.
+            if let Some((u, v)) = segments.pop() {
+                let Some(e) = self.find_edge(u, v) else {
+                    continue;
+                };
+                if self.constrained[e] && self.inside[e / 3] && self.is_encroached(e) {
+                    if let Some(p) = self.split_segment(e) {
+                        added += 1;
+                        self.queue_around(p, min_ratio, &mut segments, &mut bad);
+                    }
+                }
+                continue;
+            }
+
+            let Some([a, b, c]) = bad.pop_front() else {
+                break;
+            };
+            let Some(e) = self.find_edge(a, b) else {
+                continue;
+            };
+            let t = e / 3;
+            if self.triangles[prev_halfedge(e)] != c || !self.is_bad(t, min_ratio) {
+                continue;
+            }
+
+            let center = circumcenter(self.points[a], self.points[b], self.points[c]);
+            let location = self.locate(t, center);
+            let encroached = match location {
+                Some(Location::Triangle(t)) => self.encroached_by(t, center),
+                Some(Location::Edge(e)) if !self.constrained[e] => {
+                    self.encroached_by(e / 3, center)
+                }
+                Some(Location::Edge(e) | Location::Blocked(e)) => {
+                    vec![(self.triangles[e], self.triangles[next_halfedge(e)])]
+                }
+                Some(Location::Vertex) | None => continue,
+            };
+            if encroached.is_empty() {
+                let p = self.add_point(center, None);
+                match location {
+                    Some(Location::Edge(e)) => self.insert_on_edge(e, p),
+                    Some(Location::Triangle(t)) => self.insert_in_triangle(t, p),
+                    _ => unreachable!(),
+                }
+                added += 1;
+                self.queue_around(p, min_ratio, &mut segments, &mut bad);
+            } else {
+                // Split the segments instead, and try the triangle again afterwards.
+                let mut split = false;
+                for (u, v) in encroached {
+                    if added == refinement.max_steiner_points {
+                        break;
+                    }
+                    let Some(e) = self.find_edge(u, v) else {
+                        continue;
+                    };
+                    if let Some(p) = self.split_segment(e) {
+                        split = true;
+                        added += 1;
+                        self.queue_around(p, min_ratio, &mut segments, &mut bad);
+                    }
+                }
+                if split {
+                    bad.push_back([a, b, c]);
+                }
+            }
+        }
+    }
+
+    /// Whether triangle `t` is inside the polygons and has an angle smaller than the minimum,
+    /// which can be improved.
+    fn is_bad(&self, t: usize, min_ratio: T) -> bool {
+        if !self.inside[t] {
+            return false;
+        }
+        let [a, b, c] = self.vertices(t).map(|i| self.points[i]);
+        let lengths = [(b - a), (c - b), (a - c)].map(|d| d.x * d.x + d.y * d.y);
+        // The smallest angle is opposite the shortest edge.
+        let shortest = (0..3)
+            .min_by(|&i, &j| {
+                lengths[i]
+                    .partial_cmp(&lengths[j])
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap();
+        let radius = {
+            let d = circumcenter(a, b, c) - a;
+            d.x * d.x + d.y * d.y
+        };
+        if lengths[shortest] >= min_ratio * radius {
+            return false;
+        }
+        // An angle between two segments can't be improved.
+        let others = [3 * t + (shortest + 1) % 3, 3 * t + (shortest + 2) % 3];
+        if others.iter().all(|&e| self.constrained[e]) {
+            return false;
+        }
+        // Nor can the angle opposite an edge across a small angle between two segments, as
+        // splitting it would only add smaller triangles nearer the apex.
+        let e = 3 * t + shortest;
+        let ends = (
+            self.segments[self.triangles[e]],
+            self.segments[self.triangles[next_halfedge(e)]],
+        );
+        match ends {
+            (Some(s), Some(r)) => s == r || !s.iter().any(|i| r.contains(i)),
+            _ => true,
+        }
+    }
+
+    /// Whether the apex of the triangle of constrained half-edge `e` is inside its diametral
+    /// circle.
+    fn is_encroached(&self, e: usize) -> bool {
+        let apex = self.points[self.triangles[prev_halfedge(e)]];
+        self.encroaches(e, apex)
+    }
+
+    fn encroaches(&self, e: usize, p: Coord<T>) -> bool {
+        let (u, v) = (
+            self.points[self.triangles[e]] - p,
+            self.points[self.triangles[next_halfedge(e)]] - p,
+        );
+        u.x * v.x + u.y * v.y < T::zero()
+    }
+
+    /// The segments encroached by `p`, out of those bounding the triangles whose circumcircles
+    /// contain `p`, starting from triangle `t`.
+    fn encroached_by(&self, t: usize, p: Coord<T>) -> Vec<(usize, usize)> {
+        let mut encroached = vec![];
+        let mut cavity = vec![t];
+        let mut stack = vec![t];
+        while let Some(t) = stack.pop() {
+            for e in 3 * t..3 * t + 3 {
+                if self.constrained[e] {
+                    if self.encroaches(e, p) {
+                        encroached.push((self.triangles[e], self.triangles[next_halfedge(e)]));
+                    }
+                } else if let Some(o) = self.halfedges[e] {
+                    if !cavity.contains(&(o / 3)) && self.in_circumcircle(o, p) {
+                        cavity.push(o / 3);
+                        stack.push(o / 3);
+                    }
+                }
+            }
+        }
+        encroached
+    }
+
+    /// Splits the segment of half-edge `e`, returning the new point, unless it's too short to
+    /// split.
+    ///
+    /// Segments are split at their midpoints, except next to a point of the polygons, where
+    /// they're split at a power of two from it. Points around a small angle between two
+    /// segments then lie on concentric circles, so that the triangles between them can be left
+    /// alone, rather than being split forever.
+    fn split_segment(&mut self, e: usize) -> Option<usize> {
+        let (u, v) = (self.triangles[e], self.triangles[next_halfedge(e)]);
+        let segment = self.segments[u].or(self.segments[v]).unwrap_or([u, v]);
+        let (start, end) = (self.points[u], self.points[v]);
+        let two = T::from(2).unwrap();
+        let split = match (u < self.input_points, v < self.input_points) {
+            (true, false) => shell_split(start, end),
+            (false, true) => shell_split(end, start),
+            _ => (start + end) / two,
+        };
+        if split == start || split == end {
+            return None;
+        }
+        let p = self.add_point(split, Some(segment));
+        self.insert_on_edge(e, p);
+        Some(p)
+    }
+
+    /// Adds a Steiner point, which isn't part of the triangulation yet.
+    fn add_point(&mut self, coord: Coord<T>, segment: Option<[usize; 2]>) -> usize {
+        self.points.push(coord);
+        self.outgoing.push(0);
+        self.segments.push(segment);
+        self.points.len() - 1
+    }
+
+    /// Queues the segments and bad triangles around the new point `p`.
+    fn queue_around(
+        &self,
+        p: usize,
+        min_ratio: T,
+        segments: &mut Vec<(usize, usize)>,
+        bad: &mut VecDeque<[usize; 3]>,
+    ) {
+        for e in self.outgoing(p) {
+            let far = next_halfedge(e);
+            if self.constrained[far] && self.inside[e / 3] {
+                segments.push((self.triangles[far], self.triangles[prev_halfedge(e)]));
+            }
+            if self.is_bad(e / 3, min_ratio) {
+                bad.push_back(self.vertices(e / 3));
+            }
+        }
+    }
+
+    /// Walks in a straight line from the middle of triangle `t` to `p`, stopping at any
+    /// constrained edge in the way.
+    fn locate(&self, mut t: usize, p: Coord<T>) -> Option<Location> {
+        let [a, b, c] = self.vertices(t).map(|i| self.points[i]);
+        let start = (a + b + c) / T::from(3).unwrap();
+        for _ in 0..self.inside.len() {
+            let exit = (3 * t..3 * t + 3).find(|&e| {
+                let (u, v) = (
+                    self.points[self.triangles[e]],
+                    self.points[self.triangles[next_halfedge(e)]],
+                );
+                T::Ker::orient2d(u, v, p) == Orientation::Clockwise
+                    && T::Ker::orient2d(start, p, u) != T::Ker::orient2d(start, p, v)
+            });
+            let Some(exit) = exit else {
+                if self.vertices(t).iter().any(|&i| self.points[i] == p) {
+                    return Some(Location::Vertex);
+                }
+                return Some(
+                    (3 * t..3 * t + 3)
+                        .find(|&e| {
+                            let (u, v) = (
+                                self.points[self.triangles[e]],
+                                self.points[self.triangles[next_halfedge(e)]],
+                            );
+                            T::Ker::orient2d(u, v, p) == Orientation::Collinear
+                        })
+                        .map_or(Location::Triangle(t), Location::Edge),
+                );
+            };
+            if self.constrained[exit] {
+                return Some(Location::Blocked(exit));
+            }
+            t = self.halfedges[exit]? / 3;
+        }
+        None
+    }
+}
+
+/// The point between `apex` and `end` which is a power of two from `apex`, and between a third
+/// and two thirds of the way along.
+fn shell_split<T: GeoFloat>(apex: Coord<T>, end: Coord<T>) -> Coord<T> {
+    let d = end - apex;
+    let length = d.x.hypot(d.y);
+    let distance = T::from(2).unwrap().powf(
+        (length * T::from(2).unwrap() / T::from(3).unwrap())
+            .log2()
+            .floor(),
+    );
+    apex + d * (distance / length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{polygon, Area, Contains, Point};
+    use approx::assert_relative_eq;
+
+    /// Checks the triangles are counter-clockwise and cover the polygon, and returns their
+    /// smallest angle, in degrees.
+    fn check(polygon: &Polygon, triangles: &[Triangle]) -> f64 {
+        let mut min_angle = f64::MAX;
+        for triangle in triangles {
+            assert!(triangle.signed_area() > 0.);
+            let [a, b, c] = triangle.to_array();
+            for (p, q, r) in [(a, b, c), (b, c, a), (c, a, b)] {
+                let (u, v) = (q - p, r - p);
+                let angle = (u.x * v.y - u.y * v.x).atan2(u.x * v.x + u.y * v.y);
+                min_angle = min_angle.min(angle.to_degrees());
+            }
+            let centroid = (a + b + c) / 3.;
+            assert!(polygon.contains(&Point::from(centroid)));
+        }
+        assert_relative_eq!(
+            triangles.iter().map(|t| t.unsigned_area()).sum::<f64>(),
+            polygon.unsigned_area(),
+            epsilon = 1e-9
+        );
+        min_angle
+    }
+
+    #[test]
+    fn concave() {
+        // A comb, whose teeth aren't visible from each other.
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 8., y: 10.),
+            (x: 8., y: 2.),
+            (x: 6., y: 2.),
+            (x: 6., y: 10.),
+            (x: 4., y: 10.),
+            (x: 4., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        let triangles = polygon.constrained_triangles();
+        assert_eq!(triangles.len(), 10);
+        check(&polygon, &triangles);
+    }
+
+    #[test]
+    fn holes() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 20., y: 0.), (x: 20., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 2., y: 2.), (x: 8., y: 2.), (x: 5., y: 8.)],
+                // Touches the exterior at a point.
+                [(x: 10., y: 0.), (x: 12., y: 5.), (x: 14., y: 3.)],
+                // Has a point on the edge of the first hole's bounding triangle.
+                [(x: 15., y: 8.), (x: 18., y: 8.), (x: 18., y: 9.)],
+            ],
+        );
+        let triangles = polygon.constrained_triangles();
+        check(&polygon, &triangles);
+
+        let multi = MultiPolygon::new(vec![
+            polygon.clone(),
+            polygon![(x: 30., y: 0.), (x: 40., y: 0.), (x: 35., y: 5.)],
+        ]);
+        assert_eq!(multi.constrained_triangles().len(), triangles.len() + 1);
+    }
+
+    #[test]
+    fn collinear_points_on_edges() {
+        // The ring passes through points of other edges, and has repeated points.
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 5., y: 5.),
+            (x: 0., y: 10.),
+        ];
+        check(&polygon, &polygon.constrained_triangles());
+    }
+
+    #[test]
+    fn refine() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 30., y: 0.), (x: 30., y: 3.), (x: 0., y: 3.)],
+            interiors: [[(x: 10., y: 1.), (x: 20., y: 1.), (x: 20., y: 2.), (x: 10., y: 2.)]],
+        );
+        let coarse = polygon.constrained_triangles();
+        assert!(check(&polygon, &coarse) < 10.);
+
+        for min_angle in [20., 30.] {
+            let refined = polygon.refined_triangles(&Refinement::new().min_angle(min_angle));
+            assert!(check(&polygon, &refined) >= min_angle);
+        }
+
+        // The number of Steiner points can be limited.
+        let limited = polygon.refined_triangles(&Refinement::new().max_steiner_points(3));
+        assert!(limited.len() > coarse.len() && limited.len() <= coarse.len() + 6);
+        check(&polygon, &limited);
+    }
+
+    #[test]
+    fn small_input_angle() {
+        // The angle at the origin can't be improved, but refinement still finishes.
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 1.)];
+        let triangles = polygon.refined_triangles(&Refinement::new().min_angle(30.));
+        check(&polygon, &triangles);
+    }
+}
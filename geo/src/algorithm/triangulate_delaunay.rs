@@ -0,0 +1,747 @@
+use std::cmp::Ordering;
+
+use crate::kernels::{Kernel, Orientation};
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoFloat, MultiPoint, Triangle};
+
+/// Triangulate a set of points, such that no point lies inside the circumcircle of any
+/// triangle.
+///
+/// The triangulation is built with a sweep outwards from the middle of the points, flipping
+/// edges to restore the Delaunay condition as each point is added. The orientation and
+/// in-circle tests use the robust predicates of the [`Kernel`], so the result is exact for any
+/// input, however nearly collinear or co-circular its points are.
+///
+/// Duplicate points are only used once, and points with a `NaN` or infinite coordinate are
+/// left out. If all the points are collinear, there are no triangles.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, Area, TriangulateDelaunay};
+///
+/// let points = [
+///     coord! { x: 0., y: 0. },
+///     coord! { x: 10., y: 0. },
+///     coord! { x: 10., y: 10. },
+///     coord! { x: 0., y: 10. },
+///     coord! { x: 5., y: 4. },
+/// ];
+///
+/// let triangles = points.delaunay_triangles();
+/// assert_eq!(triangles.len(), 4);
+/// assert_eq!(triangles.iter().map(|t| t.unsigned_area()).sum::<f64>(), 100.);
+///
+/// // The triangulation can also be walked by index.
+/// let triangulation = points.delaunay_triangulation();
+/// let mut neighbors = triangulation.point_neighbors(4);
+/// neighbors.sort();
+/// assert_eq!(neighbors, vec![0, 1, 2, 3]);
+/// ```
+pub trait TriangulateDelaunay {
+    type Scalar: GeoFloat;
+
+    /// Returns the triangulation, as a half-edge structure indexing into the points.
+    fn delaunay_triangulation(&self) -> DelaunayTriangulation<Self::Scalar>;
+
+    /// Returns the triangles of the triangulation, with their vertices in counter-clockwise
+    /// order.
+    fn delaunay_triangles(&self) -> Vec<Triangle<Self::Scalar>> {
+        self.delaunay_triangulation().triangles().collect()
+    }
+}
+
+impl<T: GeoFloat> TriangulateDelaunay for MultiPoint<T> {
+    type Scalar = T;
+
+    fn delaunay_triangulation(&self) -> DelaunayTriangulation<T> {
+        DelaunayTriangulation::new(self.iter().map(|p| p.0).collect())
+    }
+}
+
+impl<T: GeoFloat> TriangulateDelaunay for [Coord<T>] {
+    type Scalar = T;
+
+    fn delaunay_triangulation(&self) -> DelaunayTriangulation<T> {
+        DelaunayTriangulation::new(self.to_vec())
+    }
+}
+
+/// A triangulation of a set of points, as a half-edge structure.
+///
+/// The points are referred to by their index in the input. Each triangle `t` has three
+/// half-edges, `3 * t`, `3 * t + 1` and `3 * t + 2`, going counter-clockwise around it. Each
+/// half-edge starts at the point returned by [`origin`](Self::origin), and the half-edge going
+/// the other way along the same edge, in the neighbouring triangle, is its
+/// [`opposite`](Self::opposite). Edges on the convex hull have no opposite.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DelaunayTriangulation<T: GeoFloat> {
+    pub(crate) points: Vec<Coord<T>>,
+    /// The point at the start of each half-edge.
+    pub(crate) triangles: Vec<usize>,
+    /// The opposite of each half-edge.
+    pub(crate) halfedges: Vec<Option<usize>>,
+    /// The points on the convex hull, in counter-clockwise order.
+    pub(crate) hull: Vec<usize>,
+    /// A half-edge ending at each point, which is on the hull for points on the hull.
+    pub(crate) inedges: Vec<Option<usize>>,
+}
+
+impl<T: GeoFloat> DelaunayTriangulation<T> {
+    /// The points which were triangulated.
+    pub fn points(&self) -> &[Coord<T>] {
+        &self.points
+    }
+
+    /// The number of triangles.
+    pub fn len(&self) -> usize {
+        self.triangles.len() / 3
+    }
+
+    /// Returns `true` if there are no triangles.
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    /// The indices of the points at the corners of triangle `t`, in counter-clockwise order.
+    pub fn vertices(&self, t: usize) -> [usize; 3] {
+        [
+            self.triangles[3 * t],
+            self.triangles[3 * t + 1],
+            self.triangles[3 * t + 2],
+        ]
+    }
+
+    /// The triangle `t`.
+    pub fn triangle(&self, t: usize) -> Triangle<T> {
+        let [a, b, c] = self.vertices(t);
+        Triangle::new(self.points[a], self.points[b], self.points[c])
+    }
+
+    /// Iterates over the triangles.
+    pub fn triangles(&self) -> impl Iterator<Item = Triangle<T>> + '_ {
+        (0..self.len()).map(|t| self.triangle(t))
+    }
+
+    /// The triangles across each edge of triangle `t`, starting with the edge from its first
+    /// vertex to its second, or `None` where the edge is on the hull.
+    pub fn triangle_neighbors(&self, t: usize) -> [Option<usize>; 3] {
+        [0, 1, 2].map(|i| self.halfedges[3 * t + i].map(|e| e / 3))
+    }
+
+    /// The points joined to point `i` by an edge, in clockwise order.
+    pub fn point_neighbors(&self, i: usize) -> Vec<usize> {
+        let mut neighbors = vec![];
+        let Some(start) = self.inedges[i] else {
+            return neighbors;
+        };
+        let mut e = start;
+        loop {
+            neighbors.push(self.triangles[e]);
+            let outgoing = next_halfedge(e);
+            match self.halfedges[outgoing] {
+                Some(opposite) if opposite != start => e = opposite,
+                Some(_) => break,
+                None => {
+                    neighbors.push(self.triangles[next_halfedge(outgoing)]);
+                    break;
+                }
+            }
+        }
+        neighbors
+    }
+
+    /// The indices of the points on the convex hull, in counter-clockwise order.
+    pub fn hull(&self) -> &[usize] {
+        &self.hull
+    }
+
+    /// The point at the start of half-edge `e`.
+    pub fn origin(&self, e: usize) -> usize {
+        self.triangles[e]
+    }
+
+    /// The half-edge going the other way along the edge of half-edge `e`, if it isn't on the
+    /// hull.
+    pub fn opposite(&self, e: usize) -> Option<usize> {
+        self.halfedges[e]
+    }
+
+    /// The next half-edge counter-clockwise around the triangle of half-edge `e`.
+    pub fn next_halfedge(&self, e: usize) -> usize {
+        next_halfedge(e)
+    }
+
+    /// The previous half-edge counter-clockwise around the triangle of half-edge `e`.
+    pub fn prev_halfedge(&self, e: usize) -> usize {
+        prev_halfedge(e)
+    }
+
+    fn new(points: Vec<Coord<T>>) -> Self {
+        let mut builder = Builder::new(&points);
+        builder.triangulate();
+        let Builder {
+            triangles,
+            halfedges,
+            hull_next,
+            hull_start,
+            ..
+        } = builder;
+
+        let mut hull = vec![];
+        if !triangles.is_empty() {
+            let mut i = hull_start;
+            loop {
+                hull.push(i);
+                i = hull_next[i];
+                if i == hull_start {
+                    break;
+                }
+            }
+        }
+
+        let mut inedges = vec![None; points.len()];
+        for e in 0..triangles.len() {
+            let end = triangles[next_halfedge(e)];
+            if halfedges[e].is_none() || inedges[end].is_none() {
+                inedges[end] = Some(e);
+            }
+        }
+
+        DelaunayTriangulation {
+            points,
+            triangles,
+            halfedges,
+            hull,
+            inedges,
+        }
+    }
+}
+
+pub(crate) fn next_halfedge(e: usize) -> usize {
+    if e % 3 == 2 {
+        e - 2
+    } else {
+        e + 1
+    }
+}
+
+pub(crate) fn prev_halfedge(e: usize) -> usize {
+    if e % 3 == 0 {
+        e + 2
+    } else {
+        e - 1
+    }
+}
+
+/// The state of the sweep.
+struct Builder<'a, T: GeoFloat> {
+    points: &'a [Coord<T>],
+    triangles: Vec<usize>,
+    halfedges: Vec<Option<usize>>,
+    /// The hull, as a circular list of points in counter-clockwise order. Points which have
+    /// been removed from the hull point to themselves.
+    hull_next: Vec<usize>,
+    hull_prev: Vec<usize>,
+    /// The half-edge along the hull from each point on it.
+    hull_tri: Vec<usize>,
+    hull_start: usize,
+    /// The points on the hull, bucketed by their angle from the centre.
+    hull_hash: Vec<Option<usize>>,
+    center: Coord<T>,
+}
+
+impl<'a, T: GeoFloat> Builder<'a, T> {
+    fn new(points: &'a [Coord<T>]) -> Self {
+        let n = points.len();
+        let hash_size = (n as f64).sqrt().ceil() as usize;
+        Builder {
+            points,
+            triangles: Vec::with_capacity(6 * n),
+            halfedges: Vec::with_capacity(6 * n),
+            hull_next: vec![0; n],
+            hull_prev: vec![0; n],
+            hull_tri: vec![0; n],
+            hull_start: 0,
+            hull_hash: vec![None; hash_size.max(1)],
+            center: Coord::zero(),
+        }
+    }
+
+    fn triangulate(&mut self) {
+        let points = self.points;
+        let finite: Vec<_> = (0..points.len())
+            .filter(|&i| points[i].x.is_finite() && points[i].y.is_finite())
+            .collect();
+        let Some((i0, i1, i2)) = seed_triangle(points, &finite) else {
+            return;
+        };
+        let center = circumcenter(points[i0], points[i1], points[i2]);
+        self.center = center;
+
+        // Add the points in order of their distance from the seed triangle, so that each is
+        // outside the hull of the points before it.
+        let mut order = finite;
+        let dist = |i: usize| squared_distance(points[i], center);
+        order.sort_by(|&a, &b| {
+            dist(a)
+                .partial_cmp(&dist(b))
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| lex_cmp(&points[a], &points[b]))
+        });
+
+        self.hull_start = i0;
+        self.hull_next[i0] = i1;
+        self.hull_prev[i2] = i1;
+        self.hull_next[i1] = i2;
+        self.hull_prev[i0] = i2;
+        self.hull_next[i2] = i0;
+        self.hull_prev[i1] = i0;
+        self.hull_tri[i0] = 0;
+        self.hull_tri[i1] = 1;
+        self.hull_tri[i2] = 2;
+        for i in [i0, i1, i2] {
+            let key = self.hash_key(points[i]);
+            self.hull_hash[key] = Some(i);
+        }
+        self.add_triangle(i0, i1, i2, None, None, None);
+
+        let mut prev: Option<usize> = None;
+        for &i in &order {
+            let p = points[i];
+            if prev.map_or(false, |prev| points[prev] == p)
+                || [i0, i1, i2].iter().any(|&s| points[s] == p)
+            {
+                continue;
+            }
+            prev = Some(i);
+            self.add_point(i);
+        }
+    }
+
+    fn add_point(&mut self, i: usize) {
+        let points = self.points;
+        let p = points[i];
+        let is_visible = |a: usize, b: usize| {
+            T::Ker::orient2d(points[a], points[b], p) == Orientation::Clockwise
+        };
+
+        // Find a point on the hull near `p`, and then an edge of the hull visible from `p`.
+        let key = self.hash_key(p);
+        let len = self.hull_hash.len();
+        let mut start = 0;
+        for j in 0..len {
+            if let Some(s) = self.hull_hash[(key + j) % len] {
+                if s != self.hull_next[s] {
+                    start = s;
+                    break;
+                }
+            }
+        }
+        start = self.hull_prev[start];
+        let mut e = start;
+        while !is_visible(e, self.hull_next[e]) {
+            e = self.hull_next[e];
+            if e == start {
+                // The point is on the hull, which only happens if it is collinear with its
+                // neighbours and so can't form a triangle.
+                return;
+            }
+        }
+
+        // Add the first triangle from the point, and then walk forwards along the hull.
+        let t = self.add_triangle(e, i, self.hull_next[e], None, None, Some(self.hull_tri[e]));
+        self.hull_tri[i] = self.legalize(t + 2);
+        self.hull_tri[e] = t;
+
+        let mut n = self.hull_next[e];
+        loop {
+            let q = self.hull_next[n];
+            if !is_visible(n, q) {
+                break;
+            }
+            let t = self.add_triangle(
+                n,
+                i,
+                q,
+                Some(self.hull_tri[i]),
+                None,
+                Some(self.hull_tri[n]),
+            );
+            self.hull_tri[i] = self.legalize(t + 2);
+            self.hull_next[n] = n;
+            n = q;
+        }
+
+        // Walk backwards along the hull, if the first edge wasn't the first visible one.
+        if e == start {
+            loop {
+                let q = self.hull_prev[e];
+                if !is_visible(q, e) {
+                    break;
+                }
+                let t = self.add_triangle(
+                    q,
+                    i,
+                    e,
+                    None,
+                    Some(self.hull_tri[e]),
+                    Some(self.hull_tri[q]),
+                );
+                self.legalize(t + 2);
+                self.hull_tri[q] = t;
+                self.hull_next[e] = e;
+                e = q;
+            }
+        }
+
+        self.hull_start = e;
+        self.hull_prev[i] = e;
+        self.hull_next[e] = i;
+        self.hull_prev[n] = i;
+        self.hull_next[i] = n;
+
+        let key = self.hash_key(p);
+        self.hull_hash[key] = Some(i);
+        let key = self.hash_key(points[e]);
+        self.hull_hash[key] = Some(e);
+    }
+
+    /// Flips edges until the triangles around half-edge `a` satisfy the Delaunay condition.
+    ///
+    /// Returns the half-edge before `a` in its triangle, once no more flips are needed.
+    fn legalize(&mut self, mut a: usize) -> usize {
+        let mut stack = vec![];
+        loop {
+            let ar = prev_halfedge(a);
+            let Some(b) = self.halfedges[a] else {
+                match stack.pop() {
+                    Some(next) => {
+                        a = next;
+                        continue;
+                    }
+                    None => return ar,
+                }
+            };
+
+            // Triangle `a` is (pr, pl, p0), and triangle `b` is (pl, pr, p1). If `p1` is inside
+            // the circumcircle of `a`, the edge between them is flipped to join `p0` and `p1`.
+            let al = next_halfedge(a);
+            let bl = prev_halfedge(b);
+            let br = next_halfedge(b);
+            let p0 = self.triangles[ar];
+            let pr = self.triangles[a];
+            let pl = self.triangles[al];
+            let p1 = self.triangles[bl];
+
+            let points = self.points;
+            let is_illegal = T::Ker::incircle(points[pr], points[pl], points[p0], points[p1])
+                == Orientation::CounterClockwise;
+            if !is_illegal {
+                match stack.pop() {
+                    Some(next) => {
+                        a = next;
+                        continue;
+                    }
+                    None => return ar,
+                }
+            }
+
+            self.triangles[a] = p1;
+            self.triangles[b] = p0;
+            let hbl = self.halfedges[bl];
+            if hbl.is_none() {
+                // The edge was on the hull, so the hull needs to refer to its replacement.
+                let mut e = self.hull_start;
+                loop {
+                    if self.hull_tri[e] == bl {
+                        self.hull_tri[e] = a;
+                        break;
+                    }
+                    e = self.hull_prev[e];
+                    if e == self.hull_start {
+                        break;
+                    }
+                }
+            }
+            self.link(a, hbl);
+            self.link(b, self.halfedges[ar]);
+            self.link(ar, Some(bl));
+            stack.push(br);
+        }
+    }
+
+    fn add_triangle(
+        &mut self,
+        i0: usize,
+        i1: usize,
+        i2: usize,
+        a: Option<usize>,
+        b: Option<usize>,
+        c: Option<usize>,
+    ) -> usize {
+        let t = self.triangles.len();
+        self.triangles.extend([i0, i1, i2]);
+        self.halfedges.extend([None, None, None]);
+        self.link(t, a);
+        self.link(t + 1, b);
+        self.link(t + 2, c);
+        t
+    }
+
+    fn link(&mut self, a: usize, b: Option<usize>) {
+        self.halfedges[a] = b;
+        if let Some(b) = b {
+            self.halfedges[b] = Some(a);
+        }
+    }
+
+    /// The bucket of the hull hash for a point, by its pseudo-angle from the centre.
+    fn hash_key(&self, p: Coord<T>) -> usize {
+        let d = p - self.center;
+        let sum = d.x.abs() + d.y.abs();
+        let angle = if sum > T::zero() {
+            let q = d.x / sum;
+            // Increases monotonically with the angle, from 0 to 4.
+            if d.y > T::zero() {
+                T::from(3).unwrap() - q
+            } else {
+                T::one() + q
+            }
+        } else {
+            T::zero()
+        };
+        let len = self.hull_hash.len();
+        let key = (angle / T::from(4).unwrap() * T::from(len).unwrap())
+            .floor()
+            .to_usize()
+            .unwrap_or(0);
+        key % len
+    }
+}
+
+/// Chooses three points to start the triangulation from: the point nearest the middle, the
+/// point nearest that, and the point which makes the smallest circumcircle with them. The
+/// points are returned in counter-clockwise order.
+fn seed_triangle<T: GeoFloat>(
+    points: &[Coord<T>],
+    indices: &[usize],
+) -> Option<(usize, usize, usize)> {
+    let (first, rest) = indices.split_first()?;
+    let first = points[*first];
+    let (min, max) = rest
+        .iter()
+        .map(|&i| points[i])
+        .fold((first, first), |(min, max), p| {
+            (
+                Coord {
+                    x: min.x.min(p.x),
+                    y: min.y.min(p.y),
+                },
+                Coord {
+                    x: max.x.max(p.x),
+                    y: max.y.max(p.y),
+                },
+            )
+        });
+    let middle = (min + max) / T::from(2).unwrap();
+
+    let nearest = |from: Coord<T>, exclude: &[usize]| {
+        indices
+            .iter()
+            .copied()
+            .filter(|i| !exclude.contains(i) && points[*i] != from)
+            .min_by(|&a, &b| {
+                squared_distance(points[a], from)
+                    .partial_cmp(&squared_distance(points[b], from))
+                    .unwrap_or(Ordering::Equal)
+            })
+    };
+    let i0 = indices.iter().copied().min_by(|&a, &b| {
+        squared_distance(points[a], middle)
+            .partial_cmp(&squared_distance(points[b], middle))
+            .unwrap_or(Ordering::Equal)
+    })?;
+    let i1 = nearest(points[i0], &[i0])?;
+    let i2 = indices
+        .iter()
+        .copied()
+        .filter(|&i| T::Ker::orient2d(points[i0], points[i1], points[i]) != Orientation::Collinear)
+        .min_by(|&a, &b| {
+            circumradius(points[i0], points[i1], points[a])
+                .partial_cmp(&circumradius(points[i0], points[i1], points[b]))
+                .unwrap_or(Ordering::Equal)
+        })?;
+
+    match T::Ker::orient2d(points[i0], points[i1], points[i2]) {
+        Orientation::CounterClockwise => Some((i0, i1, i2)),
+        _ => Some((i0, i2, i1)),
+    }
+}
+
+/// The centre of the circle through three points.
+pub(crate) fn circumcenter<T: GeoFloat>(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> Coord<T> {
+    let (ab, ac) = (b - a, c - a);
+    let (bl, cl) = (ab.x * ab.x + ab.y * ab.y, ac.x * ac.x + ac.y * ac.y);
+    let d = (ab.x * ac.y - ab.y * ac.x) * T::from(2).unwrap();
+    Coord {
+        x: a.x + (ac.y * bl - ab.y * cl) / d,
+        y: a.y + (ab.x * cl - ac.x * bl) / d,
+    }
+}
+
+/// The squared radius of the circle through three points.
+fn circumradius<T: GeoFloat>(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> T {
+    squared_distance(circumcenter(a, b, c), a)
+}
+
+fn squared_distance<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    let d = a - b;
+    d.x * d.x + d.y * d.y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, Area};
+
+    /// Checks the half-edges are consistent, and no point is inside a triangle's circumcircle.
+    fn check<T: GeoFloat>(triangulation: &DelaunayTriangulation<T>) {
+        let points = triangulation.points();
+        for e in 0..triangulation.triangles.len() {
+            if let Some(opposite) = triangulation.opposite(e) {
+                assert_eq!(triangulation.opposite(opposite), Some(e));
+                assert_eq!(
+                    triangulation.origin(opposite),
+                    triangulation.origin(next_halfedge(e))
+                );
+            }
+        }
+        for t in 0..triangulation.len() {
+            let [a, b, c] = triangulation.vertices(t).map(|i| points[i]);
+            assert_eq!(T::Ker::orient2d(a, b, c), Orientation::CounterClockwise);
+            for p in points {
+                assert_ne!(T::Ker::incircle(a, b, c, *p), Orientation::CounterClockwise);
+            }
+        }
+    }
+
+    #[test]
+    fn incircle() {
+        let [a, b, c] = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 2., y: 0. },
+            coord! { x: 0., y: 2. },
+        ];
+        type Ker = <f64 as crate::HasKernel>::Ker;
+        assert_eq!(
+            Ker::incircle(a, b, c, coord! { x: 1., y: 1. }),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            Ker::incircle(a, b, c, coord! { x: 2., y: 2. }),
+            Orientation::Collinear
+        );
+        assert_eq!(
+            Ker::incircle(a, b, c, coord! { x: 3., y: 3. }),
+            Orientation::Clockwise
+        );
+    }
+
+    #[test]
+    fn grid() {
+        // Every point of a grid is co-circular with its neighbours.
+        let points: Vec<_> = (0..10)
+            .flat_map(|i| (0..10).map(move |j| coord! { x: i as f64, y: j as f64 }))
+            .collect();
+        let triangulation = points.delaunay_triangulation();
+        check(&triangulation);
+        assert_eq!(triangulation.len(), 2 * 9 * 9);
+        assert_eq!(triangulation.hull().len(), 36);
+        let area: f64 = triangulation.triangles().map(|t| t.unsigned_area()).sum();
+        assert_eq!(area, 81.);
+    }
+
+    #[test]
+    fn random_points() {
+        // A simple linear congruential generator, so the test is reproducible.
+        let mut state = 12345_u64;
+        let mut random = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (state >> 33) as f64 / (1_u64 << 31) as f64
+        };
+        let points: MultiPoint<f64> = (0..500)
+            .map(|_| (random() * 100., random() * 100.))
+            .collect::<Vec<_>>()
+            .into();
+        let triangulation = points.delaunay_triangulation();
+        check(&triangulation);
+
+        // Euler's formula for a triangulation of points with `h` on the hull.
+        let h = triangulation.hull().len();
+        assert_eq!(triangulation.len(), 2 * 500 - 2 - h);
+    }
+
+    #[test]
+    fn neighbors() {
+        let points = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 4., y: 0. },
+            coord! { x: 2., y: 3. },
+            coord! { x: 2., y: 1. },
+        ];
+        let triangulation = points.delaunay_triangulation();
+        check(&triangulation);
+        assert_eq!(triangulation.len(), 3);
+        for t in 0..3 {
+            let neighbors = triangulation.triangle_neighbors(t);
+            assert_eq!(neighbors.iter().flatten().count(), 2);
+        }
+        let mut neighbors = triangulation.point_neighbors(0);
+        neighbors.sort();
+        assert_eq!(neighbors, vec![1, 2, 3]);
+        assert_eq!(triangulation.hull().len(), 3);
+    }
+
+    #[test]
+    fn degenerate() {
+        let collinear = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 2., y: 2. },
+        ];
+        assert!(collinear.delaunay_triangulation().is_empty());
+        assert!(collinear[..1].delaunay_triangulation().is_empty());
+
+        let duplicates = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 0. },
+            coord! { x: 0., y: 1. },
+            coord! { x: 1., y: 0. },
+            coord! { x: 0., y: 0. },
+        ];
+        let triangulation = duplicates.delaunay_triangulation();
+        check(&triangulation);
+        assert_eq!(triangulation.len(), 1);
+    }
+
+    #[test]
+    fn non_finite_points() {
+        let points: MultiPoint<f64> = vec![
+            (0., 0.),
+            (f64::NAN, 1.),
+            (4., 0.),
+            (2., f64::INFINITY),
+            (2., 3.),
+        ]
+        .into();
+        let triangulation = points.delaunay_triangulation();
+        assert_eq!(triangulation.len(), 1);
+        assert_eq!(
+            triangulation.triangles().next().unwrap().unsigned_area(),
+            6.
+        );
+        assert!(triangulation.point_neighbors(1).is_empty());
+        assert!(triangulation.point_neighbors(3).is_empty());
+    }
+}
@@ -0,0 +1,246 @@
+use std::cmp::Ordering;
+
+use crate::kernels::{Kernel, Orientation};
+use crate::monotone::{monotone_subdivision, MonoPoly, MonotonicPolygons};
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoNum, MultiPolygon, Polygon, Triangle};
+
+/// Triangulate polygons by splitting them into monotone pieces.
+///
+/// The polygons are first split into [`MonoPoly`]s with [`monotone_subdivision`], in
+/// `O(n log n)` time. Each piece is then triangulated in linear time, by sweeping along its
+/// top and bottom chains and joining each vertex to the earlier vertices it can see. Holes are
+/// handled by the subdivision, so unlike `TriangulateEarcut` no optional
+/// dependency is needed.
+///
+/// The polygons must be valid, as required by [`monotone_subdivision`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, TriangulateMonotone};
+///
+/// let polygon = polygon!(
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+/// );
+///
+/// let triangles = polygon.monotone_triangles();
+/// assert_eq!(triangles.len(), 8);
+/// assert_eq!(triangles.iter().map(|t| t.unsigned_area()).sum::<f64>(), 96.);
+/// ```
+pub trait TriangulateMonotone {
+    type Scalar: GeoNum;
+
+    /// Returns the triangles, with their vertices in counter-clockwise order.
+    fn monotone_triangles(&self) -> Vec<Triangle<Self::Scalar>>;
+}
+
+impl<T: GeoNum> TriangulateMonotone for Polygon<T> {
+    type Scalar = T;
+
+    fn monotone_triangles(&self) -> Vec<Triangle<T>> {
+        monotone_subdivision([self.clone()])
+            .iter()
+            .flat_map(triangulate)
+            .collect()
+    }
+}
+
+impl<T: GeoNum> TriangulateMonotone for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn monotone_triangles(&self) -> Vec<Triangle<T>> {
+        monotone_subdivision(self.0.iter().cloned())
+            .iter()
+            .flat_map(triangulate)
+            .collect()
+    }
+}
+
+impl<T: GeoNum> TriangulateMonotone for MonoPoly<T> {
+    type Scalar = T;
+
+    fn monotone_triangles(&self) -> Vec<Triangle<T>> {
+        triangulate(self)
+    }
+}
+
+impl<T: GeoNum> TriangulateMonotone for MonotonicPolygons<T> {
+    type Scalar = T;
+
+    fn monotone_triangles(&self) -> Vec<Triangle<T>> {
+        self.subdivisions().iter().flat_map(triangulate).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Chain {
+    Top,
+    Bottom,
+}
+
+/// Triangulates a monotone polygon.
+fn triangulate<T: GeoNum>(poly: &MonoPoly<T>) -> Vec<Triangle<T>> {
+    let (top, bot) = (&poly.top().0, &poly.bot().0);
+    let (first, last) = (top[0], top[top.len() - 1]);
+
+    // Merge the chains, in the order of the sweep.
+    let mut vertices = Vec::with_capacity(top.len() + bot.len() - 2);
+    let mut top_iter = top[1..top.len() - 1].iter().peekable();
+    let mut bot_iter = bot[1..bot.len() - 1].iter().peekable();
+    loop {
+        let chain = match (top_iter.peek(), bot_iter.peek()) {
+            (Some(t), Some(b)) if lex_cmp(t, b) == Ordering::Greater => Chain::Bottom,
+            (Some(_), _) => Chain::Top,
+            (None, Some(_)) => Chain::Bottom,
+            (None, None) => break,
+        };
+        let vertex = match chain {
+            Chain::Top => top_iter.next(),
+            Chain::Bottom => bot_iter.next(),
+        };
+        vertices.push((*vertex.unwrap(), chain));
+    }
+
+    let mut triangles = Vec::with_capacity(vertices.len() + 1);
+    let mut emit = |a: Coord<T>, b: Coord<T>, c: Coord<T>| match T::Ker::orient2d(a, b, c) {
+        Orientation::CounterClockwise => triangles.push(Triangle::new(a, b, c)),
+        Orientation::Clockwise => triangles.push(Triangle::new(a, c, b)),
+        Orientation::Collinear => {}
+    };
+
+    // The vertices which still need joining to later ones, which form a reflex chain.
+    let mut stack = vec![(first, None)];
+    for (idx, &(vertex, chain)) in vertices.iter().enumerate() {
+        if idx == 0 {
+            stack.push((vertex, Some(chain)));
+            continue;
+        }
+        let (_, top_chain) = stack[stack.len() - 1];
+        if top_chain != Some(chain) {
+            // The vertex can see the whole of the chain on the other side.
+            for pair in stack.windows(2) {
+                emit(vertex, pair[0].0, pair[1].0);
+            }
+            let (previous, previous_chain) = vertices[idx - 1];
+            stack = vec![(previous, Some(previous_chain)), (vertex, Some(chain))];
+        } else {
+            // Join the vertex to the earlier vertices on its own chain, as far as it can see.
+            let mut previous = stack.pop().unwrap();
+            while let Some(&(earlier, _)) = stack.last() {
+                let visible = matches!(
+                    (chain, T::Ker::orient2d(earlier, previous.0, vertex)),
+                    (Chain::Top, Orientation::Clockwise)
+                        | (Chain::Bottom, Orientation::CounterClockwise)
+                );
+                if !visible {
+                    break;
+                }
+                emit(earlier, previous.0, vertex);
+                previous = stack.pop().unwrap();
+            }
+            stack.push(previous);
+            stack.push((vertex, Some(chain)));
+        }
+    }
+    for pair in stack.windows(2) {
+        emit(last, pair[0].0, pair[1].0);
+    }
+    triangles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{polygon, Area, Contains, Point};
+    use approx::assert_relative_eq;
+    use wkt::TryFromWkt;
+
+    /// Checks the triangles are counter-clockwise, inside the polygon and cover it, and returns
+    /// them.
+    fn check(polygon: &Polygon) -> Vec<Triangle> {
+        let triangles = polygon.monotone_triangles();
+        for triangle in &triangles {
+            let [a, b, c] = triangle.to_array();
+            assert_eq!(
+                <f64 as crate::HasKernel>::Ker::orient2d(a, b, c),
+                Orientation::CounterClockwise
+            );
+            assert!(polygon.contains(&Point::from((a + b + c) / 3.)));
+        }
+        assert_relative_eq!(
+            triangles.iter().map(|t| t.unsigned_area()).sum::<f64>(),
+            polygon.unsigned_area(),
+            max_relative = 1e-9
+        );
+        triangles
+    }
+
+    #[test]
+    fn simple() {
+        let triangles =
+            check(&polygon![(x: 0., y: 0.), (x: 5., y: 5.), (x: 3., y: 0.), (x: 5., y: -5.)]);
+        assert_eq!(triangles.len(), 2);
+        // Vertical edges, where points of the chains share an x coordinate.
+        let triangles = check(
+            &polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 2., y: 2.), (x: 0., y: 4.)],
+        );
+        assert_eq!(triangles.len(), 3);
+    }
+
+    #[test]
+    fn reflex_chains() {
+        let polygon = Polygon::try_from_wkt_str(
+            "POLYGON((0 0,11.9 1,5.1 2,6.6 3,13.3 4,20.4 5,11.5 6,1.3 7,19.4 8,15.4 9,2.8 10,
+            7.0 11,13.7 12,24.0 13,2.6 14,9.6 15,0.2 16,250 16,67.1 15,66.1 14,61.2 13,76.4 12,
+            75.1 11,88.3 10,75.3 9,63.8 8,84.2 7,77.5 6,95.9 5,83.8 4,86.9 3,64.5 2,68.3 1,
+            99.6 0,0 0))",
+        )
+        .unwrap();
+        assert_eq!(check(&polygon).len(), 32);
+    }
+
+    #[test]
+    fn holes() {
+        let polygon = Polygon::try_from_wkt_str(
+            "POLYGON ((60 60, 60 200, 240 200, 240 60, 60 60),
+            (60 140, 110 170, 110 100, 80 100, 60 140),
+            (150 150, 200 150, 200 100, 150 150))",
+        )
+        .unwrap();
+        // The first hole touches the exterior, so it only adds one triangle.
+        assert_eq!(check(&polygon).len(), 12);
+    }
+
+    #[test]
+    fn fixtures() {
+        check(&Polygon::new(geo_test_fixtures::norway_main(), vec![]));
+        check(&geo_test_fixtures::east_baton_rouge());
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi = MultiPolygon::new(vec![
+            geo_test_fixtures::east_baton_rouge(),
+            polygon![(x: 0., y: 0.), (x: 5., y: 5.), (x: 3., y: 0.), (x: 5., y: -5.)],
+        ]);
+        let triangles = multi.monotone_triangles();
+        assert_relative_eq!(
+            triangles.iter().map(|t| t.unsigned_area()).sum::<f64>(),
+            multi.unsigned_area(),
+            max_relative = 1e-9
+        );
+        assert_eq!(
+            MonotonicPolygons::from(multi).monotone_triangles(),
+            triangles
+        );
+    }
+
+    #[test]
+    fn integers() {
+        let polygon: Polygon<i64> =
+            polygon![(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 2, y: 1), (x: 0, y: 4)];
+        assert_eq!(polygon.monotone_triangles().len(), 3);
+    }
+}
@@ -0,0 +1,245 @@
+use crate::utils::lex_cmp;
+use crate::{
+    BooleanOps, BoundingRect, Coord, GeoFloat, LineString, MultiPoint, Polygon, Rect,
+    TriangulateDelaunay,
+};
+
+/// Divide the plane into the regions closest to each of a set of points.
+///
+/// The Voronoi cell of a point, also known as its Thiessen polygon, contains every location
+/// which is at least as close to it as to any other point. The cells are found from the
+/// neighbours of each point in the [Delaunay triangulation](TriangulateDelaunay), so they share
+/// its robust predicates.
+///
+/// The cells of points on the outside of the set are unbounded, so the cells are clipped to
+/// the given bounds. Cells which lie entirely outside the bounds are left out. Where a point
+/// is repeated, the cell is only given for its first occurrence.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, Area, MultiPoint, Rect, Voronoi};
+///
+/// let points: MultiPoint = vec![(1., 1.), (3., 1.), (2., 3.)].into();
+/// let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+///
+/// let cells = points.voronoi_cells(&bounds);
+/// assert_eq!(cells.len(), 3);
+/// assert_eq!(cells[0].point, 0);
+/// assert_eq!(cells.iter().map(|c| c.polygon.unsigned_area()).sum::<f64>(), 16.);
+/// ```
+pub trait Voronoi {
+    type Scalar: GeoFloat;
+
+    /// Returns the Voronoi cells of the points, clipped to `bounds`.
+    fn voronoi_cells(&self, bounds: &Rect<Self::Scalar>) -> Vec<VoronoiCell<Self::Scalar>>;
+
+    /// Returns the Voronoi cells of the points, clipped to `clip`.
+    ///
+    /// If `clip` isn't convex, it may split a cell into several parts. Each part is returned
+    /// as a separate `VoronoiCell`, for the same point.
+    fn voronoi_cells_clipped(&self, clip: &Polygon<Self::Scalar>)
+        -> Vec<VoronoiCell<Self::Scalar>>;
+}
+
+/// The Voronoi cell of one of the points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoronoiCell<T: GeoFloat> {
+    /// The index of the point in the input.
+    pub point: usize,
+    pub polygon: Polygon<T>,
+}
+
+impl<T: GeoFloat> Voronoi for MultiPoint<T> {
+    type Scalar = T;
+
+    fn voronoi_cells(&self, bounds: &Rect<T>) -> Vec<VoronoiCell<T>> {
+        let bounds = bounds.to_polygon();
+        cells(self, &bounds)
+            .map(|(point, ring)| VoronoiCell {
+                point,
+                polygon: Polygon::new(LineString::new(ring), vec![]),
+            })
+            .collect()
+    }
+
+    fn voronoi_cells_clipped(&self, clip: &Polygon<T>) -> Vec<VoronoiCell<T>> {
+        let Some(bounds) = clip.bounding_rect() else {
+            return vec![];
+        };
+        cells(self, &bounds.to_polygon())
+            .flat_map(|(point, ring)| {
+                Polygon::new(LineString::new(ring), vec![])
+                    .intersection(clip)
+                    .into_iter()
+                    .map(move |polygon| VoronoiCell { point, polygon })
+            })
+            .collect()
+    }
+}
+
+/// The cells of the points within the convex `bounds`, as the coordinates of their rings.
+fn cells<'a, T: GeoFloat>(
+    points: &'a MultiPoint<T>,
+    bounds: &'a Polygon<T>,
+) -> impl Iterator<Item = (usize, Vec<Coord<T>>)> + 'a {
+    let coords: Vec<_> = points.iter().map(|p| p.0).collect();
+    let neighbors = neighbors(&coords);
+    neighbors
+        .into_iter()
+        .enumerate()
+        .filter_map(move |(i, neighbors)| {
+            let neighbors = neighbors?;
+            let mut ring = bounds.exterior().0.clone();
+            ring.pop();
+            for j in neighbors {
+                ring = clip_to_half_plane(&ring, coords[i], coords[j]);
+            }
+            if ring.len() < 3 {
+                return None;
+            }
+            ring.push(ring[0]);
+            Some((i, ring))
+        })
+}
+
+/// The points whose cells share an edge with the cell of each point, or `None` for repeated
+/// points.
+fn neighbors<T: GeoFloat>(coords: &[Coord<T>]) -> Vec<Option<Vec<usize>>> {
+    let mut order: Vec<_> = (0..coords.len()).collect();
+    order.sort_by(|&a, &b| lex_cmp(&coords[a], &coords[b]).then(a.cmp(&b)));
+    // The repeats of each point, starting with its first occurrence.
+    let mut groups: Vec<Vec<usize>> = vec![];
+    for i in order {
+        match groups.last_mut() {
+            Some(group) if coords[group[0]] == coords[i] => group.push(i),
+            _ => groups.push(vec![i]),
+        }
+    }
+
+    let triangulation = coords.delaunay_triangulation();
+    let mut neighbors = vec![None; coords.len()];
+    if triangulation.is_empty() {
+        // The points are collinear, so they are in order along their line.
+        for (k, group) in groups.iter().enumerate() {
+            let adjacent = [k.checked_sub(1), Some(k + 1)];
+            neighbors[group[0]] = Some(
+                adjacent
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|k| groups.get(k).map(|g| g[0]))
+                    .collect(),
+            );
+        }
+    } else {
+        // Only one of the repeats of a point is part of the triangulation.
+        for group in groups {
+            let adjacent = group
+                .iter()
+                .flat_map(|&i| triangulation.point_neighbors(i))
+                .collect();
+            neighbors[group[0]] = Some(adjacent);
+        }
+    }
+    neighbors
+}
+
+/// Clips a convex ring to the side of the perpendicular bisector of `p` and `q` nearest `p`.
+fn clip_to_half_plane<T: GeoFloat>(ring: &[Coord<T>], p: Coord<T>, q: Coord<T>) -> Vec<Coord<T>> {
+    let middle = (p + q) / T::from(2).unwrap();
+    let normal = q - p;
+    let side = |c: Coord<T>| (c.x - middle.x) * normal.x + (c.y - middle.y) * normal.y;
+
+    let mut clipped = vec![];
+    for (idx, &a) in ring.iter().enumerate() {
+        let b = ring[(idx + 1) % ring.len()];
+        let (side_a, side_b) = (side(a), side(b));
+        if side_a <= T::zero() {
+            clipped.push(a);
+        }
+        if (side_a < T::zero() && side_b > T::zero()) || (side_a > T::zero() && side_b < T::zero())
+        {
+            clipped.push(a + (b - a) * (side_a / (side_a - side_b)));
+        }
+    }
+    clipped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, polygon, Area, Contains, EuclideanDistance, Point};
+    use approx::assert_relative_eq;
+
+    fn bounds() -> Rect {
+        Rect::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 10. })
+    }
+
+    #[test]
+    fn cells_are_nearest() {
+        let points: MultiPoint = vec![
+            (1., 1.),
+            (8., 2.),
+            (5., 5.),
+            (2., 7.),
+            (9., 9.),
+            (5., 1.),
+            (5., 1.),
+        ]
+        .into();
+        let cells = points.voronoi_cells(&bounds());
+        // The repeated point only has one cell.
+        assert_eq!(cells.len(), 6);
+        assert_relative_eq!(
+            cells.iter().map(|c| c.polygon.unsigned_area()).sum::<f64>(),
+            100.
+        );
+
+        for cell in &cells {
+            let point = points.0[cell.point];
+            for vertex in cell.polygon.exterior().points() {
+                let distance = vertex.euclidean_distance(&point);
+                for other in &points {
+                    assert!(distance <= vertex.euclidean_distance(other) + 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn collinear() {
+        let points: MultiPoint = vec![(1., 5.), (3., 5.), (9., 5.)].into();
+        let cells = points.voronoi_cells(&bounds());
+        let areas: Vec<_> = cells.iter().map(|c| c.polygon.unsigned_area()).collect();
+        assert_eq!(areas, vec![20., 40., 40.]);
+
+        let single: MultiPoint = vec![(1., 5.)].into();
+        assert_eq!(
+            single.voronoi_cells(&bounds())[0].polygon.unsigned_area(),
+            100.
+        );
+    }
+
+    #[test]
+    fn clipped_to_polygon() {
+        // An L shape, which splits the cell of the point in the corner.
+        let clip = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        let points: MultiPoint = vec![(1., 1.), (9., 9.)].into();
+        let cells = points.voronoi_cells_clipped(&clip);
+        assert_eq!(cells.len(), 3);
+        assert_eq!(cells.iter().filter(|c| c.point == 0).count(), 1);
+        assert_eq!(cells.iter().filter(|c| c.point == 1).count(), 2);
+        assert_eq!(
+            cells.iter().map(|c| c.polygon.unsigned_area()).sum::<f64>(),
+            clip.unsigned_area()
+        );
+        assert!(cells[0].polygon.contains(&Point::new(1., 1.)));
+    }
+}
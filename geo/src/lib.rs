@@ -40,13 +40,21 @@
 //! ## Boolean Operations
 //!
 //! - **[`BooleanOps`](BooleanOps)**: combine or split (Multi)Polygons using intersecton, union, xor, or difference operations
+//! - **[`UnaryUnion`](UnaryUnion)**: Efficiently union any number of polygons together
+//! - **[`LineBooleanOps`](LineBooleanOps)**: combine or split (Multi)LineStrings using intersection, union, xor, or difference operations
+//! - **[`Overlay`](Overlay)**: combine or split geometries of any dimension, returning a GeometryCollection
+//! - **[`Buffer`](Buffer)**: Grow or shrink a geometry by a distance, with configurable joins and end caps
+//! - **[`OffsetCurve`](OffsetCurve)**: Calculate the curve parallel to a line at a distance to one side of it
 //!
 //! ## Distance
 //!
+//! - **[`DistanceIndex`](DistanceIndex)**: Index the segments of a geometry for fast distance, nearest points and is-within-distance queries against other large geometries
 //! - **[`EuclideanDistance`](EuclideanDistance)**: Calculate the minimum euclidean distance between geometries
 //! - **[`GeodesicDistance`](GeodesicDistance)**: Calculate the minimum geodesic distance between geometries using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
 //! - **[`HausdorffDistance`](HausdorffDistance)**: Calculate "the maximum of the distances from a point in any of the sets to the nearest point in the other set." (Rote, 1991)
 //! - **[`HaversineDistance`](HaversineDistance)**: Calculate the minimum geodesic distance between geometries using the haversine formula
+//! - **[`IsWithinDistance`](IsWithinDistance)**: Determine whether two geometries are within a euclidean distance of each other, stopping at the first pair of points or segments within it. See also [`IsWithinHaversineDistance`] and [`IsWithinGeodesicDistance`]
+//! - **[`NearestPoints`](NearestPoints)**: Find the nearest points of two geometries, and the components and segments they lie on
 //! - **[`VincentyDistance`](VincentyDistance)**: Calculate the minimum geodesic distance between geometries using Vincenty’s formula
 //!
 //! ## Length
@@ -90,22 +98,48 @@
 //!
 //! ## Topology
 //!
+//! - **[`Boundary`](Boundary)**: Calculate the combinatorial boundary of a geometry, per the
+//!   OGC Simple Features rules
 //! - **[`Contains`](Contains)**: Calculate if a geometry contains another
 //!   geometry
 //! - **[`CoordinatePosition`](CoordinatePosition)**: Calculate
 //!   the position of a coordinate relative to a geometry
+//! - **[`CoveredBy`](CoveredBy)**: Calculate if a geometry is covered by another geometry
+//! - **[`Covers`](Covers)**: Calculate if a geometry covers another geometry, including
+//!   geometries on its boundary
+//! - **[`Crosses`](Crosses)**: Calculate if a geometry crosses another geometry
+//! - **[`EqualsTopo`](EqualsTopo)**: Calculate if two geometries are topologically equal
 //! - **[`HasDimensions`](HasDimensions)**: Determine the dimensions of a geometry
 //! - **[`Intersects`](Intersects)**: Calculate if a geometry intersects
 //!   another geometry
 //! - **[`line_intersection`](line_intersection::line_intersection)**: Calculates the
 //!   intersection, if any, between two lines.
+//! - **[`Overlaps`](Overlaps)**: Calculate if two geometries overlap
 //! - **[`Relate`](Relate)**: Topologically relate two geometries based on
 //!   [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) semantics.
+//! - **[`PreparedGeometry`](PreparedGeometry)**: Cache a geometry's topology graph and
+//!   segment index to relate it to many other geometries
+//! - **[`BoundaryNodeRule`](BoundaryNodeRule)**: Choose which endpoints of lines are on
+//!   their boundary when relating geometries
+//! - **[`Touches`](Touches)**: Calculate if two geometries touch without their interiors
+//!   intersecting
 //! - **[`Within`]**: Calculate if a geometry lies completely within another geometry.
 //!
+//! ## Validation
+//!
+//! - **[`IsValid`](IsValid)**: Determine whether a geometry is valid according to the OGC
+//!   Simple Features rules, and describe why it isn't
+//! - **[`MakeValid`](MakeValid)**: Repair invalid polygons and multi-polygons
+//! - **[`MinimumClearance`](MinimumClearance)**: Calculate how far a vertex can move before a
+//!   geometry becomes invalid
+//!
 //! ## Triangulation
 //!
 //! - **[`TriangulateEarcut`](triangulate_earcut)**: Triangulate polygons using the earcut algorithm (requires the `earcutr` feature).
+//! - **[`TriangulateDelaunay`](TriangulateDelaunay)**: Triangulate a set of points with a Delaunay triangulation
+//! - **[`TriangulateMonotone`](TriangulateMonotone)**: Triangulate polygons, including holes, by splitting them into monotone pieces
+//! - **[`TriangulateConstrained`](TriangulateConstrained)**: Triangulate polygons with holes with a constrained Delaunay triangulation, optionally refined to a minimum angle
+//! - **[`Voronoi`](Voronoi)**: Calculate the Voronoi cells of a set of points, clipped to a boundary
 //!
 //! ## Winding
 //!
@@ -127,6 +161,12 @@
 //!   bounding rectangle of a geometry
 //! - **[`MinimumRotatedRect`](MinimumRotatedRect)**: Calculate the
 //!   minimum bounding box of a geometry
+//! - **[`MinimumBoundingCircle`](MinimumBoundingCircle)**: Calculate the
+//!   smallest circle enclosing a geometry
+//! - **[`MaximumInscribedCircle`](MaximumInscribedCircle)**: Calculate the
+//!   largest circle inside a polygon
+//! - **[`LargestEmptyCircle`](LargestEmptyCircle)**: Calculate the largest
+//!   circle which misses a set of obstacles, with its centre in a boundary
 //! - **[`ConcaveHull`](ConcaveHull)**: Calculate the concave hull of a
 //!   geometry
 //! - **[`ConvexHull`](ConvexHull)**: Calculate the convex hull of a
@@ -162,6 +202,10 @@
 //! - **[`LineStringSegmentize`](LineStringSegmentize)**: Segment a LineString into `n` segments.
 //! - **[`Transform`](Transform)**: Transform a geometry using Proj.
 //! - **[`RemoveRepeatedPoints`](RemoveRepeatedPoints)**: Remove repeated points from a geometry.
+//! - **[`Polygonize`](Polygonize)**: Form the polygons enclosed by a set of noded lines
+//! - **[`Node`](Node)**: Split lines at every point where they cross or touch
+//! - **[`LineMerge`](LineMerge)**: Merge lines which meet end to end into maximal LineStrings
+//! - **[`PoleOfInaccessibility`](PoleOfInaccessibility)**: Find the point of a polygon farthest from its boundary, for placing labels
 //!
 //! # Features
 //!
@@ -1,6 +1,7 @@
 use geo::bool_ops::OpType as BoolOp;
+use geo::buffer::{BufferStyle, JoinStyle};
 use geo::relate::IntersectionMatrix;
-use geo::{Geometry, Point};
+use geo::{Geometry, Line, Point};
 use serde::{Deserialize, Deserializer};
 
 use super::Result;
@@ -64,6 +65,25 @@ pub(crate) struct Test {
     pub(crate) operation_input: OperationInput,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct BoundaryInput {
+    pub(crate) arg1: String,
+
+    #[serde(rename = "$value", deserialize_with = "wkt::deserialize_wkt")]
+    pub(crate) expected: geo::Geometry<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BufferInput {
+    pub(crate) arg1: String,
+
+    #[serde(rename = "arg2", deserialize_with = "deserialize_from_str")]
+    pub(crate) distance: f64,
+
+    #[serde(rename = "$value", deserialize_with = "wkt::deserialize_wkt")]
+    pub(crate) expected: geo::Geometry<f64>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CentroidInput {
     pub(crate) arg1: String,
@@ -107,6 +127,15 @@ pub struct ContainsInput {
     pub(crate) expected: bool,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct PredicateInput {
+    pub(crate) arg1: String,
+    pub(crate) arg2: String,
+
+    #[serde(rename = "$value", deserialize_with = "deserialize_from_str")]
+    pub(crate) expected: bool,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct WithinInput {
     pub(crate) arg1: String,
@@ -116,6 +145,38 @@ pub struct WithinInput {
     pub(crate) expected: bool,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct IsValidInput {
+    pub(crate) arg1: String,
+
+    #[serde(rename = "$value", deserialize_with = "deserialize_from_str")]
+    pub(crate) expected: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MinimumClearanceInput {
+    pub(crate) arg1: String,
+
+    #[serde(rename = "$value", deserialize_with = "deserialize_from_str")]
+    pub(crate) expected: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MinimumClearanceLineInput {
+    pub(crate) arg1: String,
+
+    #[serde(rename = "$value", deserialize_with = "wkt::deserialize_wkt")]
+    pub(crate) expected: geo::Geometry<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PolygonizeInput {
+    pub(crate) arg1: String,
+
+    #[serde(rename = "$value", deserialize_with = "wkt::deserialize_wkt")]
+    pub(crate) expected: geo::Geometry<f64>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct OverlayInput {
     pub(crate) arg1: String,
@@ -128,18 +189,54 @@ pub struct OverlayInput {
 #[derive(Debug, Deserialize)]
 #[serde(tag = "name")]
 pub(crate) enum OperationInput {
+    #[serde(rename = "getboundary")]
+    BoundaryInput(BoundaryInput),
+
+    #[serde(rename = "buffer")]
+    BufferInput(BufferInput),
+
+    #[serde(rename = "bufferMitredJoin")]
+    BufferMitredJoinInput(BufferInput),
+
     #[serde(rename = "contains")]
     ContainsInput(ContainsInput),
 
     #[serde(rename = "getCentroid")]
     CentroidInput(CentroidInput),
 
+    #[serde(rename = "coveredBy")]
+    CoveredByInput(PredicateInput),
+
+    #[serde(rename = "covers")]
+    CoversInput(PredicateInput),
+
+    #[serde(rename = "crosses")]
+    CrossesInput(PredicateInput),
+
+    #[serde(rename = "equalsTopo")]
+    EqualsTopoInput(PredicateInput),
+
     #[serde(rename = "convexhull")]
     ConvexHullInput(ConvexHullInput),
 
     #[serde(rename = "intersects")]
     IntersectsInput(IntersectsInput),
 
+    #[serde(rename = "isValid")]
+    IsValidInput(IsValidInput),
+
+    #[serde(rename = "minClearance")]
+    MinimumClearanceInput(MinimumClearanceInput),
+
+    #[serde(rename = "minClearanceLine")]
+    MinimumClearanceLineInput(MinimumClearanceLineInput),
+
+    #[serde(rename = "overlaps")]
+    OverlapsInput(PredicateInput),
+
+    #[serde(rename = "polygonize")]
+    PolygonizeInput(PolygonizeInput),
+
     #[serde(rename = "relate")]
     RelateInput(RelateInput),
 
@@ -155,6 +252,9 @@ pub(crate) enum OperationInput {
     #[serde(rename = "symdifference")]
     SymDifferenceInput(OverlayInput),
 
+    #[serde(rename = "touches")]
+    TouchesInput(PredicateInput),
+
     #[serde(rename = "within")]
     WithinInput(WithinInput),
 
@@ -162,8 +262,29 @@ pub(crate) enum OperationInput {
     Unsupported,
 }
 
+/// A named DE-9IM predicate.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Predicate {
+    CoveredBy,
+    Covers,
+    Crosses,
+    EqualsTopo,
+    Overlaps,
+    Touches,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum Operation {
+    Boundary {
+        subject: Geometry,
+        expected: Geometry,
+    },
+    Buffer {
+        subject: Geometry<f64>,
+        distance: f64,
+        style: BufferStyle<f64>,
+        expected: Geometry<f64>,
+    },
     Centroid {
         subject: Geometry,
         expected: Option<Point>,
@@ -187,6 +308,28 @@ pub(crate) enum Operation {
         clip: Geometry,
         expected: bool,
     },
+    IsValid {
+        subject: Geometry,
+        expected: bool,
+    },
+    MinimumClearance {
+        subject: Geometry,
+        expected: Option<f64>,
+    },
+    MinimumClearanceLine {
+        subject: Geometry,
+        expected: Option<Line>,
+    },
+    Polygonize {
+        subject: Geometry,
+        expected: Geometry,
+    },
+    Predicate {
+        subject: Geometry,
+        target: Geometry,
+        predicate: Predicate,
+        expected: bool,
+    },
     Relate {
         a: Geometry,
         b: Geometry,
@@ -204,6 +347,31 @@ impl OperationInput {
     pub(crate) fn into_operation(self, case: &Case) -> Result<Operation> {
         let geometry = &case.a;
         match self {
+            Self::BoundaryInput(input) => {
+                assert_eq!("A", input.arg1);
+                Ok(Operation::Boundary {
+                    subject: geometry.clone(),
+                    expected: input.expected,
+                })
+            }
+            Self::BufferInput(input) => {
+                assert_eq!("A", input.arg1);
+                Ok(Operation::Buffer {
+                    subject: geometry.clone(),
+                    distance: input.distance,
+                    style: BufferStyle::new(),
+                    expected: input.expected,
+                })
+            }
+            Self::BufferMitredJoinInput(input) => {
+                assert_eq!("A", input.arg1);
+                Ok(Operation::Buffer {
+                    subject: geometry.clone(),
+                    distance: input.distance,
+                    style: BufferStyle::new().join_style(JoinStyle::Mitre),
+                    expected: input.expected,
+                })
+            }
             Self::CentroidInput(centroid_input) => {
                 assert_eq!("A", centroid_input.arg1);
                 Ok(Operation::Centroid {
@@ -231,6 +399,44 @@ impl OperationInput {
                     expected: input.expected,
                 })
             }
+            Self::IsValidInput(input) => {
+                assert_eq!("A", input.arg1);
+                Ok(Operation::IsValid {
+                    subject: geometry.clone(),
+                    expected: input.expected,
+                })
+            }
+            Self::MinimumClearanceInput(input) => {
+                assert_eq!("A", input.arg1);
+                Ok(Operation::MinimumClearance {
+                    subject: geometry.clone(),
+                    // JTS uses the largest double when there's no clearance.
+                    expected: (input.expected != f64::MAX).then_some(input.expected),
+                })
+            }
+            Self::MinimumClearanceLineInput(input) => {
+                assert_eq!("A", input.arg1);
+                let expected = match input.expected {
+                    Geometry::LineString(ls) if ls.0.is_empty() => None,
+                    Geometry::LineString(ls) if ls.0.len() == 2 => Some(Line::new(ls[0], ls[1])),
+                    other => {
+                        return Err(
+                            format!("expected a line for minimum clearance: {other:?}").into()
+                        )
+                    }
+                };
+                Ok(Operation::MinimumClearanceLine {
+                    subject: geometry.clone(),
+                    expected,
+                })
+            }
+            Self::PolygonizeInput(input) => {
+                assert!(input.arg1.eq_ignore_ascii_case("A"));
+                Ok(Operation::Polygonize {
+                    subject: geometry.clone(),
+                    expected: input.expected,
+                })
+            }
             Self::RelateInput(input) => {
                 assert_eq!("A", input.arg1);
                 assert_eq!("B", input.arg2);
@@ -253,6 +459,12 @@ impl OperationInput {
                     expected: input.expected,
                 })
             }
+            Self::CoveredByInput(input) => input.into_operation(case, Predicate::CoveredBy),
+            Self::CoversInput(input) => input.into_operation(case, Predicate::Covers),
+            Self::CrossesInput(input) => input.into_operation(case, Predicate::Crosses),
+            Self::EqualsTopoInput(input) => input.into_operation(case, Predicate::EqualsTopo),
+            Self::OverlapsInput(input) => input.into_operation(case, Predicate::Overlaps),
+            Self::TouchesInput(input) => input.into_operation(case, Predicate::Touches),
             Self::WithinInput(input) => {
                 assert_eq!("A", input.arg1);
                 assert_eq!("B", input.arg2);
@@ -263,12 +475,7 @@ impl OperationInput {
                 })
             }
             Self::UnionInput(input) => {
-                validate_boolean_op(
-                    &input.arg1,
-                    &input.arg2,
-                    geometry,
-                    case.b.as_ref().expect("no geometry b in case"),
-                )?;
+                validate_boolean_op(&input.arg1, &input.arg2)?;
                 Ok(Operation::BooleanOp {
                     a: geometry.clone(),
                     b: case.b.clone().expect("no geometry b in case"),
@@ -277,12 +484,7 @@ impl OperationInput {
                 })
             }
             Self::IntersectionInput(input) => {
-                validate_boolean_op(
-                    &input.arg1,
-                    &input.arg2,
-                    geometry,
-                    case.b.as_ref().expect("no geometry b in case"),
-                )?;
+                validate_boolean_op(&input.arg1, &input.arg2)?;
                 Ok(Operation::BooleanOp {
                     a: geometry.clone(),
                     b: case.b.clone().expect("no geometry b in case"),
@@ -291,12 +493,7 @@ impl OperationInput {
                 })
             }
             Self::DifferenceInput(input) => {
-                validate_boolean_op(
-                    &input.arg1,
-                    &input.arg2,
-                    geometry,
-                    case.b.as_ref().expect("no geometry b in case"),
-                )?;
+                validate_boolean_op(&input.arg1, &input.arg2)?;
                 Ok(Operation::BooleanOp {
                     a: geometry.clone(),
                     b: case.b.clone().expect("no geometry b in case"),
@@ -305,12 +502,7 @@ impl OperationInput {
                 })
             }
             Self::SymDifferenceInput(input) => {
-                validate_boolean_op(
-                    &input.arg1,
-                    &input.arg2,
-                    geometry,
-                    case.b.as_ref().expect("no geometry b in case"),
-                )?;
+                validate_boolean_op(&input.arg1, &input.arg2)?;
                 Ok(Operation::BooleanOp {
                     a: geometry.clone(),
                     b: case.b.clone().expect("no geometry b in case"),
@@ -323,19 +515,27 @@ impl OperationInput {
     }
 }
 
-fn validate_boolean_op(arg1: &str, arg2: &str, a: &Geometry<f64>, b: &Geometry<f64>) -> Result<()> {
+impl PredicateInput {
+    fn into_operation(self, case: &Case, predicate: Predicate) -> Result<Operation> {
+        let a = case.a.clone();
+        let b = case.b.clone().expect("no geometry b in case");
+        let (subject, target) = match (self.arg1.as_str(), self.arg2.as_str()) {
+            ("A", "B") => (a, b),
+            ("B", "A") => (b, a),
+            (arg1, arg2) => return Err(format!("unexpected arguments: {arg1}, {arg2}").into()),
+        };
+        Ok(Operation::Predicate {
+            subject,
+            target,
+            predicate,
+            expected: self.expected,
+        })
+    }
+}
+
+fn validate_boolean_op(arg1: &str, arg2: &str) -> Result<()> {
     assert_eq!("A", arg1);
     assert_eq!("B", arg2);
-    for arg in &[a, b] {
-        if matches!(arg, Geometry::LineString(_)) {
-            log::warn!("skipping `line_string.union` we don't support");
-            return Err("`line_string.union` is not supported".into());
-        }
-        if matches!(arg, Geometry::MultiPoint(_)) {
-            log::warn!("skipping `line_string.union` we don't support");
-            return Err("`line_string.union` is not supported".into());
-        }
-    }
     Ok(())
 }
 
@@ -9,10 +9,28 @@ use super::{input, Operation, Result};
 use geo::algorithm::{BooleanOps, Contains, HasDimensions, Intersects, Within};
 use geo::geometry::*;
 use geo::GeoNum;
+use input::Predicate;
 
 const GENERAL_TEST_XML: Dir = include_dir!("$CARGO_MANIFEST_DIR/resources/testxml/general");
 const VALIDATE_TEST_XML: Dir = include_dir!("$CARGO_MANIFEST_DIR/resources/testxml/validate");
 
+/// The de-9im specs of the named predicates, to check `Relate::relate_matches` against
+const RELATE_SPECS: [&str; 13] = [
+    "T********",
+    "*T*******",
+    "***T*****",
+    "****T****",
+    "FF*FF****",
+    "T*F**F***",
+    "T*****FF*",
+    "FT*******",
+    "F***T****",
+    "T*T******",
+    "0********",
+    "T*T***T**",
+    "T*F**FFF*",
+];
+
 #[derive(Debug, Default, Clone)]
 pub struct TestRunner {
     filename_filter: Option<String>,
@@ -86,6 +104,52 @@ impl TestRunner {
 
         for test_case in cases {
             match &test_case.operation {
+                Operation::Boundary { subject, expected } => {
+                    use geo::{Boundary, Relate};
+                    let actual = subject.boundary();
+                    let is_equal = if actual.is_empty() || expected.is_empty() {
+                        actual.is_empty() && expected.is_empty()
+                    } else {
+                        actual.relate(expected).matches("T*F**FFF*").unwrap()
+                    };
+                    if is_equal {
+                        debug!("Boundary success: actual == expected");
+                        self.successes.push(test_case);
+                    } else {
+                        debug!("Boundary failure: actual != expected");
+                        let error_description = format!(
+                            "expected {:?}, actual: {:?}",
+                            expected.wkt_string(),
+                            actual.wkt_string()
+                        );
+                        self.failures.push(TestFailure {
+                            test_case,
+                            error_description,
+                        });
+                    }
+                }
+                Operation::Buffer {
+                    subject,
+                    distance,
+                    style,
+                    expected,
+                } => {
+                    use geo::Buffer;
+                    let actual = subject.buffer_with_style(*distance, style);
+                    match is_buffer_match(&actual, expected, *distance) {
+                        Ok(()) => {
+                            debug!("Buffer success: actual matches expected");
+                            self.successes.push(test_case);
+                        }
+                        Err(error_description) => {
+                            debug!("Buffer failure: actual doesn't match expected");
+                            self.failures.push(TestFailure {
+                                test_case,
+                                error_description,
+                            });
+                        }
+                    }
+                }
                 Operation::Centroid { subject, expected } => {
                     use geo::prelude::Centroid;
                     match (subject.centroid(), expected) {
@@ -113,9 +177,10 @@ impl TestRunner {
                     target,
                     expected,
                 } => {
-                    use geo::Relate;
+                    use geo::{PreparedGeometry, Relate};
                     let relate_actual = subject.relate(target).is_contains();
                     let direct_actual = subject.contains(target);
+                    let prepared_actual = PreparedGeometry::from(subject).contains(target);
 
                     if relate_actual != *expected {
                         debug!("Contains failure: Relate doesn't match expected");
@@ -126,6 +191,15 @@ impl TestRunner {
                             test_case,
                             error_description,
                         });
+                    } else if relate_actual != prepared_actual {
+                        debug!("Contains failure: Relate doesn't match PreparedGeometry");
+                        let error_description = format!(
+                            "Contains failure - Relate.is_contains: {expected:?} doesn't match PreparedGeometry: {prepared_actual:?}"
+                        );
+                        self.failures.push(TestFailure {
+                            test_case,
+                            error_description,
+                        });
                     } else if relate_actual != direct_actual {
                         debug!(
                             "Contains failure: Relate doesn't match Contains trait implementation"
@@ -279,13 +353,163 @@ impl TestRunner {
                         self.successes.push(test_case);
                     }
                 }
-                Operation::Relate { a, b, expected } => {
-                    use geo::Relate;
-                    let actual = a.relate(b);
-                    if actual == *expected {
-                        debug!("Relate success: actual == expected");
+                Operation::IsValid { subject, expected } => {
+                    use geo::IsValid;
+                    let errors = subject.validation_errors();
+                    if errors.is_empty() == *expected {
+                        debug!("IsValid success: actual == expected");
                         self.successes.push(test_case);
                     } else {
+                        debug!("IsValid failure: actual != expected");
+                        let error_description =
+                            format!("expected {expected:?}, actual errors: {errors:?}");
+                        self.failures.push(TestFailure {
+                            test_case,
+                            error_description,
+                        });
+                    }
+                }
+                Operation::MinimumClearance { subject, expected } => {
+                    use geo::MinimumClearance;
+                    let actual = subject.minimum_clearance();
+                    let is_equal = match (actual, expected) {
+                        (None, None) => true,
+                        (Some(actual), Some(expected)) => relative_eq!(actual, expected),
+                        _ => false,
+                    };
+                    if is_equal {
+                        debug!("MinimumClearance success: actual == expected");
+                        self.successes.push(test_case);
+                    } else {
+                        debug!("MinimumClearance failure: actual != expected");
+                        let error_description =
+                            format!("expected {expected:?}, actual: {actual:?}");
+                        self.failures.push(TestFailure {
+                            test_case,
+                            error_description,
+                        });
+                    }
+                }
+                Operation::MinimumClearanceLine { subject, expected } => {
+                    use geo::MinimumClearance;
+                    let actual = subject.minimum_clearance_line();
+                    // The line may go either way.
+                    let is_equal = match (actual, expected) {
+                        (None, None) => true,
+                        (Some(actual), Some(expected)) => {
+                            (relative_eq!(actual.start, expected.start)
+                                && relative_eq!(actual.end, expected.end))
+                                || (relative_eq!(actual.start, expected.end)
+                                    && relative_eq!(actual.end, expected.start))
+                        }
+                        _ => false,
+                    };
+                    if is_equal {
+                        debug!("MinimumClearanceLine success: actual == expected");
+                        self.successes.push(test_case);
+                    } else {
+                        debug!("MinimumClearanceLine failure: actual != expected");
+                        let error_description =
+                            format!("expected {expected:?}, actual: {actual:?}");
+                        self.failures.push(TestFailure {
+                            test_case,
+                            error_description,
+                        });
+                    }
+                }
+                Operation::Polygonize { subject, expected } => {
+                    use geo::{Polygonize, Relate};
+                    let actual = line_strings(subject).polygonize().polygons;
+                    let expected_count = match expected {
+                        Geometry::GeometryCollection(gc) => gc.len(),
+                        _ => 1,
+                    };
+                    let is_equal = if actual.is_empty() || expected.is_empty() {
+                        actual.is_empty() && expected.is_empty()
+                    } else {
+                        actual.0.len() == expected_count
+                            && Geometry::MultiPolygon(actual.clone())
+                                .relate(expected)
+                                .matches("T*F**FFF*")
+                                .unwrap()
+                    };
+                    if is_equal {
+                        debug!("Polygonize success: actual == expected");
+                        self.successes.push(test_case);
+                    } else {
+                        debug!("Polygonize failure: actual != expected");
+                        let error_description = format!(
+                            "expected {:?}, actual: {:?}",
+                            expected.wkt_string(),
+                            actual.wkt_string()
+                        );
+                        self.failures.push(TestFailure {
+                            test_case,
+                            error_description,
+                        });
+                    }
+                }
+                Operation::Predicate {
+                    subject,
+                    target,
+                    predicate,
+                    expected,
+                } => {
+                    use geo::{CoveredBy, Covers, Crosses, EqualsTopo, Overlaps, Relate, Touches};
+                    if matches!(predicate, Predicate::EqualsTopo)
+                        && (is_collapsed_line(subject) || is_collapsed_line(target))
+                    {
+                        debug!("geo treats a zero-length line as a point, unlike JTS");
+                        self.unsupported.push(test_case);
+                        continue;
+                    }
+                    let matrix = subject.relate(target);
+                    let (relate_result, trait_result) = match predicate {
+                        Predicate::CoveredBy => {
+                            (matrix.is_covered_by(), subject.is_covered_by(target))
+                        }
+                        Predicate::Covers => (matrix.is_covers(), subject.covers(target)),
+                        Predicate::Crosses => (matrix.is_crosses(), subject.crosses(target)),
+                        Predicate::EqualsTopo => {
+                            (matrix.is_equal_topo(), subject.equals_topo(target))
+                        }
+                        Predicate::Overlaps => (matrix.is_overlaps(), subject.overlaps(target)),
+                        Predicate::Touches => (matrix.is_touches(), subject.touches(target)),
+                    };
+                    if relate_result != *expected {
+                        debug!("{predicate:?} failure: Relate doesn't match expected");
+                        let error_description = format!(
+                            "{predicate:?} failure: expected {expected:?}, relate: {relate_result:?}"
+                        );
+                        self.failures.push(TestFailure {
+                            test_case,
+                            error_description,
+                        });
+                    } else if relate_result != trait_result {
+                        debug!("{predicate:?} failure: Relate doesn't match trait implementation");
+                        let error_description = format!(
+                            "{predicate:?} failure: Relate: {relate_result:?}, trait: {trait_result:?}"
+                        );
+                        self.failures.push(TestFailure {
+                            test_case,
+                            error_description,
+                        });
+                    } else {
+                        debug!("{predicate:?} success: actual == expected");
+                        self.successes.push(test_case);
+                    }
+                }
+                Operation::Relate { a, b, expected } => {
+                    use geo::{PreparedGeometry, Relate};
+                    let actual = a.relate(b);
+                    let prepared_a = PreparedGeometry::from(a);
+                    let prepared_b = PreparedGeometry::from(b);
+                    let prepared_actuals = [
+                        prepared_a.relate(b),
+                        a.relate(&prepared_b),
+                        prepared_a.relate(&prepared_b),
+                    ];
+                    if actual != *expected {
                         debug!("Relate failure: actual != expected");
                         let error_description =
                             format!("expected {expected:?}, actual: {actual:?}");
@@ -293,6 +517,56 @@ impl TestRunner {
                             test_case,
                             error_description,
                         });
+                    } else if let Some(prepared_actual) =
+                        prepared_actuals.iter().find(|im| *im != expected)
+                    {
+                        debug!("Relate failure: PreparedGeometry doesn't match expected");
+                        let error_description =
+                            format!("expected {expected:?}, PreparedGeometry: {prepared_actual:?}");
+                        self.failures.push(TestFailure {
+                            test_case,
+                            error_description,
+                        });
+                    } else if let Some(spec) = RELATE_SPECS.iter().find(|spec| {
+                        let expected = expected.matches(spec).unwrap();
+                        a.relate_matches(b, spec).unwrap() != expected
+                            || prepared_a.relate_matches(b, spec).unwrap() != expected
+                    }) {
+                        debug!("Relate failure: relate_matches doesn't match expected");
+                        let error_description =
+                            format!("expected {expected:?}, relate_matches differs for {spec}");
+                        self.failures.push(TestFailure {
+                            test_case,
+                            error_description,
+                        });
+                    } else {
+                        debug!("Relate success: actual == expected");
+                        self.successes.push(test_case);
+                    }
+                }
+                Operation::BooleanOp { a, b, op, expected } if !is_polygonal_pair(a, b) => {
+                    use geo::{Overlay, Relate};
+                    let actual = Geometry::GeometryCollection(a.overlay(b, *op));
+
+                    let is_equal = if actual.is_empty() || expected.is_empty() {
+                        actual.is_empty() && expected.is_empty()
+                    } else {
+                        actual.relate(expected).matches("T*F**FFF*").unwrap()
+                    };
+                    if is_equal {
+                        debug!("Overlay success - expected: {:?}", expected.wkt_string());
+                        self.successes.push(test_case);
+                    } else {
+                        let error_description = format!(
+                            "op: {:?}, expected {:?}, actual: {:?}",
+                            op,
+                            expected.wkt_string(),
+                            actual.wkt_string()
+                        );
+                        self.failures.push(TestFailure {
+                            test_case,
+                            error_description,
+                        });
                     }
                 }
                 Operation::BooleanOp { a, b, op, expected } => {
@@ -429,6 +703,101 @@ impl TestRunner {
     }
 }
 
+/// The `LineString`s and polygon rings in a geometry.
+fn line_strings(geometry: &Geometry) -> Vec<LineString> {
+    match geometry {
+        Geometry::Point(_) | Geometry::MultiPoint(_) => vec![],
+        Geometry::Line(line) => vec![LineString::from(*line)],
+        Geometry::LineString(ls) => vec![ls.clone()],
+        Geometry::MultiLineString(mls) => mls.0.clone(),
+        Geometry::Polygon(polygon) => std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .cloned()
+            .collect(),
+        Geometry::MultiPolygon(mp) => mp
+            .iter()
+            .flat_map(|p| line_strings(&Geometry::Polygon(p.clone())))
+            .collect(),
+        Geometry::Rect(rect) => line_strings(&Geometry::Polygon(rect.to_polygon())),
+        Geometry::Triangle(triangle) => line_strings(&Geometry::Polygon(triangle.to_polygon())),
+        Geometry::GeometryCollection(gc) => gc.iter().flat_map(line_strings).collect(),
+    }
+}
+
+/// Whether the operands are handled by `BooleanOps`, rather than `Overlay`.
+fn is_polygonal_pair(a: &Geometry, b: &Geometry) -> bool {
+    matches!(
+        (a, b),
+        (Geometry::Polygon(_), Geometry::Polygon(_))
+            | (Geometry::MultiPolygon(_), Geometry::MultiPolygon(_))
+    )
+}
+
+/// Compare buffers in the manner of JTS's `BufferResultMatcher`: the areas must be close
+/// relative to the expected area, and the boundaries must be close to each other relative to the
+/// buffer distance.
+///
+/// Unlike JTS, the area of the symmetric difference isn't used, since overlaying two nearly
+/// identical polygons is numerically fragile. Instead, the boundaries are compared in both
+/// directions.
+fn is_buffer_match(
+    actual: &MultiPolygon<f64>,
+    expected: &Geometry<f64>,
+    distance: f64,
+) -> std::result::Result<(), String> {
+    use geo::{Area, EuclideanDistance, LinesIter};
+
+    const MAX_RELATIVE_AREA_DIFFERENCE: f64 = 1.0e-3;
+    const MAX_HAUSDORFF_DISTANCE_FACTOR: f64 = 100.;
+    const MIN_DISTANCE_TOLERANCE: f64 = 1.0e-8;
+
+    let expected = match expected {
+        Geometry::Polygon(poly) => MultiPolygon::new(vec![poly.clone()]),
+        Geometry::MultiPolygon(multi) => multi.clone(),
+        _ => return Err(format!("unsupported expected buffer: {expected:?}")),
+    };
+    if actual.0.is_empty() && expected.0.is_empty() {
+        return Ok(());
+    }
+
+    let area = expected.unsigned_area();
+    let area_difference = (actual.unsigned_area() - area).abs();
+    if area > 0. && area_difference / area >= MAX_RELATIVE_AREA_DIFFERENCE {
+        return Err(format!(
+            "area difference {area_difference} of expected area {area}, actual: {}",
+            actual.wkt_string()
+        ));
+    }
+
+    let boundary = |mp: &MultiPolygon<f64>| -> MultiLineString<f64> {
+        mp.iter()
+            .flat_map(|p| std::iter::once(p.exterior()).chain(p.interiors()))
+            .cloned()
+            .collect()
+    };
+    // The greatest distance from `a`, densified to a quarter of each segment, to `b`.
+    let oriented_distance = |a: &MultiLineString<f64>, b: &MultiLineString<f64>| {
+        a.lines_iter()
+            .flat_map(|line| {
+                (0..4).map(move |i| Point::from(line.start + line.delta() * (i as f64 / 4.)))
+            })
+            .map(|pt| pt.euclidean_distance(b))
+            .fold(0., f64::max)
+    };
+    let actual_boundary = boundary(actual);
+    let expected_boundary = boundary(&expected);
+    let max_distance = oriented_distance(&actual_boundary, &expected_boundary)
+        .max(oriented_distance(&expected_boundary, &actual_boundary));
+    let tolerance = (distance.abs() / MAX_HAUSDORFF_DISTANCE_FACTOR).max(MIN_DISTANCE_TOLERANCE);
+    if max_distance > tolerance {
+        return Err(format!(
+            "boundary distance {max_distance} exceeds tolerance {tolerance}, actual: {}",
+            actual.wkt_string()
+        ));
+    }
+    Ok(())
+}
+
 trait RotatedEq<T: GeoNum> {
     fn is_rotated_eq<F>(&self, other: &Self, coord_matcher: F) -> bool
     where
@@ -510,3 +879,9 @@ impl<T: GeoNum> RotatedEq<T> for LineString<T> {
         })
     }
 }
+
+/// A `Line` or `LineString` whose points are all equal, which JTS still considers one-dimensional.
+fn is_collapsed_line(geometry: &Geometry) -> bool {
+    matches!(geometry, Geometry::LineString(_) | Geometry::Line(_))
+        && geometry.dimensions() == geo::dimensions::Dimensions::ZeroDimensional
+}